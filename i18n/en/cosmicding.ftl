@@ -1,27 +1,54 @@
 about = About
 account = Account
+account-details = Account Details
 account-exists = Account already exists
 accounts = Accounts
 accounts-with-count = Accounts ({$count})
 actions = Actions
 add-account = Add Account
 add-bookmark = Add Bookmark
+add-header = Add Header
+add-replication-rule = Add Replication Rule
+add-replication-rule-body = Bookmarks of the source account matching the filter are created or updated in the target account, bookmarks are matched by their URL.
+add-tags = Add Tags
 added-account = Added account {$acc}
 added-bookmark-to-account = Added bookmark {$bkmrk} to {$acc}
+added-replication-rule = Added replication rule
+all-bookmarks = All bookmarks
 api-key = API Key
+app-password = App Password
 appearance = Appearance
+archive = Archive
 archived = Archived
+background-sync = Background Sync
 bookmark-alphabetical-ascending = A-Z (Bookmark Title) 
 bookmark-alphabetical-descending = Z-A (Bookmark Title) 
+bookmark-conflict = Conflicting Changes
+bookmark-conflict-body = Bookmark {$bkmrk} was modified in {$acc} since it was last synchronized.
 bookmark-date-newest = Newest First
 bookmark-date-oldest = Oldest First
+bookmark-not-synced = Bookmark was not synchronized with the provider yet
+bookmark-relevance = Relevance (Search Only)
 bookmarks = Bookmarks
+bookmarks-modified-during-sync = Bookmarks were modified while syncing, they will be synced again
 bookmarks-with-count = Bookmarks ({$count})
 browse = Browse
+bulk-tags-body = Enter any number of tags separated by space, they will be applied to {$count} selected bookmarks.
+ca-certificate-path = CA certificate bundle (PEM file path)
 cancel = Cancel
+clear-selection = Clear Selection
+client-certificate-path = Client certificate (PEM file path)
+client-id = Client ID
+client-key-path = Client key (PKCS#8 PEM file path)
+client-secret = Client secret
+copied-bookmarks-to-account = Copied {$count} bookmarks to account {$acc}
+copy = Copy
+copying-bookmarks = Copying Bookmarks
 cosmicding = Cosmicding
+create-secrets-passphrase-body = The Secret Service is not available, API tokens are stored in a file encrypted using a passphrase. Choose a passphrase, it will be requested when cosmicding starts.
 dark = Dark
 description = Description
+details = Details
 disable = Disable
 disabled = Disabled
 disabled-account = Disabled account {$acc}
@@ -49,10 +76,21 @@ failed-refreshing-all-accounts = Failed refreshing all accounts
 failed-refreshing-bookmarks-for-account = Failed refreshing account {$account}
 failed-to-add-account = Failed to add account {$acc}: {$err}
 failed-to-edit-account = Failed to edit account {$acc}: {$err}
+failed-to-find-karakeep-api-endpoint = Failed to find Karakeep API endpoint
 failed-to-find-linkding-api-endpoint = Failed to find linkding API endpoint
+failed-to-find-linkwarden-api-endpoint = Failed to find Linkwarden API endpoint
+failed-to-find-nextcloud-bookmarks-api-endpoint = Failed to find Nextcloud Bookmarks API endpoint
+failed-to-find-shaarli-api-endpoint = Failed to find Shaarli API endpoint
+failed-to-find-wallabag-api-endpoint = Failed to find Wallabag API endpoint
 failed-to-parse-response = Failed to parse response
+failed-to-replay-bookmark-changes = Failed to synchronize {$count} changes to {$acc}, the changes are kept locally
+failed-to-synchronize = Failed to synchronize
+failed-to-unlock-secrets = Failed to unlock secrets: {$err}
 file = File
+filter-by-collection = Show bookmarks in this collection
 git-description = Git commit {$hash} on {$date}
+header-name = Name
+header-value = Value
 http-error = HTTP error {$http_rc}: {$http_err}
 import = Import
 import-bookmarks = Import Bookmarks
@@ -63,57 +101,149 @@ import-bookmarks-finished = Successfully imported {$count} bookmarks
 import-bookmarks-no-path = Please select a file path for import
 import-bookmarks-started = Importing {$count} bookmarks...
 importing-bookmarks = Importing Bookmarks
+incorrect-passphrase = Incorrect passphrase
 instance = Instance
 invalid-api-token = Invalid API token
 items-per-page = Items Per Page - {{$count}}
+keep-local = Keep Mine
+keep-remote = Keep Theirs
+last-full-sync-time = Last full sync time
 last-sync-time = Last sync time
 light = Light
+local-version = Mine
+mark-read = Mark as Read
+mark-unread = Mark as Unread
 match-desktop = Match Desktop
+merge = Merge
+merge-tags = Merge Tags
+merge-tags-body = Merge {$tags} into a single tag.
+missing-client-certificate-or-key = Both a client certificate and a client key are required
+move = Move
+move-copy = Move/Copy
+moved-bookmarks-to-account = Moved {$count} bookmarks to account {$acc}
+moving-bookmarks = Moving Bookmarks
+network = Network
+never = Never
 next = Next
+no = No
 no-bookmarks = No bookmarks
 no-bookmarks-found-for-account = No bookmarks found for account {$acc}
+no-certificates-found = No certificates found
 no-file-selected = No file selected
+no-sync-runs = No syncs recorded
+no-tags = No tags
 notes = Notes
 open-accounts-page = Open Accounts Page
+passphrase = Passphrase
+passphrase-empty = Passphrase is empty
+password = Password
+pending = Pending
 previous = Previous
 provided-url-is-not-valid = Provided URL is not valid
+provider = Provider
+proxy-url = Proxy URL (e.g. http://proxy.example.com:8080)
 public-sharing = Public Sharing
 purge-favicons-cache = Purge Favicons Cache
 purge-favicons-cache-confirm = Are you sure you wish to delete favicons cache?
+queued-bookmark-change = Unable to reach {$acc}, changes to {$bkmrk} will be synchronized later
 quit = Quit
+reading-time = {$minutes} min read
 refresh = Refresh
 refresh-bookmarks = Refresh Bookmarks
 refreshed-bookmarks = Refreshed bookmarks
 refreshed-bookmarks-for-account = Refreshed account {$acc}
+refreshing-account-pages = Refreshing {$acc} (page {$page} of {$pages})
 refreshing-accounts = Refreshing Accounts
+remote-version = Theirs
 remove = Remove
 remove-account-confirm = Are you sure you wish to delete this account?
 remove-bookmark-confirm = Are you sure you wish to delete this bookmark?
+remove-bookmarks = Remove Bookmarks
+remove-bookmarks-confirm = Are you sure you wish to delete {$count} bookmarks?
+remove-from-source-account = Remove from source account
+remove-tags = Remove Tags
+remove-tags-confirm = Are you sure you wish to remove {$tags} from all bookmarks?
 removed-account = Removed account {$acc}
 removed-bookmark-from-account = Removed bookmark from account {$acc}
+removed-bookmarks = Removed {$count} bookmarks
+removed-replication-rule = Removed replication rule
+removing-bookmarks = Removing Bookmarks
+rename = Rename
+rename-tag = Rename Tag
+rename-tag-body = Rename {$tag} in all bookmarks.
+replicated-bookmarks = Replication finished: {$created} created, {$updated} updated, {$skipped} skipped, {$conflicted} kept remote changes
+replicating-bookmarks = Replicating Bookmarks
+replication-filter = Filter (e.g. tag:team)
+replication-last-run = Last run: {$time} ({$created} created, {$updated} updated, {$skipped} skipped, {$conflicted} kept remote changes)
+replication-never-run = Never run
+replication-rules = Replication Rules
+replication-target-unavailable = Target account of the replication rule is not available
+request-headers = Additional Request Headers
+run = Run
 save = Save
 search = Search
+search-invalid-date = Invalid date {$value}, expected a date such as 2025-01-01
+search-invalid-state = Unknown state {$value}, expected archived, shared or unread
+search-missing-value = Missing value for {$filter}:
+search-unclosed-quote = Missing closing quote
+secret-corrupted = Secret is corrupted or could not be decrypted
+secret-not-found = Secret not found
+secrets-locked = Secrets are locked
+secrets-unavailable = Secret storage is not available
+select = Select
 select-accounts = Select Accounts
+select-all-matching = Select All Matching
 select-export-path = Select Export File
 select-import-path = Select Import File
+select-page = Select Page
+selected-count = {$count} selected
 setting-managed-externally = This setting can only be managed from Linkding web UI
 settings = Settings
+share = Share
 shared = Shared
 shared-disabled = Shared (Disabled)
 sharing = Sharing
+skipped-conflicting-bookmarks = Skipped {$count} bookmarks that kept changing remotely
 snapshot = Snapshot
 sort = Sort
+source-account = Source
 successful = successful
+sync = Synchronization
+sync-history = Sync History
+sync-interval = Sync Interval
+sync-interval-hours = {$count}h
+sync-interval-minutes = {$count}m
+sync-page-size = Sync Page Size - {$count}
+sync-run-counts = {$added} added, {$updated} updated, {$removed} removed
+sync-run-duration = {$seconds}s
+tag-name = Tag name
 tags = Tags
 tags-subtext = Enter any number of tags separated by space.
+tags-with-count = Tags ({$count})
+target-account = Target
 theme = Theme
 title = Title
+tls-certificates = TLS Certificates
 token = Token
+transfer-bookmarks = Move or Copy Bookmarks
+transfer-bookmarks-body = Move or copy {$count} bookmarks to another account.
 trust-invalid-certificates = Trust Invalid SSL Certificates
+unarchive = Unarchive
 unexpected-http-return-code = Unexpected HTTP return code {$http_rc}
+unlock = Unlock
+unlock-secrets = Unlock Secrets
+unlock-secrets-body = The Secret Service is not available, enter the passphrase used to encrypt API tokens.
+unreachable = unreachable
 unread = Unread
+unshare = Unshare
+unsupported-client-key-format = Client keys must be in PKCS#8 format (BEGIN PRIVATE KEY), convert the key using 'openssl pkcs8 -topk8 -nocrypt'
+unsupported-provider = Unsupported provider {$provider}
 updated-account = Updated account {$acc}
 updated-bookmark-in-account = Updated bookmark {$bkmrk} in account {$acc}
+updated-bookmarks = Updated {$count} bookmarks
+updating-bookmarks = Updating Bookmarks
+updating-tags = Updating Tags
 url = URL
+username = Username
 view = View
 yes = Yes
@@ -0,0 +1,4 @@
+ALTER TABLE UserAccounts ADD COLUMN sync_cursor TEXT;
+ALTER TABLE UserAccounts ADD COLUMN last_full_sync_timestamp INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS Bookmarks_Account_Provider_Id ON Bookmarks (user_account_id, provider_internal_id);
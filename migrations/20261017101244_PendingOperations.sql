@@ -0,0 +1,12 @@
+CREATE TABLE PendingOperations (
+    id INTEGER PRIMARY KEY NOT NULL,
+    user_account_id INTEGER NOT NULL,
+    bookmark_id INTEGER,
+    provider_internal_id INTEGER,
+    operation TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    created_timestamp INTEGER NOT NULL,
+    CHECK (
+        operation IN ('add', 'edit', 'remove')
+    )
+);
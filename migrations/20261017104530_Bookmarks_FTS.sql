@@ -0,0 +1,85 @@
+CREATE VIRTUAL TABLE BookmarksFTS USING fts5 (
+    url,
+    title,
+    description,
+    notes,
+    tag_names,
+    content = 'Bookmarks',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER Bookmarks_FTS_Insert AFTER INSERT ON Bookmarks BEGIN
+    INSERT INTO BookmarksFTS (
+        rowid,
+        url,
+        title,
+        description,
+        notes,
+        tag_names
+    ) VALUES (
+        new.id,
+        new.url,
+        new.title,
+        new.description,
+        new.notes,
+        new.tag_names
+    );
+END;
+
+CREATE TRIGGER Bookmarks_FTS_Delete AFTER DELETE ON Bookmarks BEGIN
+    INSERT INTO BookmarksFTS (
+        BookmarksFTS,
+        rowid,
+        url,
+        title,
+        description,
+        notes,
+        tag_names
+    ) VALUES (
+        'delete',
+        old.id,
+        old.url,
+        old.title,
+        old.description,
+        old.notes,
+        old.tag_names
+    );
+END;
+
+CREATE TRIGGER Bookmarks_FTS_Update AFTER UPDATE ON Bookmarks BEGIN
+    INSERT INTO BookmarksFTS (
+        BookmarksFTS,
+        rowid,
+        url,
+        title,
+        description,
+        notes,
+        tag_names
+    ) VALUES (
+        'delete',
+        old.id,
+        old.url,
+        old.title,
+        old.description,
+        old.notes,
+        old.tag_names
+    );
+    INSERT INTO BookmarksFTS (
+        rowid,
+        url,
+        title,
+        description,
+        notes,
+        tag_names
+    ) VALUES (
+        new.id,
+        new.url,
+        new.title,
+        new.description,
+        new.notes,
+        new.tag_names
+    );
+END;
+
+INSERT INTO BookmarksFTS (BookmarksFTS) VALUES ('rebuild');
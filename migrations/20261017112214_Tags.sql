@@ -0,0 +1,52 @@
+CREATE TABLE Tags (
+    id INTEGER PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE
+);
+
+CREATE TABLE BookmarkTags (
+    bookmark_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    PRIMARY KEY (bookmark_id, tag_id)
+);
+
+CREATE INDEX BookmarkTags_Tag ON BookmarkTags (tag_id);
+
+CREATE TRIGGER Bookmarks_Tags_Insert AFTER INSERT ON Bookmarks BEGIN
+    INSERT OR IGNORE INTO Tags (name)
+    SELECT value FROM json_each('["' || replace(replace(replace(coalesce(new.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]')
+    WHERE value != '';
+    INSERT OR IGNORE INTO BookmarkTags (bookmark_id, tag_id)
+    SELECT new.id, Tags.id FROM json_each('["' || replace(replace(replace(coalesce(new.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]')
+    INNER JOIN Tags ON Tags.name = json_each.value
+    WHERE json_each.value != '';
+END;
+
+CREATE TRIGGER Bookmarks_Tags_Delete AFTER DELETE ON Bookmarks BEGIN
+    DELETE FROM BookmarkTags WHERE bookmark_id = old.id;
+    DELETE FROM Tags
+    WHERE name IN (SELECT value FROM json_each('["' || replace(replace(replace(coalesce(old.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]'))
+    AND NOT EXISTS (SELECT 1 FROM BookmarkTags WHERE BookmarkTags.tag_id = Tags.id);
+END;
+
+CREATE TRIGGER Bookmarks_Tags_Update AFTER UPDATE OF tag_names ON Bookmarks BEGIN
+    DELETE FROM BookmarkTags WHERE bookmark_id = old.id;
+    DELETE FROM Tags
+    WHERE name IN (SELECT value FROM json_each('["' || replace(replace(replace(coalesce(old.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]'))
+    AND NOT EXISTS (SELECT 1 FROM BookmarkTags WHERE BookmarkTags.tag_id = Tags.id);
+    INSERT OR IGNORE INTO Tags (name)
+    SELECT value FROM json_each('["' || replace(replace(replace(coalesce(new.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]')
+    WHERE value != '';
+    INSERT OR IGNORE INTO BookmarkTags (bookmark_id, tag_id)
+    SELECT new.id, Tags.id FROM json_each('["' || replace(replace(replace(coalesce(new.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]')
+    INNER JOIN Tags ON Tags.name = json_each.value
+    WHERE json_each.value != '';
+END;
+
+INSERT OR IGNORE INTO Tags (name)
+SELECT json_each.value FROM Bookmarks, json_each('["' || replace(replace(replace(coalesce(Bookmarks.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]')
+WHERE json_each.value != '';
+
+INSERT OR IGNORE INTO BookmarkTags (bookmark_id, tag_id)
+SELECT Bookmarks.id, Tags.id FROM Bookmarks, json_each('["' || replace(replace(replace(coalesce(Bookmarks.tag_names, ''), '\', '\\'), '"', '\"'), ' ', '","') || '"]')
+INNER JOIN Tags ON Tags.name = json_each.value
+WHERE json_each.value != '';
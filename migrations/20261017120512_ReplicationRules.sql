@@ -0,0 +1,13 @@
+CREATE TABLE ReplicationRules (
+    id INTEGER PRIMARY KEY NOT NULL,
+    source_account_id INTEGER NOT NULL,
+    target_account_id INTEGER NOT NULL,
+    filter TEXT NOT NULL,
+    last_run_timestamp INTEGER NOT NULL DEFAULT 0,
+    last_created_count INTEGER NOT NULL DEFAULT 0,
+    last_updated_count INTEGER NOT NULL DEFAULT 0,
+    last_skipped_count INTEGER NOT NULL DEFAULT 0,
+    CHECK (
+        source_account_id != target_account_id
+    )
+);
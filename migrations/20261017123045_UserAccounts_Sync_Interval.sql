@@ -0,0 +1 @@
+ALTER TABLE UserAccounts ADD COLUMN sync_interval_minutes INTEGER NOT NULL DEFAULT 60;
@@ -0,0 +1,16 @@
+CREATE TABLE SyncRuns (
+    id INTEGER PRIMARY KEY NOT NULL,
+    user_account_id INTEGER NOT NULL,
+    start_timestamp INTEGER NOT NULL,
+    end_timestamp INTEGER NOT NULL,
+    successful INTEGER NOT NULL,
+    endpoint_statuses TEXT NOT NULL,
+    added_count INTEGER NOT NULL DEFAULT 0,
+    updated_count INTEGER NOT NULL DEFAULT 0,
+    removed_count INTEGER NOT NULL DEFAULT 0,
+    error TEXT,
+    CHECK (
+        successful IN (0, 1)
+    )
+);
+CREATE INDEX SyncRuns_Account ON SyncRuns (user_account_id, start_timestamp);
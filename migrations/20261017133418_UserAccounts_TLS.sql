@@ -0,0 +1,3 @@
+ALTER TABLE UserAccounts ADD COLUMN ca_certificate_path TEXT;
+ALTER TABLE UserAccounts ADD COLUMN client_certificate_path TEXT;
+ALTER TABLE UserAccounts ADD COLUMN client_key_path TEXT;
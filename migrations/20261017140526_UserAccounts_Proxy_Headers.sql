@@ -0,0 +1,2 @@
+ALTER TABLE UserAccounts ADD COLUMN proxy_url TEXT;
+ALTER TABLE UserAccounts ADD COLUMN extra_headers TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1 @@
+ALTER TABLE UserAccounts ADD COLUMN api_token_reference TEXT;
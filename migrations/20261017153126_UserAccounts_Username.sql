@@ -0,0 +1 @@
+ALTER TABLE UserAccounts ADD COLUMN username TEXT;
@@ -0,0 +1 @@
+ALTER TABLE Bookmarks ADD COLUMN provider_remote_id TEXT;
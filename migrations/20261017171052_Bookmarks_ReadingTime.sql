@@ -0,0 +1 @@
+ALTER TABLE Bookmarks ADD COLUMN reading_time INTEGER;
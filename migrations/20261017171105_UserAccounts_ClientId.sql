@@ -0,0 +1 @@
+ALTER TABLE UserAccounts ADD COLUMN client_id TEXT;
@@ -0,0 +1 @@
+ALTER TABLE PendingOperations ADD COLUMN error TEXT;
@@ -0,0 +1,105 @@
+DROP TRIGGER Bookmarks_Tags_Insert;
+DROP TRIGGER Bookmarks_Tags_Delete;
+DROP TRIGGER Bookmarks_Tags_Update;
+
+CREATE TRIGGER Bookmarks_Tags_Insert AFTER INSERT ON Bookmarks BEGIN
+    INSERT OR IGNORE INTO Tags (name)
+    SELECT name FROM (
+        WITH RECURSIVE Split(name, remainder) AS (
+            SELECT '', coalesce(new.tag_names, '') || ' '
+            UNION ALL
+            SELECT substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+            FROM Split WHERE remainder != ''
+        )
+        SELECT name FROM Split
+    )
+    WHERE name != '';
+    INSERT OR IGNORE INTO BookmarkTags (bookmark_id, tag_id)
+    SELECT new.id, Tags.id FROM (
+        WITH RECURSIVE Split(name, remainder) AS (
+            SELECT '', coalesce(new.tag_names, '') || ' '
+            UNION ALL
+            SELECT substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+            FROM Split WHERE remainder != ''
+        )
+        SELECT name FROM Split
+    ) AS BookmarkTagNames
+    INNER JOIN Tags ON Tags.name = BookmarkTagNames.name
+    WHERE BookmarkTagNames.name != '';
+END;
+
+CREATE TRIGGER Bookmarks_Tags_Delete AFTER DELETE ON Bookmarks BEGIN
+    DELETE FROM BookmarkTags WHERE bookmark_id = old.id;
+    DELETE FROM Tags
+    WHERE name IN (
+        WITH RECURSIVE Split(name, remainder) AS (
+            SELECT '', coalesce(old.tag_names, '') || ' '
+            UNION ALL
+            SELECT substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+            FROM Split WHERE remainder != ''
+        )
+        SELECT name FROM Split
+    )
+    AND NOT EXISTS (SELECT 1 FROM BookmarkTags WHERE BookmarkTags.tag_id = Tags.id);
+END;
+
+CREATE TRIGGER Bookmarks_Tags_Update AFTER UPDATE OF tag_names ON Bookmarks BEGIN
+    DELETE FROM BookmarkTags WHERE bookmark_id = old.id;
+    DELETE FROM Tags
+    WHERE name IN (
+        WITH RECURSIVE Split(name, remainder) AS (
+            SELECT '', coalesce(old.tag_names, '') || ' '
+            UNION ALL
+            SELECT substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+            FROM Split WHERE remainder != ''
+        )
+        SELECT name FROM Split
+    )
+    AND NOT EXISTS (SELECT 1 FROM BookmarkTags WHERE BookmarkTags.tag_id = Tags.id);
+    INSERT OR IGNORE INTO Tags (name)
+    SELECT name FROM (
+        WITH RECURSIVE Split(name, remainder) AS (
+            SELECT '', coalesce(new.tag_names, '') || ' '
+            UNION ALL
+            SELECT substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+            FROM Split WHERE remainder != ''
+        )
+        SELECT name FROM Split
+    )
+    WHERE name != '';
+    INSERT OR IGNORE INTO BookmarkTags (bookmark_id, tag_id)
+    SELECT new.id, Tags.id FROM (
+        WITH RECURSIVE Split(name, remainder) AS (
+            SELECT '', coalesce(new.tag_names, '') || ' '
+            UNION ALL
+            SELECT substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+            FROM Split WHERE remainder != ''
+        )
+        SELECT name FROM Split
+    ) AS BookmarkTagNames
+    INNER JOIN Tags ON Tags.name = BookmarkTagNames.name
+    WHERE BookmarkTagNames.name != '';
+END;
+
+DELETE FROM BookmarkTags;
+DELETE FROM Tags;
+
+WITH RECURSIVE Split(bookmark_id, name, remainder) AS (
+    SELECT id, '', coalesce(tag_names, '') || ' ' FROM Bookmarks
+    UNION ALL
+    SELECT bookmark_id, substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+    FROM Split WHERE remainder != ''
+)
+INSERT OR IGNORE INTO Tags (name)
+SELECT name FROM Split WHERE name != '';
+
+WITH RECURSIVE Split(bookmark_id, name, remainder) AS (
+    SELECT id, '', coalesce(tag_names, '') || ' ' FROM Bookmarks
+    UNION ALL
+    SELECT bookmark_id, substr(remainder, 1, instr(remainder, ' ') - 1), substr(remainder, instr(remainder, ' ') + 1)
+    FROM Split WHERE remainder != ''
+)
+INSERT OR IGNORE INTO BookmarkTags (bookmark_id, tag_id)
+SELECT Split.bookmark_id, Tags.id FROM Split
+INNER JOIN Tags ON Tags.name = Split.name
+WHERE Split.name != '';
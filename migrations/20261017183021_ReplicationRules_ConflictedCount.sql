@@ -0,0 +1 @@
+ALTER TABLE ReplicationRules ADD COLUMN last_conflicted_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1 @@
+UPDATE Bookmarks SET provider_internal_id = NULL WHERE provider_remote_id IS NOT NULL;
@@ -1,33 +1,43 @@
 use crate::{
     app::{
-        actions::{ApplicationAction, ImportAction},
+        actions::{
+            ApplicationAction, BookmarksAction, BulkActionContext, ImportAction, ReplicationContext,
+        },
         config::{AppTheme, CosmicConfig, SortOption},
         context::ContextPage,
-        dialog::DialogPage,
+        dialog::{ConflictResolution, DialogPage},
         menu as app_menu,
         nav::AppNavPage,
     },
     db::{self},
     fl,
     models::{
-        account::{Account, LinkdingAccountApiResponse},
+        account::{Account, AccountDetails, RequestHeader},
         bookmarks::{
-            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, DetailedResponse,
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksSyncEvent,
         },
+        bulk_action::BulkAction,
         db_cursor::{AccountsPaginationCursor, BookmarksPaginationCursor, Pagination},
         favicon_cache::Favicon,
         operation::OperationProgress,
-        provider::Provider,
+        pending_operation::{PendingOperation, PendingOperationKind},
+        provider::LINKDING_PROVIDER,
+        replication::{self, ReplicationRule, ReplicationSummary},
+        sync_run::{SyncRun, MAX_SYNC_RUNS_PER_ACCOUNT},
         sync_status::SyncStatus,
+        tag::TagOperation,
     },
     pages::{
-        accounts::{add_account, edit_account, PageAccountsView},
+        accounts::{account_details, add_account, edit_account, PageAccountsView},
         bookmarks::{edit_bookmark, new_bookmark, view_notes, PageBookmarksView},
+        tags::PageTagsView,
     },
     provider::{self},
+    secrets,
     style::animation::refresh,
-    utils::bookmark_parser,
+    utils::{bookmark_parser, search},
 };
+use chrono::Utc;
 use cosmic::{
     app::{context_drawer, Core, Task},
     cosmic_config::{self, Update},
@@ -73,6 +83,10 @@ pub const APP: &str = "cosmicding";
 pub const APPID: &str = constcat::concat!(QUALIFIER, ".", ORG, ".", APP);
 
 const REPOSITORY: &str = "https://github.com/vkhitrin/cosmicding";
+// NOTE: (vkhitrin) how often accounts are checked for a due background sync.
+const BACKGROUND_SYNC_CHECK_INTERVAL_SECONDS: u64 = 60;
+// NOTE: (vkhitrin) bookmarks that keep conflicting after being resubmitted are not replicated.
+const MAX_CONFLICT_RETRIES: usize = 1;
 
 pub static REFRESH_ICON: std::sync::LazyLock<refresh::Id> =
     std::sync::LazyLock::new(refresh::Id::unique);
@@ -98,6 +112,17 @@ async fn open_file_dialog() -> Option<PathBuf> {
         .map(|file| file.path().to_path_buf())
 }
 
+// NOTE: (vkhitrin) identifies a cancellable operation, results of a cancelled operation
+//       no longer match the operation in progress.
+fn new_operation_id() -> u64 {
+    #[allow(clippy::cast_possible_truncation)]
+    let operation_id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64;
+    operation_id
+}
+
 pub struct Flags {
     pub config_handler: Option<cosmic_config::Config>,
     pub config: CosmicConfig,
@@ -118,6 +143,7 @@ pub struct Cosmicding {
     context_bookmark: Option<Bookmark>,
     context_bookmark_description: widget::text_editor::Content,
     context_bookmark_notes: widget::text_editor::Content,
+    context_sync_runs: Vec<SyncRun>,
     context_selected_account_index: usize,
     pub accounts_cursor: AccountsPaginationCursor,
     pub accounts_view: PageAccountsView,
@@ -125,11 +151,17 @@ pub struct Cosmicding {
     pub bookmarks_view: PageBookmarksView,
     pub config: CosmicConfig,
     pub state: ApplicationState,
+    pub tags_view: PageTagsView,
     search_id: widget::Id,
     timeline: Timeline,
     sync_status: SyncStatus,
     toasts: widget::toaster::Toasts<ApplicationAction>,
     operation_progress: Option<OperationProgress>,
+    sync_states: HashMap<i64, db::BookmarksSyncState>,
+    // NOTE: (vkhitrin) consecutive failed syncs of each account, used to back off
+    //       background syncs.
+    sync_failures: HashMap<i64, u32>,
+    is_background_sync: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -159,6 +191,9 @@ impl Application for Cosmicding {
 
     fn init(core: Core, flags: Self::Flags) -> (Self, Task<Self::Message>) {
         let timeline = Timeline::new();
+        block_on(async {
+            secrets::initialize().await;
+        });
         let db_pool = Some(block_on(async {
             db::SqliteDatabase::create().await.unwrap()
         }));
@@ -227,19 +262,27 @@ impl Application for Cosmicding {
             context_bookmark: None,
             context_bookmark_description: widget::text_editor::Content::new(),
             context_bookmark_notes: widget::text_editor::Content::new(),
+            context_sync_runs: Vec::new(),
             context_selected_account_index: 0,
             state: ApplicationState::NoEnabledRemoteAccounts,
+            tags_view: PageTagsView::default(),
             search_id: widget::Id::unique(),
             timeline,
             sync_status: SyncStatus::default(),
             toasts: widget::toaster::Toasts::new(ApplicationAction::CloseToast),
             operation_progress: None,
+            sync_states: HashMap::new(),
+            sync_failures: HashMap::new(),
+            is_background_sync: false,
         };
 
         app.bookmarks_cursor.items_per_page = app.config.items_per_page;
         app.accounts_cursor.items_per_page = app.config.items_per_page;
         // NOTE: (vkhitrin) probably wiser to initiate this field in the constructor above
         app.bookmarks_view.search_id = Some(app.search_id.clone());
+        if !secrets::is_locked() {
+            app.migrate_api_tokens();
+        }
 
         let commands = vec![
             app.update_title(),
@@ -310,6 +353,16 @@ impl Application for Cosmicding {
                 )
                 .title(self.context_page.title()),
             ),
+            ContextPage::AccountDetails => Some(
+                context_drawer::context_drawer(
+                    account_details(
+                        self.context_account.as_ref().unwrap(),
+                        &self.context_sync_runs,
+                    ),
+                    ApplicationAction::ContextClose,
+                )
+                .title(self.context_page.title()),
+            ),
             ContextPage::NewBookmarkForm => Some(
                 context_drawer::context_drawer(
                     new_bookmark(
@@ -543,6 +596,418 @@ impl Application for Cosmicding {
                             .on_press(ApplicationAction::DialogCancel),
                     )
             }
+            DialogPage::BookmarkConflict(account, _, local_bookmark, remote_bookmark, _) => {
+                let spacing = cosmic::theme::active().cosmic().spacing;
+                let yes_no = |value: bool| if value { fl!("yes") } else { fl!("no") };
+                let fields: Vec<(String, String, String)> = vec![
+                    (
+                        fl!("url"),
+                        local_bookmark.url.clone(),
+                        remote_bookmark.url.clone(),
+                    ),
+                    (
+                        fl!("title"),
+                        local_bookmark.title.clone(),
+                        remote_bookmark.title.clone(),
+                    ),
+                    (
+                        fl!("description"),
+                        local_bookmark.description.clone(),
+                        remote_bookmark.description.clone(),
+                    ),
+                    (
+                        fl!("notes"),
+                        local_bookmark.notes.clone(),
+                        remote_bookmark.notes.clone(),
+                    ),
+                    (
+                        fl!("tags"),
+                        local_bookmark.tag_names.join(" "),
+                        remote_bookmark.tag_names.join(" "),
+                    ),
+                    (
+                        fl!("archived"),
+                        yes_no(local_bookmark.is_archived),
+                        yes_no(remote_bookmark.is_archived),
+                    ),
+                    (
+                        fl!("unread"),
+                        yes_no(local_bookmark.unread),
+                        yes_no(remote_bookmark.unread),
+                    ),
+                    (
+                        fl!("shared"),
+                        yes_no(local_bookmark.shared),
+                        yes_no(remote_bookmark.shared),
+                    ),
+                ];
+
+                let mut fields_column = widget::column::with_capacity(fields.len() + 1)
+                    .spacing(spacing.space_xxs)
+                    .push(
+                        widget::row::with_capacity(3)
+                            .spacing(spacing.space_xs)
+                            .push(widget::horizontal_space().width(Length::FillPortion(1)))
+                            .push(
+                                widget::text::heading(fl!("local-version"))
+                                    .width(Length::FillPortion(2)),
+                            )
+                            .push(
+                                widget::text::heading(fl!("remote-version"))
+                                    .width(Length::FillPortion(2)),
+                            ),
+                    );
+                // NOTE: (vkhitrin) fields that differ between both versions are emphasized.
+                for (label, local_value, remote_value) in fields {
+                    let label = if local_value == remote_value {
+                        widget::text::body(label)
+                    } else {
+                        widget::text::heading(label)
+                    };
+                    fields_column = fields_column.push(
+                        widget::row::with_capacity(3)
+                            .spacing(spacing.space_xs)
+                            .push(label.width(Length::FillPortion(1)))
+                            .push(widget::text::body(local_value).width(Length::FillPortion(2)))
+                            .push(widget::text::body(remote_value).width(Length::FillPortion(2))),
+                    );
+                }
+
+                let fields_container = widget::container(
+                    widget::container(fields_column)
+                        .padding([spacing.space_xs, spacing.space_s])
+                        .width(Length::Fill),
+                )
+                .padding([spacing.space_xxs, 0])
+                .width(Length::Fill)
+                .class(theme::Container::Background);
+
+                widget::dialog()
+                    .title(fl!("bookmark-conflict"))
+                    .icon(icon::from_name("dialog-warning-symbolic").size(58))
+                    .body(fl!(
+                        "bookmark-conflict-body",
+                        bkmrk = local_bookmark.url.clone(),
+                        acc = account.display_name.clone()
+                    ))
+                    .control(fields_container)
+                    .primary_action(widget::button::suggested(fl!("keep-local")).on_press(
+                        ApplicationAction::ResolveBookmarkConflict(ConflictResolution::KeepLocal),
+                    ))
+                    .secondary_action(widget::button::standard(fl!("keep-remote")).on_press(
+                        ApplicationAction::ResolveBookmarkConflict(ConflictResolution::KeepRemote),
+                    ))
+                    .tertiary_action(widget::button::text(fl!("merge")).on_press(
+                        ApplicationAction::ResolveBookmarkConflict(ConflictResolution::Merge),
+                    ))
+            }
+            DialogPage::BulkAddTags(bookmark_ids, tags)
+            | DialogPage::BulkRemoveTags(bookmark_ids, tags) => {
+                let is_add = matches!(dialog_page, DialogPage::BulkAddTags(_, _));
+                let tag_names: Vec<String> = tags.split_whitespace().map(String::from).collect();
+                let action = if is_add {
+                    BulkAction::AddTags(tag_names.clone())
+                } else {
+                    BulkAction::RemoveTags(tag_names.clone())
+                };
+                widget::dialog()
+                    .icon(icon::from_name("tag-symbolic").size(58))
+                    .title(if is_add {
+                        fl!("add-tags")
+                    } else {
+                        fl!("remove-tags")
+                    })
+                    .body(fl!("bulk-tags-body", count = bookmark_ids.len()))
+                    .control(
+                        widget::text_input(fl!("tags"), tags.clone()).on_input(move |value| {
+                            ApplicationAction::DialogUpdate(if is_add {
+                                DialogPage::BulkAddTags(bookmark_ids.clone(), value)
+                            } else {
+                                DialogPage::BulkRemoveTags(bookmark_ids.clone(), value)
+                            })
+                        }),
+                    )
+                    .primary_action(widget::button::suggested(fl!("save")).on_press_maybe(
+                        (!tag_names.is_empty()).then_some(
+                            ApplicationAction::CompleteBulkActionDialog(
+                                action,
+                                bookmark_ids.clone(),
+                            ),
+                        ),
+                    ))
+                    .secondary_action(
+                        widget::button::standard(fl!("cancel"))
+                            .on_press(ApplicationAction::DialogCancel),
+                    )
+            }
+            DialogPage::BulkRemoveBookmarks(bookmark_ids) => widget::dialog()
+                .icon(icon::from_name("dialog-warning-symbolic").size(58))
+                .title(fl!("remove-bookmarks"))
+                .body(fl!("remove-bookmarks-confirm", count = bookmark_ids.len()))
+                .primary_action(widget::button::destructive(fl!("yes")).on_press(
+                    ApplicationAction::CompleteBulkActionDialog(
+                        BulkAction::Remove,
+                        bookmark_ids.clone(),
+                    ),
+                ))
+                .secondary_action(
+                    widget::button::standard(fl!("cancel"))
+                        .on_press(ApplicationAction::DialogCancel),
+                ),
+            DialogPage::AddReplicationRule(accounts, source_idx, target_idx, filter) => {
+                let spacing = cosmic::theme::active().cosmic().spacing;
+                let account_names: Vec<String> = accounts
+                    .iter()
+                    .map(|acc| acc.display_name.clone())
+                    .collect();
+                let source_dropdown =
+                    widget::dropdown(account_names.clone(), Some(*source_idx), move |idx| {
+                        ApplicationAction::DialogUpdate(DialogPage::AddReplicationRule(
+                            accounts.clone(),
+                            idx,
+                            *target_idx,
+                            filter.clone(),
+                        ))
+                    })
+                    .width(Length::Fixed(150.0));
+                let target_dropdown =
+                    widget::dropdown(account_names, Some(*target_idx), move |idx| {
+                        ApplicationAction::DialogUpdate(DialogPage::AddReplicationRule(
+                            accounts.clone(),
+                            *source_idx,
+                            idx,
+                            filter.clone(),
+                        ))
+                    })
+                    .width(Length::Fixed(150.0));
+                let body_column = widget::column::with_capacity(3)
+                    .spacing(spacing.space_s)
+                    .push(
+                        widget::row::with_capacity(2)
+                            .spacing(spacing.space_xs)
+                            .align_y(cosmic::iced::Alignment::Center)
+                            .push(
+                                widget::container(widget::text::body(fl!("source-account")))
+                                    .padding([spacing.space_xxs, spacing.space_xs])
+                                    .width(Length::Fixed(80.0)),
+                            )
+                            .push(
+                                widget::container(source_dropdown)
+                                    .class(theme::Container::Background),
+                            ),
+                    )
+                    .push(
+                        widget::row::with_capacity(2)
+                            .spacing(spacing.space_xs)
+                            .align_y(cosmic::iced::Alignment::Center)
+                            .push(
+                                widget::container(widget::text::body(fl!("target-account")))
+                                    .padding([spacing.space_xxs, spacing.space_xs])
+                                    .width(Length::Fixed(80.0)),
+                            )
+                            .push(
+                                widget::container(target_dropdown)
+                                    .class(theme::Container::Background),
+                            ),
+                    )
+                    .push(
+                        widget::text_input(fl!("replication-filter"), filter.clone()).on_input(
+                            move |value| {
+                                ApplicationAction::DialogUpdate(DialogPage::AddReplicationRule(
+                                    accounts.clone(),
+                                    *source_idx,
+                                    *target_idx,
+                                    value,
+                                ))
+                            },
+                        ),
+                    );
+
+                let rule = match (accounts[*source_idx].id, accounts[*target_idx].id) {
+                    (Some(source_id), Some(target_id))
+                        if source_id != target_id && search::parse_search_query(filter).is_ok() =>
+                    {
+                        Some(ReplicationRule::new(source_id, target_id, filter.clone()))
+                    }
+                    _ => None,
+                };
+                widget::dialog()
+                    .title(fl!("add-replication-rule"))
+                    .icon(icon::from_name("emblem-synchronizing-symbolic").size(58))
+                    .body(fl!("add-replication-rule-body"))
+                    .control(body_column)
+                    .primary_action(
+                        widget::button::suggested(fl!("save"))
+                            .on_press_maybe(rule.map(ApplicationAction::AddReplicationRule)),
+                    )
+                    .secondary_action(
+                        widget::button::standard(fl!("cancel"))
+                            .on_press(ApplicationAction::DialogCancel),
+                    )
+            }
+            DialogPage::TransferBookmarks(bookmark_ids, accounts, selected_idx, remove_source) => {
+                let spacing = cosmic::theme::active().cosmic().spacing;
+                let mut body_column = widget::column::with_capacity(2).spacing(spacing.space_s);
+                let account_names: Vec<String> = accounts
+                    .iter()
+                    .map(|acc| acc.display_name.clone())
+                    .collect();
+                body_column = body_column.push(
+                    widget::row::with_capacity(2)
+                        .spacing(spacing.space_xs)
+                        .align_y(cosmic::iced::Alignment::Center)
+                        .push(
+                            widget::container(widget::text::body(fl!("account")))
+                                .padding([spacing.space_xxs, spacing.space_xs])
+                                .align_y(cosmic::iced::alignment::Vertical::Center)
+                                .height(Length::Shrink),
+                        )
+                        .push(
+                            widget::container(
+                                widget::dropdown(account_names, Some(*selected_idx), move |idx| {
+                                    ApplicationAction::DialogUpdate(DialogPage::TransferBookmarks(
+                                        bookmark_ids.clone(),
+                                        accounts.clone(),
+                                        idx,
+                                        *remove_source,
+                                    ))
+                                })
+                                .width(Length::Fixed(150.0)),
+                            )
+                            .class(theme::Container::Background),
+                        ),
+                );
+                body_column = body_column.push(
+                    widget::checkbox(fl!("remove-from-source-account"), *remove_source).on_toggle(
+                        move |checked| {
+                            ApplicationAction::DialogUpdate(DialogPage::TransferBookmarks(
+                                bookmark_ids.clone(),
+                                accounts.clone(),
+                                *selected_idx,
+                                checked,
+                            ))
+                        },
+                    ),
+                );
+
+                let target_account = accounts[*selected_idx].clone();
+                let (label, action) = if *remove_source {
+                    (fl!("move"), BulkAction::MoveToAccount(target_account))
+                } else {
+                    (fl!("copy"), BulkAction::CopyToAccount(target_account))
+                };
+                widget::dialog()
+                    .title(fl!("transfer-bookmarks"))
+                    .icon(icon::from_name("go-jump-symbolic").size(58))
+                    .body(fl!("transfer-bookmarks-body", count = bookmark_ids.len()))
+                    .control(body_column)
+                    .primary_action(widget::button::suggested(label).on_press(
+                        ApplicationAction::CompleteBulkActionDialog(action, bookmark_ids.clone()),
+                    ))
+                    .secondary_action(
+                        widget::button::standard(fl!("cancel"))
+                            .on_press(ApplicationAction::DialogCancel),
+                    )
+            }
+            DialogPage::TagOperation(operation) => {
+                let source_tags = operation
+                    .source_tags()
+                    .iter()
+                    .map(|tag| format!("#{tag}"))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                let start_action = operation.is_valid().then(|| {
+                    ApplicationAction::CompleteBulkActionDialog(
+                        BulkAction::UpdateTags(operation.clone()),
+                        Vec::new(),
+                    )
+                });
+                match operation {
+                    TagOperation::Delete(_) => widget::dialog()
+                        .icon(icon::from_name("dialog-warning-symbolic").size(58))
+                        .title(fl!("remove-tags"))
+                        .body(fl!("remove-tags-confirm", tags = source_tags))
+                        .primary_action(
+                            widget::button::destructive(fl!("yes")).on_press_maybe(start_action),
+                        )
+                        .secondary_action(
+                            widget::button::standard(fl!("cancel"))
+                                .on_press(ApplicationAction::DialogCancel),
+                        ),
+                    TagOperation::Merge(tags, target_tag) => widget::dialog()
+                        .icon(icon::from_name("tag-symbolic").size(58))
+                        .title(fl!("merge-tags"))
+                        .body(fl!("merge-tags-body", tags = source_tags))
+                        .control(
+                            widget::text_input(fl!("tag-name"), target_tag.clone()).on_input(
+                                move |value| {
+                                    ApplicationAction::DialogUpdate(DialogPage::TagOperation(
+                                        TagOperation::Merge(tags.clone(), value),
+                                    ))
+                                },
+                            ),
+                        )
+                        .primary_action(
+                            widget::button::suggested(fl!("merge")).on_press_maybe(start_action),
+                        )
+                        .secondary_action(
+                            widget::button::standard(fl!("cancel"))
+                                .on_press(ApplicationAction::DialogCancel),
+                        ),
+                    TagOperation::Rename(tag, target_tag) => widget::dialog()
+                        .icon(icon::from_name("tag-symbolic").size(58))
+                        .title(fl!("rename-tag"))
+                        .body(fl!("rename-tag-body", tag = source_tags))
+                        .control(
+                            widget::text_input(fl!("tag-name"), target_tag.clone()).on_input(
+                                move |value| {
+                                    ApplicationAction::DialogUpdate(DialogPage::TagOperation(
+                                        TagOperation::Rename(tag.clone(), value),
+                                    ))
+                                },
+                            ),
+                        )
+                        .primary_action(
+                            widget::button::suggested(fl!("rename")).on_press_maybe(start_action),
+                        )
+                        .secondary_action(
+                            widget::button::standard(fl!("cancel"))
+                                .on_press(ApplicationAction::DialogCancel),
+                        ),
+                }
+            }
+            DialogPage::UnlockSecrets(is_new_passphrase, passphrase) => {
+                let is_new_passphrase = *is_new_passphrase;
+                widget::dialog()
+                    .icon(icon::from_name("dialog-password-symbolic").size(58))
+                    .title(fl!("unlock-secrets"))
+                    .body(if is_new_passphrase {
+                        fl!("create-secrets-passphrase-body")
+                    } else {
+                        fl!("unlock-secrets-body")
+                    })
+                    .control(
+                        widget::text_input(fl!("passphrase"), passphrase.clone())
+                            .on_input(move |value| {
+                                ApplicationAction::DialogUpdate(DialogPage::UnlockSecrets(
+                                    is_new_passphrase,
+                                    value,
+                                ))
+                            })
+                            .password(),
+                    )
+                    .primary_action(
+                        widget::button::suggested(fl!("unlock")).on_press_maybe(
+                            (!passphrase.is_empty()).then(|| {
+                                ApplicationAction::CompleteUnlockSecrets(passphrase.clone())
+                            }),
+                        ),
+                    )
+                    .secondary_action(
+                        widget::button::standard(fl!("cancel"))
+                            .on_press(ApplicationAction::DialogCancel),
+                    )
+            }
         };
 
         Some(dialog.into())
@@ -571,7 +1036,7 @@ impl Application for Cosmicding {
     fn subscription(&self) -> Subscription<Self::Message> {
         struct ThemeSubscription;
 
-        let subscriptions = vec![
+        let mut subscriptions = vec![
             event::listen_with(|event, status, _| match event {
                 Event::Keyboard(KeyEvent::KeyPressed { key, modifiers, .. }) => match status {
                     event::Status::Ignored => Some(ApplicationAction::Key(modifiers, key)),
@@ -603,6 +1068,14 @@ impl Application for Cosmicding {
             //     .map(|(_id, instant)| ApplicationAction::Tick(instant)),
             cosmic::iced::time::every(Duration::from_millis(250)).map(ApplicationAction::Tick),
         ];
+        if self.config.enable_background_sync {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(
+                    BACKGROUND_SYNC_CHECK_INTERVAL_SECONDS,
+                ))
+                .map(|_| ApplicationAction::StartBackgroundSync),
+            );
+        }
         Subscription::batch(subscriptions)
     }
 
@@ -658,9 +1131,16 @@ impl Application for Cosmicding {
                 commands.push(self.update(ApplicationAction::LoadAccounts));
                 commands.push(self.update(ApplicationAction::LoadBookmarks));
             }
+            ApplicationAction::SetSyncPageSize(sync_page_size) => {
+                config_set!(sync_page_size, sync_page_size);
+            }
             ApplicationAction::SystemThemeModeChange => {
                 return self.update_config();
             }
+            ApplicationAction::EnableBackgroundSync(enable_background_sync) => {
+                config_set!(enable_background_sync, enable_background_sync);
+                self.config.enable_background_sync = enable_background_sync;
+            }
             ApplicationAction::EnableFavicons(enable_favicon) => {
                 config_set!(enable_favicons, enable_favicon);
                 self.config.enable_favicons = enable_favicon;
@@ -669,6 +1149,14 @@ impl Application for Cosmicding {
                 let account_page_entity = &self.nav.entity_at(0);
                 self.nav.activate(account_page_entity.unwrap());
             }
+            ApplicationAction::FilterBookmarksByTag(tag) => {
+                let bookmarks_page_entity = &self.nav.entity_at(1);
+                self.nav.activate(bookmarks_page_entity.unwrap());
+                commands.push(self.update_title());
+                commands.push(self.bookmarks_view.update(BookmarksAction::SearchBookmarks(
+                    format!("tag:{}", search::quote(&tag)),
+                )));
+            }
             ApplicationAction::Tick(now) => {
                 self.timeline.now(now);
             }
@@ -697,6 +1185,12 @@ impl Application for Cosmicding {
                     )
                     .await
                 });
+                self.accounts_view.replication_rules = block_on(async {
+                    db::SqliteDatabase::select_replication_rules(
+                        self.bookmarks_cursor.database.as_mut().unwrap(),
+                    )
+                    .await
+                });
 
                 if self.context_selected_account_index >= self.context_accounts_list.len() {
                     self.context_selected_account_index = if self.context_accounts_list.is_empty() {
@@ -711,15 +1205,16 @@ impl Application for Cosmicding {
                 if let Some(ref mut database) = &mut self.accounts_cursor.database {
                     for account in &mut self.accounts_view.accounts {
                         if account.is_local_provider() {
-                            let current_version =
-                                provider::get_provider_version(account.provider(), None);
+                            let current_version = provider::get_provider_version(account, None);
                             if account.provider_version != current_version {
                                 account.provider_version = current_version;
                                 let account_clone = account.clone();
-                                block_on(async {
+                                if let Err(e) = block_on(async {
                                     db::SqliteDatabase::update_account(database, &account_clone)
-                                        .await;
-                                });
+                                        .await
+                                }) {
+                                    log::error!("Failed to update account: {e}");
+                                }
                             }
                         }
                     }
@@ -727,10 +1222,7 @@ impl Application for Cosmicding {
 
                 self.accounts_view
                     .accounts
-                    .sort_by_key(|account| match account.provider() {
-                        Provider::Cosmicding => 0,
-                        Provider::Linkding => 1,
-                    });
+                    .sort_by_key(|account| !account.is_local_provider());
                 // FIXME: (vkhitrin) If an account is deleted during refresh (should not be
                 //        possible without interacting with the database manually, a crash will
                 //        occur if an account context window is open.
@@ -749,7 +1241,7 @@ impl Application for Cosmicding {
                     String::new(),
                     String::new(),
                     String::new(),
-                    Provider::Linkding,
+                    LINKDING_PROVIDER,
                 ));
                 commands.push(self.update(ApplicationAction::ToggleContextPage(
                     ContextPage::AddAccountForm,
@@ -761,6 +1253,23 @@ impl Application for Cosmicding {
                     ContextPage::EditAccountForm,
                 )));
             }
+            ApplicationAction::ViewAccountDetails(account) => {
+                self.context_sync_runs = match (&mut self.bookmarks_cursor.database, account.id) {
+                    (Some(database), Some(account_id)) => block_on(async {
+                        db::SqliteDatabase::select_sync_runs(
+                            database,
+                            account_id,
+                            MAX_SYNC_RUNS_PER_ACCOUNT,
+                        )
+                        .await
+                    }),
+                    _ => Vec::new(),
+                };
+                self.context_account = Some(account);
+                commands.push(self.update(ApplicationAction::ToggleContextPage(
+                    ContextPage::AccountDetails,
+                )));
+            }
             ApplicationAction::RemoveAccount(account) => {
                 if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
                     block_on(async {
@@ -777,9 +1286,31 @@ impl Application for Cosmicding {
                         )
                         .await;
                     });
+                    block_on(async {
+                        db::SqliteDatabase::delete_all_pending_operations_of_account(
+                            database,
+                            account.id.unwrap(),
+                        )
+                        .await;
+                    });
+                    block_on(async {
+                        db::SqliteDatabase::delete_all_replication_rules_of_account(
+                            database,
+                            account.id.unwrap(),
+                        )
+                        .await;
+                    });
+                    block_on(async {
+                        db::SqliteDatabase::delete_all_sync_runs_of_account(
+                            database,
+                            account.id.unwrap(),
+                        )
+                        .await;
+                    });
                     block_on(async {
                         db::SqliteDatabase::delete_account(database, account.id.unwrap()).await;
                     });
+                    provider::remove_http_client(account.id.unwrap());
                     self.bookmarks_view
                         .bookmarks
                         .retain(|bkmrk| bkmrk.user_account_id != Some(account.id.unwrap()));
@@ -798,10 +1329,7 @@ impl Application for Cosmicding {
                     self.accounts_view.accounts = self.accounts_cursor.result.clone().unwrap();
                     self.accounts_view
                         .accounts
-                        .sort_by_key(|account| match account.provider() {
-                            Provider::Cosmicding => 0,
-                            Provider::Linkding => 1,
-                        });
+                        .sort_by_key(|account| !account.is_local_provider());
                     commands.push(self.update(ApplicationAction::LoadBookmarks));
                 }
             }
@@ -823,7 +1351,7 @@ impl Application for Cosmicding {
                         );
                     } else {
                         let cloned_acc = account.clone();
-                        let message = move |api_response: Option<LinkdingAccountApiResponse>| {
+                        let message = move |api_response: Option<AccountDetails>| {
                             cosmic::Action::App(ApplicationAction::DoneAddAccount(
                                 cloned_acc.clone(),
                                 api_response,
@@ -840,12 +1368,24 @@ impl Application for Cosmicding {
                 if let Some(response) = api_response {
                     if response.error.is_none() {
                         account.provider_version =
-                            provider::get_provider_version(account.provider(), Some(&response));
+                            provider::get_provider_version(&account, Some(&response));
 
                         if let Some(ref mut database) = &mut self.accounts_cursor.database {
-                            block_on(async {
-                                db::SqliteDatabase::create_account(database, &account).await;
-                            });
+                            if let Err(e) = block_on(async {
+                                db::SqliteDatabase::create_account(database, &account).await
+                            }) {
+                                commands.push(
+                                    self.toasts
+                                        .push(widget::toaster::Toast::new(fl!(
+                                            "failed-to-add-account",
+                                            acc = account.display_name,
+                                            err = e.to_string()
+                                        )))
+                                        .map(cosmic::Action::App),
+                                );
+                                self.core.window.show_context = false;
+                                return Task::batch(commands);
+                            }
                             commands.push(self.update(ApplicationAction::LoadAccounts));
                             commands.push(self.update(
                                 ApplicationAction::StartRefreshBookmarksForAccount(
@@ -878,7 +1418,7 @@ impl Application for Cosmicding {
             ApplicationAction::StartEditAccount(account) => {
                 let cloned_acc = account.clone();
                 if account.enabled {
-                    let message = move |api_response: Option<LinkdingAccountApiResponse>| {
+                    let message = move |api_response: Option<AccountDetails>| {
                         cosmic::Action::App(ApplicationAction::DoneEditAccount(
                             cloned_acc.clone(),
                             api_response,
@@ -905,11 +1445,22 @@ impl Application for Cosmicding {
                             account.enable_public_sharing = response.enable_public_sharing;
                             account.enable_sharing = response.enable_sharing;
                             account.provider_version =
-                                provider::get_provider_version(account.provider(), Some(&response));
+                                provider::get_provider_version(&account, Some(&response));
                             if current_account != account {
-                                block_on(async {
-                                    db::SqliteDatabase::update_account(database, &account).await;
-                                });
+                                if let Err(e) = block_on(async {
+                                    db::SqliteDatabase::update_account(database, &account).await
+                                }) {
+                                    commands.push(
+                                        self.toasts
+                                            .push(widget::toaster::Toast::new(fl!(
+                                                "failed-to-edit-account",
+                                                acc = account.display_name,
+                                                err = e.to_string()
+                                            )))
+                                            .map(cosmic::Action::App),
+                                    );
+                                    return Task::batch(commands);
+                                }
                                 commands.push(
                                     self.toasts
                                         .push(widget::toaster::Toast::new(fl!(
@@ -941,13 +1492,24 @@ impl Application for Cosmicding {
                     } else {
                         if account.is_local_provider() {
                             account.provider_version =
-                                provider::get_provider_version(account.provider(), None);
+                                provider::get_provider_version(&account, None);
                         }
 
                         if current_account != account {
-                            block_on(async {
-                                db::SqliteDatabase::update_account(database, &account).await;
-                            });
+                            if let Err(e) = block_on(async {
+                                db::SqliteDatabase::update_account(database, &account).await
+                            }) {
+                                commands.push(
+                                    self.toasts
+                                        .push(widget::toaster::Toast::new(fl!(
+                                            "failed-to-edit-account",
+                                            acc = account.display_name,
+                                            err = e.to_string()
+                                        )))
+                                        .map(cosmic::Action::App),
+                                );
+                                return Task::batch(commands);
+                            }
                             commands.push(
                                 self.toasts
                                     .push(widget::toaster::Toast::new(fl!(
@@ -967,7 +1529,34 @@ impl Application for Cosmicding {
             ApplicationAction::BookmarksView(message) => {
                 commands.push(self.bookmarks_view.update(message));
             }
+            ApplicationAction::TagsView(message) => {
+                commands.push(self.tags_view.update(message));
+            }
+            ApplicationAction::LoadTags => {
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    self.tags_view.tags =
+                        block_on(async { db::SqliteDatabase::select_tags(database).await });
+                    let tags = &self.tags_view.tags;
+                    self.tags_view
+                        .selected_tags
+                        .retain(|selected_tag| tags.iter().any(|tag| &tag.name == selected_tag));
+                }
+            }
             ApplicationAction::StartRefreshBookmarksForAllAccounts => {
+                // NOTE: (vkhitrin) API tokens can't be read until the secret storage is unlocked,
+                //       the passphrase is requested again if the dialog was dismissed.
+                if secrets::is_locked() {
+                    if !matches!(
+                        self.dialog_pages.front(),
+                        Some(DialogPage::UnlockSecrets(_, _))
+                    ) {
+                        self.dialog_pages.push_back(DialogPage::UnlockSecrets(
+                            secrets::requires_new_passphrase(),
+                            String::new(),
+                        ));
+                    }
+                    return Task::batch(commands);
+                }
                 if !self.accounts_view.accounts.is_empty() {
                     if let ApplicationState::Refreshing = self.state {
                     } else {
@@ -996,43 +1585,164 @@ impl Application for Cosmicding {
                             let first_account = enabled_accounts[0].clone();
                             let remaining_accounts = enabled_accounts[1..].to_vec();
 
-                            let message = move |response: DetailedResponse| {
-                                cosmic::Action::App(ApplicationAction::DoneRefreshSingleAccount(
-                                    response,
-                                    remaining_accounts.clone(),
-                                ))
-                            };
-
-                            commands.push(Task::perform(
-                                provider::fetch_bookmarks_for_single_account(first_account),
-                                message,
-                            ));
+                            commands.push(
+                                self.fetch_bookmarks_for_account(first_account, remaining_accounts),
+                            );
                         }
                     }
                 }
             }
-            ApplicationAction::DoneRefreshSingleAccount(response, remaining_accounts) => {
-                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
-                    if !response.successful {
-                        log::error!(
-                            "Failed to refresh account: {}",
-                            response.account.display_name
+            // NOTE: (vkhitrin) accounts are synced in the background once their sync interval
+            //       has passed, syncs are skipped while other operations are running.
+            ApplicationAction::StartBackgroundSync => {
+                let is_busy = matches!(
+                    self.state,
+                    ApplicationState::Loading | ApplicationState::Refreshing
+                ) || self.operation_progress.is_some();
+                if self.config.enable_background_sync && !is_busy && !secrets::is_locked() {
+                    let epoch_timestamp = provider::current_timestamp();
+                    let due_accounts: Vec<Account> = self
+                        .context_accounts_list
+                        .iter()
+                        .filter(|account| {
+                            let consecutive_failures = account
+                                .id
+                                .and_then(|account_id| self.sync_failures.get(&account_id))
+                                .copied()
+                                .unwrap_or_default();
+                            account
+                                .next_sync_timestamp(consecutive_failures)
+                                .is_some_and(|timestamp| timestamp <= epoch_timestamp)
+                        })
+                        .cloned()
+                        .collect();
+                    if !due_accounts.is_empty() {
+                        log::info!(
+                            "Starting background sync of {} accounts",
+                            due_accounts.len()
+                        );
+                        self.is_background_sync = true;
+                        self.state = ApplicationState::Refreshing;
+                        self.operation_progress = Some(OperationProgress {
+                            operation_id: 0,
+                            total: due_accounts.len(),
+                            current: 0,
+                            operation_label: fl!("refreshing-accounts"),
+                            cancellable: false,
+                        });
+
+                        let first_account = due_accounts[0].clone();
+                        let remaining_accounts = due_accounts[1..].to_vec();
+
+                        commands.push(
+                            self.fetch_bookmarks_for_account(first_account, remaining_accounts),
                         );
                     }
-                    block_on(async {
-                        db::SqliteDatabase::aggregate_bookmarks_for_account(
-                            database,
-                            &response.account,
-                            response.bookmarks.unwrap_or_else(Vec::new),
-                            response.timestamp,
-                            response.successful,
+                }
+            }
+            // NOTE: (vkhitrin) the overall progress tracks accounts, pages of the account that
+            //       is being synced are displayed as part of the label.
+            ApplicationAction::ReceivedBookmarksPage(page) => {
+                if let Some(ref mut progress) = self.operation_progress {
+                    progress.operation_label = fl!(
+                        "refreshing-account-pages",
+                        acc = page.account.display_name.clone(),
+                        page = page.fetched_pages,
+                        pages = page.total_pages
+                    );
+                }
+                if let (Some(account_id), Some(ref mut database)) =
+                    (page.account.id, &mut self.bookmarks_cursor.database)
+                {
+                    let sync_state = self.sync_states.entry(account_id).or_default();
+                    block_on(async {
+                        db::SqliteDatabase::aggregate_bookmarks_page(
+                            database,
+                            &page.account,
+                            page.bookmarks,
+                            sync_state,
                         )
                         .await;
                     });
                 }
+            }
+            ApplicationAction::DoneRefreshSingleAccount(mut response, remaining_accounts) => {
+                // NOTE: (vkhitrin) pages were aggregated while fetching, the final response may
+                //       contain bookmarks that were not streamed.
+                let mut sync_state = response
+                    .account
+                    .id
+                    .and_then(|account_id| self.sync_states.remove(&account_id))
+                    .unwrap_or_default();
+                let bookmarks = response.bookmarks.take().unwrap_or_default();
+                if let Some(account_id) = response.account.id {
+                    if response.successful {
+                        self.sync_failures.remove(&account_id);
+                    } else {
+                        *self.sync_failures.entry(account_id).or_default() += 1;
+                    }
+                }
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    if !response.successful {
+                        log::error!(
+                            "Failed to refresh account: {}",
+                            response.account.display_name
+                        );
+                    } else if !response.is_complete() {
+                        log::warn!(
+                            "Partially refreshed account {}: {}",
+                            response.account.display_name,
+                            response.error.clone().unwrap_or_default()
+                        );
+                    }
+                    let sync_counts = block_on(async {
+                        if response.successful {
+                            db::SqliteDatabase::aggregate_bookmarks_page(
+                                database,
+                                &response.account,
+                                bookmarks,
+                                &mut sync_state,
+                            )
+                            .await;
+                        }
+                        db::SqliteDatabase::complete_bookmarks_sync(
+                            database,
+                            &response.account,
+                            sync_state,
+                            response.timestamp,
+                            response.successful,
+                            response.is_full_sync,
+                            response.is_complete(),
+                        )
+                        .await
+                    });
+                    // NOTE: (vkhitrin) local accounts are not synced, there is nothing to
+                    //       diagnose.
+                    if let (Some(account_id), false) =
+                        (response.account.id, response.account.is_local_provider())
+                    {
+                        let epoch_timestamp = provider::current_timestamp();
+                        let sync_run =
+                            SyncRun::new(account_id, &response, sync_counts, epoch_timestamp);
+                        block_on(async {
+                            db::SqliteDatabase::add_sync_run(database, &sync_run).await;
+                        });
+                        if self.context_page == ContextPage::AccountDetails
+                            && self
+                                .context_account
+                                .as_ref()
+                                .is_some_and(|account| account.id == Some(account_id))
+                        {
+                            self.context_sync_runs.insert(0, sync_run);
+                            self.context_sync_runs
+                                .truncate(usize::try_from(MAX_SYNC_RUNS_PER_ACCOUNT).unwrap());
+                        }
+                    }
+                }
 
                 if let Some(ref mut progress) = self.operation_progress {
                     progress.current += 1;
+                    progress.operation_label = fl!("refreshing-accounts");
 
                     if remaining_accounts.is_empty() {
                         self.operation_progress = None;
@@ -1040,33 +1750,132 @@ impl Application for Cosmicding {
                         commands.push(self.update(ApplicationAction::LoadBookmarks));
                         self.state = ApplicationState::Ready;
                         self.sync_status = SyncStatus::Successful;
-                        commands.push(
-                            self.toasts
-                                .push(widget::toaster::Toast::new(fl!("refreshed-bookmarks")))
-                                .map(cosmic::Action::App),
-                        );
+                        if self.is_background_sync {
+                            self.is_background_sync = false;
+                        } else {
+                            commands.push(
+                                self.toasts
+                                    .push(widget::toaster::Toast::new(fl!("refreshed-bookmarks")))
+                                    .map(cosmic::Action::App),
+                            );
+                        }
                     } else {
                         let next_account = remaining_accounts[0].clone();
                         let remaining = remaining_accounts[1..].to_vec();
 
-                        let message = move |response: DetailedResponse| {
-                            cosmic::Action::App(ApplicationAction::DoneRefreshSingleAccount(
-                                response,
-                                remaining.clone(),
-                            ))
-                        };
-
-                        commands.push(Task::perform(
-                            provider::fetch_bookmarks_for_single_account(next_account),
-                            message,
-                        ));
+                        commands.push(self.fetch_bookmarks_for_account(next_account, remaining));
                     }
                 }
             }
 
-            ApplicationAction::StartRefreshBookmarksForAccount(account) => {
+            ApplicationAction::DoneReplayPendingOperations(
+                account,
+                results,
+                remaining_accounts,
+            ) => {
+                let mut failed_count: usize = 0;
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    for result in results {
+                        if result.unreachable {
+                            log::warn!(
+                                "Unable to replay pending operations for account {}",
+                                account.display_name
+                            );
+                            break;
+                        }
+                        if let Some(error) = result.error {
+                            log::error!(
+                                "Failed to replay pending operation for bookmark {}: {error}",
+                                result.operation.bookmark.url
+                            );
+                            if let Some(operation_id) = result.operation.id {
+                                block_on(async {
+                                    db::SqliteDatabase::fail_pending_operation(
+                                        database,
+                                        operation_id,
+                                        &error,
+                                    )
+                                    .await;
+                                });
+                            }
+                            failed_count += 1;
+                            continue;
+                        } else if let (Some(remote_bookmark), Some(local_bookmark)) =
+                            (result.conflict, result.bookmark.clone())
+                        {
+                            // NOTE: (vkhitrin) the cached bookmark was already replaced by the
+                            //       local changes, local fields are preferred when merging.
+                            let is_presented = self.dialog_pages.iter().any(|page| {
+                                matches!(
+                                    page,
+                                    DialogPage::BookmarkConflict(_, _, _, _, Some(operation_id))
+                                        if Some(*operation_id) == result.operation.id
+                                )
+                            });
+                            if !is_presented {
+                                self.dialog_pages.push_back(DialogPage::BookmarkConflict(
+                                    account.clone(),
+                                    None,
+                                    local_bookmark,
+                                    remote_bookmark,
+                                    result.operation.id,
+                                ));
+                            }
+                            continue;
+                        } else if let Some(mut bkmrk) = result.bookmark {
+                            bkmrk.is_owner = Some(true);
+                            match (result.operation.kind, result.operation.bookmark_id) {
+                                (PendingOperationKind::Add, Some(bookmark_id)) => {
+                                    block_on(async {
+                                        db::SqliteDatabase::complete_pending_bookmark(
+                                            database,
+                                            bookmark_id,
+                                            &bkmrk,
+                                        )
+                                        .await;
+                                    });
+                                }
+                                (PendingOperationKind::Edit, _) => {
+                                    block_on(async {
+                                        db::SqliteDatabase::update_bookmark(
+                                            database, &bkmrk, &bkmrk,
+                                        )
+                                        .await;
+                                    });
+                                }
+                                _ => {}
+                            }
+                        }
+                        if let Some(operation_id) = result.operation.id {
+                            block_on(async {
+                                db::SqliteDatabase::delete_pending_operation(
+                                    database,
+                                    operation_id,
+                                )
+                                .await;
+                            });
+                        }
+                    }
+                }
+                if failed_count > 0 {
+                    commands.push(
+                        self.toasts
+                            .push(widget::toaster::Toast::new(fl!(
+                                "failed-to-replay-bookmark-changes",
+                                count = failed_count,
+                                acc = account.display_name.clone()
+                            )))
+                            .map(cosmic::Action::App),
+                    );
+                }
+                commands.push(self.stream_bookmarks_for_account(account, remaining_accounts));
+            }
+            ApplicationAction::StartRefreshBookmarksForAccount(mut account) => {
                 if let ApplicationState::Refreshing = self.state {
                 } else if account.enabled {
+                    // NOTE: (vkhitrin) refreshing a single account always performs a full sync,
+                    //       the account may have been modified since the previous sync.
+                    account.sync_cursor = None;
                     self.state = ApplicationState::Refreshing;
 
                     self.operation_progress = Some(OperationProgress {
@@ -1077,21 +1886,10 @@ impl Application for Cosmicding {
                         cancellable: false,
                     });
 
-                    let cloned_account = account.clone();
-                    let message = move |response: DetailedResponse| {
-                        cosmic::Action::App(ApplicationAction::DoneRefreshSingleAccount(
-                            response,
-                            Vec::new(),
-                        ))
-                    };
-
                     commands.push(self.update(ApplicationAction::StartRefreshAccountProfile(
                         account.clone(),
                     )));
-                    commands.push(Task::perform(
-                        provider::fetch_bookmarks_for_single_account(cloned_account),
-                        message,
-                    ));
+                    commands.push(self.fetch_bookmarks_for_account(account, Vec::new()));
                 } else {
                     let has_enabled_remote_accounts = self
                         .accounts_view
@@ -1110,7 +1908,7 @@ impl Application for Cosmicding {
                 } else if account.enabled {
                     self.state = ApplicationState::Refreshing;
                     let cloned_acc = account.clone();
-                    let message = move |api_response: Option<LinkdingAccountApiResponse>| {
+                    let message = move |api_response: Option<AccountDetails>| {
                         cosmic::Action::App(ApplicationAction::DoneRefreshAccountProfile(
                             cloned_acc.clone(),
                             api_response,
@@ -1125,12 +1923,14 @@ impl Application for Cosmicding {
             ApplicationAction::DoneRefreshAccountProfile(mut account, api_response) => {
                 if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
                     if let Some(response) = api_response {
-                        if response.successful.expect("") {
+                        if response.successful {
                             account.enable_sharing = response.enable_sharing;
                             account.enable_public_sharing = response.enable_public_sharing;
-                            block_on(async {
-                                db::SqliteDatabase::update_account(database, &account).await;
-                            });
+                            if let Err(e) = block_on(async {
+                                db::SqliteDatabase::update_account(database, &account).await
+                            }) {
+                                log::error!("Failed to update account: {e}");
+                            }
                             commands.push(self.update(ApplicationAction::LoadAccounts));
                         }
                     }
@@ -1205,11 +2005,77 @@ impl Application for Cosmicding {
                     account.api_token = key;
                 }
             }
+            ApplicationAction::SetAccountUsername(username) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.username = (!username.is_empty()).then_some(username);
+                }
+            }
+            ApplicationAction::SetAccountClientId(client_id) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.client_id = (!client_id.is_empty()).then_some(client_id);
+                }
+            }
+            ApplicationAction::SetAccountClientSecret(client_secret) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.client_secret = client_secret;
+                }
+            }
+            ApplicationAction::SetAccountCACertificatePath(path) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.ca_certificate_path = (!path.is_empty()).then_some(path);
+                }
+            }
+            ApplicationAction::SetAccountClientCertificatePath(path) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.client_certificate_path = (!path.is_empty()).then_some(path);
+                }
+            }
+            ApplicationAction::SetAccountClientKeyPath(path) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.client_key_path = (!path.is_empty()).then_some(path);
+                }
+            }
+            ApplicationAction::SetAccountProxyURL(url) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.proxy_url = (!url.is_empty()).then_some(url);
+                }
+            }
+            ApplicationAction::AddAccountHeader => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.extra_headers.0.push(RequestHeader::default());
+                }
+            }
+            ApplicationAction::SetAccountHeaderName(index, name) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    if let Some(header) = account.extra_headers.0.get_mut(index) {
+                        header.name = name;
+                    }
+                }
+            }
+            ApplicationAction::SetAccountHeaderValue(index, value) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    if let Some(header) = account.extra_headers.0.get_mut(index) {
+                        header.value = value;
+                    }
+                }
+            }
+            ApplicationAction::RemoveAccountHeader(index) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    if index < account.extra_headers.0.len() {
+                        account.extra_headers.0.remove(index);
+                    }
+                }
+            }
             ApplicationAction::SetAccountStatus(enabled) => {
                 if let Some(ref mut account) = &mut self.context_account {
                     account.enabled = enabled;
                 }
             }
+            ApplicationAction::SetAccountSyncInterval(sync_interval_minutes) => {
+                if let Some(ref mut account) = &mut self.context_account {
+                    account.sync_interval_minutes = sync_interval_minutes;
+                }
+            }
             ApplicationAction::SetAccountTrustInvalidCertificates(trust) => {
                 if let Some(ref mut account) = &mut self.context_account {
                     account.trust_invalid_certs = trust;
@@ -1217,7 +2083,7 @@ impl Application for Cosmicding {
             }
             ApplicationAction::SetAccountProvider(provider) => {
                 if let Some(ref mut account) = &mut self.context_account {
-                    account.set_provider(provider);
+                    account.set_provider(&provider);
                 }
             }
             ApplicationAction::AddBookmarkFormAccountIndex(idx) => {
@@ -1393,6 +2259,15 @@ impl Application for Cosmicding {
                                     ));
                                 }
                             }
+                        } else if response.unreachable && import_context.is_none() {
+                            if let Some(bkmrk) = response.bookmark {
+                                let kind = if bkmrk.exists_remotely() {
+                                    PendingOperationKind::Edit
+                                } else {
+                                    PendingOperationKind::Add
+                                };
+                                commands.push(self.queue_pending_operation(&account, kind, bkmrk));
+                            }
                         } else {
                             commands.push(
                                 self.toasts
@@ -1423,19 +2298,27 @@ impl Application for Cosmicding {
                     let account: Account = block_on(async {
                         db::SqliteDatabase::select_single_account(database, account_id).await
                     });
-                    let cloned_account = account.clone();
-                    let cloned_bookmark = bookmark.clone();
-                    let message = move |api_response: Option<BookmarkRemoveResponse>| {
-                        cosmic::Action::App(ApplicationAction::DoneRemoveBookmark(
-                            cloned_account.clone(),
-                            cloned_bookmark.clone(),
-                            api_response,
-                        ))
-                    };
-                    commands.push(Task::perform(
-                        provider::remove_bookmark(account, bookmark),
-                        message,
-                    ));
+                    if self.has_pending_operation(&bookmark) {
+                        commands.push(self.queue_pending_operation(
+                            &account,
+                            PendingOperationKind::Remove,
+                            bookmark,
+                        ));
+                    } else {
+                        let cloned_account = account.clone();
+                        let cloned_bookmark = bookmark.clone();
+                        let message = move |api_response: Option<BookmarkRemoveResponse>| {
+                            cosmic::Action::App(ApplicationAction::DoneRemoveBookmark(
+                                cloned_account.clone(),
+                                cloned_bookmark.clone(),
+                                api_response,
+                            ))
+                        };
+                        commands.push(Task::perform(
+                            provider::remove_bookmark(account, bookmark),
+                            message,
+                        ));
+                    }
                 }
                 self.core.window.show_context = false;
             }
@@ -1465,6 +2348,12 @@ impl Application for Cosmicding {
                             commands.push(self.update(ApplicationAction::LoadBookmarks));
                             self.bookmarks_view.bookmarks =
                                 self.bookmarks_cursor.result.clone().unwrap();
+                        } else if response.unreachable {
+                            commands.push(self.queue_pending_operation(
+                                &account,
+                                PendingOperationKind::Remove,
+                                bookmark,
+                            ));
                         } else {
                             commands.push(
                                 self.toasts
@@ -1502,23 +2391,50 @@ impl Application for Cosmicding {
                     .map(String::from)
                     .collect();
 
-                let cloned_acc = account.clone();
-                let message = move |api_response: Option<BookmarkCheckDetailsResponse>| {
-                    cosmic::Action::App(ApplicationAction::DoneEditBookmark(
-                        cloned_acc.clone(),
-                        api_response,
-                    ))
-                };
-                commands.push(Task::perform(
-                    provider::populate_bookmark(account, bookmark, false, false),
-                    message,
-                ));
+                if self.has_pending_operation(&bookmark) {
+                    commands.push(self.queue_pending_operation(
+                        &account,
+                        PendingOperationKind::Edit,
+                        bookmark,
+                    ));
+                } else {
+                    let cloned_acc = account.clone();
+                    let message = move |api_response: Option<BookmarkCheckDetailsResponse>| {
+                        cosmic::Action::App(ApplicationAction::DoneEditBookmark(
+                            cloned_acc.clone(),
+                            api_response,
+                        ))
+                    };
+                    commands.push(Task::perform(
+                        provider::populate_bookmark(account, bookmark, false, false),
+                        message,
+                    ));
+                }
                 self.core.window.show_context = false;
             }
             ApplicationAction::DoneEditBookmark(account, api_response) => {
                 if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
                     if let Some(response) = api_response {
-                        if response.error.is_none() {
+                        if let Some(remote_bookmark) = response.conflict {
+                            if let Some(local_bookmark) = response.bookmark {
+                                let cached_bookmark = local_bookmark.id.and_then(|bookmark_id| {
+                                    block_on(async {
+                                        db::SqliteDatabase::select_single_bookmark(
+                                            database,
+                                            bookmark_id,
+                                        )
+                                        .await
+                                    })
+                                });
+                                self.dialog_pages.push_back(DialogPage::BookmarkConflict(
+                                    account,
+                                    cached_bookmark,
+                                    local_bookmark,
+                                    remote_bookmark,
+                                    None,
+                                ));
+                            }
+                        } else if response.error.is_none() {
                             if let Some(mut bkmrk) = response.bookmark {
                                 bkmrk.is_owner = Some(true);
                                 block_on(async {
@@ -1536,6 +2452,14 @@ impl Application for Cosmicding {
                                 );
                                 commands.push(self.update(ApplicationAction::LoadBookmarks));
                             }
+                        } else if response.unreachable {
+                            if let Some(bkmrk) = response.bookmark {
+                                commands.push(self.queue_pending_operation(
+                                    &account,
+                                    PendingOperationKind::Edit,
+                                    bkmrk,
+                                ));
+                            }
                         } else {
                             commands.push(
                                 self.toasts
@@ -1579,6 +2503,7 @@ impl Application for Cosmicding {
             }
             ApplicationAction::Modifiers(modifiers) => {
                 self.modifiers = modifiers;
+                self.bookmarks_view.modifiers = modifiers;
             }
             ApplicationAction::UpdateConfig(config) => {
                 self.config = config;
@@ -1595,6 +2520,61 @@ impl Application for Cosmicding {
                         .push_back(DialogPage::RemoveBookmark(account_id, bookmark));
                 }
             }
+            ApplicationAction::OpenTagOperationDialog(operation) => {
+                if self.dialog_pages.pop_front().is_none() {
+                    self.dialog_pages
+                        .push_back(DialogPage::TagOperation(operation));
+                }
+            }
+            ApplicationAction::OpenBulkActionDialog(action, bookmark_ids) => {
+                let dialog_page = match action {
+                    BulkAction::AddTags(tags) => {
+                        Some(DialogPage::BulkAddTags(bookmark_ids, tags.join(" ")))
+                    }
+                    BulkAction::Remove => Some(DialogPage::BulkRemoveBookmarks(bookmark_ids)),
+                    BulkAction::RemoveTags(tags) => {
+                        Some(DialogPage::BulkRemoveTags(bookmark_ids, tags.join(" ")))
+                    }
+                    _ => None,
+                };
+                if let Some(dialog_page) = dialog_page {
+                    if self.dialog_pages.pop_front().is_none() {
+                        self.dialog_pages.push_back(dialog_page);
+                    }
+                }
+            }
+            ApplicationAction::OpenTransferBookmarksDialog(bookmark_ids) => {
+                let enabled_accounts: Vec<Account> = self
+                    .accounts_view
+                    .accounts
+                    .iter()
+                    .filter(|acc| acc.enabled)
+                    .cloned()
+                    .collect();
+                if !enabled_accounts.is_empty() && self.dialog_pages.pop_front().is_none() {
+                    self.dialog_pages.push_back(DialogPage::TransferBookmarks(
+                        bookmark_ids,
+                        enabled_accounts,
+                        0,
+                        true,
+                    ));
+                }
+            }
+            ApplicationAction::SelectAllMatchingBookmarks => {
+                let search_query = match &self.bookmarks_cursor.search_query {
+                    Some(query) => search::parse_search_query(query).unwrap_or_default(),
+                    None => search::SearchQuery::default(),
+                };
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    let bookmark_ids = block_on(async {
+                        db::SqliteDatabase::select_matching_bookmark_ids(database, &search_query)
+                            .await
+                    });
+                    self.bookmarks_view
+                        .selected_bookmark_ids
+                        .extend(bookmark_ids);
+                }
+            }
             ApplicationAction::OpenPurgeFaviconsCache => {
                 if self.dialog_pages.pop_front().is_none() {
                     self.dialog_pages
@@ -1620,14 +2600,99 @@ impl Application for Cosmicding {
                             commands.push(self.update(ApplicationAction::LoadBookmarks));
                         }
                         DialogPage::ExportBookmarks(_, _, _)
-                        | DialogPage::ImportBookmarks(_, _, _) => {}
+                        | DialogPage::ImportBookmarks(_, _, _)
+                        | DialogPage::BookmarkConflict(_, _, _, _, _)
+                        | DialogPage::AddReplicationRule(_, _, _, _)
+                        | DialogPage::BulkAddTags(_, _)
+                        | DialogPage::BulkRemoveBookmarks(_)
+                        | DialogPage::BulkRemoveTags(_, _)
+                        | DialogPage::TagOperation(_)
+                        | DialogPage::TransferBookmarks(_, _, _, _)
+                        | DialogPage::UnlockSecrets(_, _) => {}
                     }
                 }
                 commands.push(self.update(ApplicationAction::LoadAccounts));
             }
+            ApplicationAction::ResolveBookmarkConflict(resolution) => {
+                if let Some(DialogPage::BookmarkConflict(
+                    account,
+                    cached_bookmark,
+                    mut local_bookmark,
+                    remote_bookmark,
+                    operation_id,
+                )) = self.dialog_pages.pop_front()
+                {
+                    // NOTE: (vkhitrin) the replayed operation is kept until the conflict is
+                    //       resolved, the resolution replaces it.
+                    if let (Some(operation_id), Some(database)) =
+                        (operation_id, &mut self.bookmarks_cursor.database)
+                    {
+                        self.bookmarks_view.pending_bookmark_ids = block_on(async {
+                            db::SqliteDatabase::delete_pending_operation(database, operation_id)
+                                .await;
+                            db::SqliteDatabase::select_pending_bookmark_ids(database).await
+                        });
+                    }
+                    match resolution {
+                        ConflictResolution::KeepLocal => {
+                            // NOTE: (vkhitrin) the edit is submitted against the remote
+                            //       bookmark, conflicts are detected again if the bookmark
+                            //       was modified in the meantime.
+                            local_bookmark
+                                .date_modified
+                                .clone_from(&remote_bookmark.date_modified);
+                            commands.push(self.update(ApplicationAction::StartEditBookmark(
+                                account,
+                                local_bookmark,
+                            )));
+                        }
+                        ConflictResolution::KeepRemote => {
+                            if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                                block_on(async {
+                                    db::SqliteDatabase::update_bookmark(
+                                        database,
+                                        &remote_bookmark,
+                                        &remote_bookmark,
+                                    )
+                                    .await;
+                                });
+                            }
+                            commands.push(self.update(ApplicationAction::LoadBookmarks));
+                        }
+                        ConflictResolution::Merge => {
+                            let merged_bookmark = local_bookmark
+                                .merge_conflict(cached_bookmark.as_ref(), remote_bookmark);
+                            commands.push(self.update(ApplicationAction::StartEditBookmark(
+                                account,
+                                merged_bookmark,
+                            )));
+                        }
+                    }
+                }
+            }
             ApplicationAction::DialogCancel => {
                 self.dialog_pages.pop_front();
             }
+            ApplicationAction::CompleteUnlockSecrets(passphrase) => {
+                match secrets::unlock(&passphrase) {
+                    Ok(()) => {
+                        self.dialog_pages.pop_front();
+                        self.migrate_api_tokens();
+                        commands.push(self.update(ApplicationAction::LoadAccounts));
+                        commands.push(self.update(ApplicationAction::StartupCompleted));
+                    }
+                    Err(e) => {
+                        commands.push(
+                            self.toasts
+                                .push(widget::toaster::Toast::new(fl!(
+                                    "failed-to-unlock-secrets",
+                                    err = e.to_string()
+                                )))
+                                .map(cosmic::Action::App),
+                        );
+                    }
+                }
+            }
             ApplicationAction::StartExportBookmarks => {
                 let enabled_accounts: Vec<Account> = self
                     .accounts_view
@@ -1960,10 +3025,794 @@ impl Application for Cosmicding {
                     );
                 }
             }
-            ApplicationAction::CloseToast(id) => {
-                self.toasts.remove(id);
+            ApplicationAction::CompleteBulkActionDialog(action, bookmark_ids) => {
+                self.dialog_pages.pop_front();
+                commands
+                    .push(self.update(ApplicationAction::StartBulkAction(action, bookmark_ids)));
             }
-            ApplicationAction::LoadBookmarks => {
+            // NOTE: (vkhitrin) tag operations affect all bookmarks with the source tags.
+            ApplicationAction::StartBulkAction(action, bookmark_ids) => {
+                let is_busy = matches!(
+                    self.state,
+                    ApplicationState::Loading | ApplicationState::Refreshing
+                ) || self.operation_progress.is_some();
+                if is_busy {
+                    return Task::batch(commands);
+                }
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    let (accounts, bookmarks) = block_on(async {
+                        let bookmarks = if let BulkAction::UpdateTags(operation) = &action {
+                            db::SqliteDatabase::select_bookmarks_with_tags(
+                                database,
+                                &operation.source_tags(),
+                            )
+                            .await
+                        } else {
+                            db::SqliteDatabase::select_bookmarks_by_ids(database, &bookmark_ids)
+                                .await
+                        };
+                        (
+                            db::SqliteDatabase::select_accounts(database).await,
+                            bookmarks,
+                        )
+                    });
+                    let bookmarks = match bookmarks {
+                        Ok(bookmarks) => bookmarks,
+                        Err(e) => {
+                            log::error!("Failed to select bookmarks for bulk action: {e}");
+                            commands.push(
+                                self.toasts
+                                    .push(widget::toaster::Toast::new(e.to_string()))
+                                    .map(cosmic::Action::App),
+                            );
+                            return Task::batch(commands);
+                        }
+                    };
+                    // NOTE: (vkhitrin) bookmarks can only be shared by accounts that have
+                    //       sharing enabled.
+                    let remaining_bookmarks: Vec<(Account, Bookmark)> = bookmarks
+                        .iter()
+                        .filter_map(|bookmark| {
+                            let account = accounts
+                                .iter()
+                                .find(|account| account.id == bookmark.user_account_id)?;
+                            if matches!(action, BulkAction::Share)
+                                && !(account.enable_sharing
+                                    && provider::capabilities(account).sharing)
+                            {
+                                return None;
+                            }
+                            Some((account.clone(), action.apply(bookmark)?))
+                        })
+                        .collect();
+
+                    let operation_id = new_operation_id();
+                    self.state = ApplicationState::Refreshing;
+                    self.operation_progress = Some(OperationProgress {
+                        operation_id,
+                        total: remaining_bookmarks.len(),
+                        current: 0,
+                        operation_label: match action {
+                            BulkAction::CopyToAccount(_) => fl!("copying-bookmarks"),
+                            BulkAction::MoveToAccount(_) => fl!("moving-bookmarks"),
+                            BulkAction::Remove => fl!("removing-bookmarks"),
+                            BulkAction::UpdateTags(_) => fl!("updating-tags"),
+                            _ => fl!("updating-bookmarks"),
+                        },
+                        cancellable: true,
+                    });
+                    commands.push(self.update(ApplicationAction::StartBulkActionForBookmark(
+                        BulkActionContext {
+                            operation_id,
+                            action,
+                            updated_count: 0,
+                            skipped_count: 0,
+                            conflict_attempts: 0,
+                        },
+                        remaining_bookmarks,
+                    )));
+                }
+            }
+            ApplicationAction::StartBulkActionForBookmark(
+                mut bulk_context,
+                mut remaining_bookmarks,
+            ) => {
+                let is_cancelled = match &self.operation_progress {
+                    None => true,
+                    Some(progress) => progress.operation_id != bulk_context.operation_id,
+                };
+                if is_cancelled || remaining_bookmarks.is_empty() {
+                    if is_cancelled {
+                        log::debug!(
+                            "Skipping {} bookmarks - bulk action {} was cancelled",
+                            remaining_bookmarks.len(),
+                            bulk_context.operation_id
+                        );
+                    }
+                    commands.push(self.update(ApplicationAction::DoneBulkAction(bulk_context)));
+                    return Task::batch(commands);
+                }
+
+                let (account, bookmark) = remaining_bookmarks.remove(0);
+                match bulk_context.action.clone() {
+                    BulkAction::CopyToAccount(target_account)
+                    | BulkAction::MoveToAccount(target_account) => {
+                        // NOTE: (vkhitrin) the bookmark is created in the target account, when
+                        //       moving it is removed from the source account once it was created.
+                        let mut transferred_bookmark = bookmark.clone();
+                        transferred_bookmark.id = None;
+                        transferred_bookmark.provider_internal_id = None;
+                        transferred_bookmark.provider_remote_id = None;
+                        transferred_bookmark.user_account_id = target_account.id;
+                        transferred_bookmark.is_owner = Some(true);
+                        transferred_bookmark.favicon_cached = None;
+                        // NOTE: (vkhitrin) states that are not supported by the target
+                        //       account are dropped.
+                        let capabilities = provider::capabilities(&target_account);
+                        transferred_bookmark.is_archived &= capabilities.archiving;
+                        transferred_bookmark.unread &= capabilities.unread;
+                        transferred_bookmark.shared &=
+                            capabilities.sharing && target_account.enable_sharing;
+                        let message = move |api_response: Option<BookmarkCheckDetailsResponse>| {
+                            cosmic::Action::App(ApplicationAction::DoneBulkTransferBookmark(
+                                bulk_context.clone(),
+                                account.clone(),
+                                bookmark.clone(),
+                                api_response,
+                                remaining_bookmarks.clone(),
+                            ))
+                        };
+                        commands.push(Task::perform(
+                            provider::populate_bookmark(
+                                target_account,
+                                transferred_bookmark,
+                                true,
+                                true,
+                            ),
+                            message,
+                        ));
+                    }
+                    BulkAction::Remove if !self.has_pending_operation(&bookmark) => {
+                        let remove_task =
+                            provider::remove_bookmark(account.clone(), bookmark.clone());
+                        let message = move |api_response: Option<BookmarkRemoveResponse>| {
+                            cosmic::Action::App(ApplicationAction::DoneBulkRemoveBookmark(
+                                bulk_context.clone(),
+                                account.clone(),
+                                bookmark.clone(),
+                                api_response,
+                                remaining_bookmarks.clone(),
+                            ))
+                        };
+                        commands.push(Task::perform(remove_task, message));
+                    }
+                    BulkAction::Remove => {
+                        self.enqueue_pending_operation(
+                            &account,
+                            PendingOperationKind::Remove,
+                            bookmark,
+                        );
+                        bulk_context.updated_count += 1;
+                        commands.push(self.continue_bulk_action(bulk_context, remaining_bookmarks));
+                    }
+                    _ if self.has_pending_operation(&bookmark) => {
+                        self.enqueue_pending_operation(
+                            &account,
+                            PendingOperationKind::Edit,
+                            bookmark,
+                        );
+                        bulk_context.updated_count += 1;
+                        commands.push(self.continue_bulk_action(bulk_context, remaining_bookmarks));
+                    }
+                    _ => {
+                        let cloned_acc = account.clone();
+                        let message = move |api_response: Option<BookmarkCheckDetailsResponse>| {
+                            cosmic::Action::App(ApplicationAction::DoneBulkEditBookmark(
+                                bulk_context.clone(),
+                                cloned_acc.clone(),
+                                api_response,
+                                remaining_bookmarks.clone(),
+                            ))
+                        };
+                        commands.push(Task::perform(
+                            provider::edit_bookmark(account, bookmark),
+                            message,
+                        ));
+                    }
+                }
+            }
+            ApplicationAction::DoneBulkEditBookmark(
+                mut bulk_context,
+                account,
+                api_response,
+                mut remaining_bookmarks,
+            ) => {
+                if let Some(response) = api_response {
+                    if let Some(remote_bookmark) = response.conflict {
+                        // NOTE: (vkhitrin) the bulk action is the only local change, it is
+                        //       applied on top of the remote bookmark and submitted again.
+                        let edited_bookmark = bulk_context.action.apply(&remote_bookmark);
+                        if edited_bookmark.is_some()
+                            && bulk_context.conflict_attempts >= MAX_CONFLICT_RETRIES
+                        {
+                            bulk_context.skipped_count += 1;
+                        } else if let Some(edited_bookmark) = edited_bookmark {
+                            bulk_context.conflict_attempts += 1;
+                            remaining_bookmarks.insert(0, (account, edited_bookmark));
+                            commands.push(self.update(
+                                ApplicationAction::StartBulkActionForBookmark(
+                                    bulk_context,
+                                    remaining_bookmarks,
+                                ),
+                            ));
+                            return Task::batch(commands);
+                        }
+                        if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                            block_on(async {
+                                db::SqliteDatabase::update_bookmark(
+                                    database,
+                                    &remote_bookmark,
+                                    &remote_bookmark,
+                                )
+                                .await;
+                            });
+                        }
+                    } else if response.error.is_none() {
+                        if let Some(mut bkmrk) = response.bookmark {
+                            bkmrk.is_owner = Some(true);
+                            if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                                block_on(async {
+                                    db::SqliteDatabase::update_bookmark(database, &bkmrk, &bkmrk)
+                                        .await;
+                                });
+                            }
+                            bulk_context.updated_count += 1;
+                        }
+                    } else if response.unreachable {
+                        if let Some(bkmrk) = response.bookmark {
+                            self.enqueue_pending_operation(
+                                &account,
+                                PendingOperationKind::Edit,
+                                bkmrk,
+                            );
+                            bulk_context.updated_count += 1;
+                        }
+                    } else {
+                        commands.push(self.fail_bulk_action(
+                            &bulk_context,
+                            response.error.unwrap(),
+                            remaining_bookmarks.len(),
+                        ));
+                    }
+                }
+                commands.push(self.continue_bulk_action(bulk_context, remaining_bookmarks));
+            }
+            ApplicationAction::DoneBulkTransferBookmark(
+                mut bulk_context,
+                account,
+                bookmark,
+                api_response,
+                remaining_bookmarks,
+            ) => {
+                let (target_account, remove_source) = match bulk_context.action.clone() {
+                    BulkAction::CopyToAccount(target_account) => (target_account, false),
+                    BulkAction::MoveToAccount(target_account) => (target_account, true),
+                    _ => return Task::batch(commands),
+                };
+                if let Some(response) = api_response {
+                    let mut is_created = false;
+                    if response.error.is_none() {
+                        if let Some(mut bkmrk) = response.bookmark {
+                            bkmrk.is_owner = Some(true);
+                            if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                                block_on(async {
+                                    if response.is_new {
+                                        db::SqliteDatabase::add_bookmark(database, &bkmrk).await;
+                                    } else {
+                                        db::SqliteDatabase::update_bookmark(
+                                            database, &bkmrk, &bkmrk,
+                                        )
+                                        .await;
+                                    }
+                                });
+                            }
+                            is_created = true;
+                        }
+                    } else if response.unreachable {
+                        let bkmrk = response.bookmark.unwrap_or_else(|| {
+                            let mut transferred_bookmark = bookmark.clone();
+                            transferred_bookmark.id = None;
+                            transferred_bookmark.provider_internal_id = None;
+                            transferred_bookmark.provider_remote_id = None;
+                            transferred_bookmark.favicon_cached = None;
+                            transferred_bookmark
+                        });
+                        self.enqueue_pending_operation(
+                            &target_account,
+                            PendingOperationKind::Add,
+                            bkmrk,
+                        );
+                        is_created = true;
+                    } else {
+                        commands.push(self.fail_bulk_action(
+                            &bulk_context,
+                            response.error.unwrap(),
+                            remaining_bookmarks.len(),
+                        ));
+                    }
+                    if is_created && !remove_source {
+                        bulk_context.updated_count += 1;
+                    } else if is_created {
+                        if self.has_pending_operation(&bookmark) {
+                            self.enqueue_pending_operation(
+                                &account,
+                                PendingOperationKind::Remove,
+                                bookmark,
+                            );
+                            bulk_context.updated_count += 1;
+                        } else {
+                            let remove_task =
+                                provider::remove_bookmark(account.clone(), bookmark.clone());
+                            let message = move |api_response: Option<BookmarkRemoveResponse>| {
+                                cosmic::Action::App(ApplicationAction::DoneBulkRemoveBookmark(
+                                    bulk_context.clone(),
+                                    account.clone(),
+                                    bookmark.clone(),
+                                    api_response,
+                                    remaining_bookmarks.clone(),
+                                ))
+                            };
+                            commands.push(Task::perform(remove_task, message));
+                            return Task::batch(commands);
+                        }
+                    }
+                }
+                commands.push(self.continue_bulk_action(bulk_context, remaining_bookmarks));
+            }
+            ApplicationAction::DoneBulkRemoveBookmark(
+                mut bulk_context,
+                account,
+                bookmark,
+                api_response,
+                remaining_bookmarks,
+            ) => {
+                if let Some(response) = api_response {
+                    if response.error.is_none() {
+                        if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                            block_on(async {
+                                db::SqliteDatabase::delete_bookmark(database, &bookmark).await;
+                            });
+                        }
+                        bulk_context.updated_count += 1;
+                    } else if response.unreachable {
+                        self.enqueue_pending_operation(
+                            &account,
+                            PendingOperationKind::Remove,
+                            bookmark,
+                        );
+                        bulk_context.updated_count += 1;
+                    } else {
+                        commands.push(self.fail_bulk_action(
+                            &bulk_context,
+                            response.error.unwrap(),
+                            remaining_bookmarks.len(),
+                        ));
+                    }
+                }
+                commands.push(self.continue_bulk_action(bulk_context, remaining_bookmarks));
+            }
+            ApplicationAction::CancelBulkAction(operation_id) => {
+                log::info!("Bulk action {} cancelled", operation_id);
+
+                self.operation_progress = None;
+            }
+            ApplicationAction::DoneBulkAction(bulk_context) => {
+                self.state = ApplicationState::Ready;
+                self.operation_progress = None;
+                self.bookmarks_view.selected_bookmark_ids.clear();
+                self.tags_view.selected_tags.clear();
+
+                let count = bulk_context.updated_count;
+                commands.push(
+                    self.toasts
+                        .push(widget::toaster::Toast::new(match bulk_context.action {
+                            BulkAction::CopyToAccount(account) => fl!(
+                                "copied-bookmarks-to-account",
+                                count = count,
+                                acc = account.display_name
+                            ),
+                            BulkAction::MoveToAccount(account) => fl!(
+                                "moved-bookmarks-to-account",
+                                count = count,
+                                acc = account.display_name
+                            ),
+                            BulkAction::Remove => fl!("removed-bookmarks", count = count),
+                            _ => fl!("updated-bookmarks", count = count),
+                        }))
+                        .map(cosmic::Action::App),
+                );
+                if bulk_context.skipped_count > 0 {
+                    commands.push(
+                        self.toasts
+                            .push(widget::toaster::Toast::new(fl!(
+                                "skipped-conflicting-bookmarks",
+                                count = bulk_context.skipped_count
+                            )))
+                            .map(cosmic::Action::App),
+                    );
+                }
+                commands.push(self.update(ApplicationAction::LoadBookmarks));
+                commands.push(self.update(ApplicationAction::LoadTags));
+            }
+            ApplicationAction::OpenAddReplicationRuleDialog => {
+                let enabled_accounts: Vec<Account> = self
+                    .context_accounts_list
+                    .iter()
+                    .filter(|acc| acc.enabled)
+                    .cloned()
+                    .collect();
+                if enabled_accounts.len() > 1 && self.dialog_pages.pop_front().is_none() {
+                    self.dialog_pages.push_back(DialogPage::AddReplicationRule(
+                        enabled_accounts,
+                        0,
+                        1,
+                        String::new(),
+                    ));
+                }
+            }
+            ApplicationAction::AddReplicationRule(rule) => {
+                self.dialog_pages.pop_front();
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    block_on(async {
+                        db::SqliteDatabase::add_replication_rule(database, &rule).await;
+                    });
+                    commands.push(
+                        self.toasts
+                            .push(widget::toaster::Toast::new(fl!("added-replication-rule")))
+                            .map(cosmic::Action::App),
+                    );
+                }
+                commands.push(self.update(ApplicationAction::LoadAccounts));
+            }
+            ApplicationAction::RemoveReplicationRule(rule) => {
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    if let Some(rule_id) = rule.id {
+                        block_on(async {
+                            db::SqliteDatabase::delete_replication_rule(database, rule_id).await;
+                        });
+                        commands.push(
+                            self.toasts
+                                .push(widget::toaster::Toast::new(fl!("removed-replication-rule")))
+                                .map(cosmic::Action::App),
+                        );
+                    }
+                }
+                commands.push(self.update(ApplicationAction::LoadAccounts));
+            }
+            // NOTE: (vkhitrin) the target bookmarks are looked up in the local database, the
+            //       target account should be synced before replicating.
+            ApplicationAction::StartReplication(rule) => {
+                let search_query = match search::parse_search_query(&rule.filter) {
+                    Ok(query) => query,
+                    Err(e) => {
+                        commands.push(
+                            self.toasts
+                                .push(widget::toaster::Toast::new(e))
+                                .map(cosmic::Action::App),
+                        );
+                        return Task::batch(commands);
+                    }
+                };
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    let (accounts, source_bookmarks) = block_on(async {
+                        (
+                            db::SqliteDatabase::select_accounts(database).await,
+                            db::SqliteDatabase::select_replication_source_bookmarks(
+                                database,
+                                rule.source_account_id,
+                                &search_query,
+                            )
+                            .await,
+                        )
+                    });
+                    let Some(target_account) = accounts
+                        .into_iter()
+                        .find(|acc| acc.id == Some(rule.target_account_id) && acc.enabled)
+                    else {
+                        commands.push(
+                            self.toasts
+                                .push(widget::toaster::Toast::new(fl!(
+                                    "replication-target-unavailable"
+                                )))
+                                .map(cosmic::Action::App),
+                        );
+                        return Task::batch(commands);
+                    };
+                    let source_urls: Vec<String> = source_bookmarks
+                        .iter()
+                        .map(|bookmark| bookmark.url.clone())
+                        .collect();
+                    let target_bookmarks = match block_on(async {
+                        db::SqliteDatabase::select_bookmarks_by_urls(
+                            database,
+                            rule.target_account_id,
+                            &source_urls,
+                        )
+                        .await
+                    }) {
+                        Ok(target_bookmarks) => target_bookmarks,
+                        Err(e) => {
+                            log::error!("Failed to select target bookmarks: {e}");
+                            commands.push(
+                                self.toasts
+                                    .push(widget::toaster::Toast::new(e.to_string()))
+                                    .map(cosmic::Action::App),
+                            );
+                            return Task::batch(commands);
+                        }
+                    };
+                    let mut summary = ReplicationSummary::default();
+                    let mut remaining_bookmarks: Vec<(Bookmark, Bookmark)> = Vec::new();
+                    for source_bookmark in source_bookmarks {
+                        match replication::replicate_bookmark(
+                            &source_bookmark,
+                            target_bookmarks.get(&source_bookmark.url),
+                        ) {
+                            Some(replicated_bookmark) => {
+                                remaining_bookmarks.push((source_bookmark, replicated_bookmark));
+                            }
+                            None => summary.skipped += 1,
+                        }
+                    }
+
+                    let operation_id = new_operation_id();
+                    self.state = ApplicationState::Refreshing;
+                    self.operation_progress = Some(OperationProgress {
+                        operation_id,
+                        total: remaining_bookmarks.len(),
+                        current: 0,
+                        operation_label: fl!("replicating-bookmarks"),
+                        cancellable: true,
+                    });
+                    commands.push(self.update(ApplicationAction::StartReplicationForBookmark(
+                        ReplicationContext {
+                            operation_id,
+                            rule,
+                            account: target_account,
+                            summary,
+                            conflict_attempts: 0,
+                        },
+                        remaining_bookmarks,
+                    )));
+                }
+            }
+            ApplicationAction::StartReplicationForBookmark(
+                mut replication_context,
+                mut remaining_bookmarks,
+            ) => {
+                let is_cancelled = match &self.operation_progress {
+                    None => true,
+                    Some(progress) => progress.operation_id != replication_context.operation_id,
+                };
+                if is_cancelled || remaining_bookmarks.is_empty() {
+                    if is_cancelled {
+                        log::debug!(
+                            "Skipping {} bookmarks - replication {} was cancelled",
+                            remaining_bookmarks.len(),
+                            replication_context.operation_id
+                        );
+                    }
+                    commands
+                        .push(self.update(ApplicationAction::DoneReplication(replication_context)));
+                    return Task::batch(commands);
+                }
+
+                let (source_bookmark, replicated_bookmark) = remaining_bookmarks.remove(0);
+                let account = replication_context.account.clone();
+                let is_new =
+                    replicated_bookmark.id.is_none() && !replicated_bookmark.exists_remotely();
+                if !is_new && self.has_pending_operation(&replicated_bookmark) {
+                    self.enqueue_pending_operation(
+                        &account,
+                        PendingOperationKind::Edit,
+                        replicated_bookmark,
+                    );
+                    replication_context.summary.updated += 1;
+                    replication_context.conflict_attempts = 0;
+                    if let Some(ref mut progress) = self.operation_progress {
+                        progress.current += 1;
+                    }
+                    commands.push(self.update(ApplicationAction::StartReplicationForBookmark(
+                        replication_context,
+                        remaining_bookmarks,
+                    )));
+                    return Task::batch(commands);
+                }
+                let requested_bookmark = replicated_bookmark.clone();
+                let message = move |api_response: Option<BookmarkCheckDetailsResponse>| {
+                    cosmic::Action::App(ApplicationAction::DoneReplicateBookmark(
+                        replication_context.clone(),
+                        (source_bookmark.clone(), requested_bookmark.clone()),
+                        api_response,
+                        remaining_bookmarks.clone(),
+                    ))
+                };
+                if is_new {
+                    commands.push(Task::perform(
+                        provider::populate_bookmark(account, replicated_bookmark, true, true),
+                        message,
+                    ));
+                } else {
+                    commands.push(Task::perform(
+                        provider::edit_bookmark(account, replicated_bookmark),
+                        message,
+                    ));
+                }
+            }
+            ApplicationAction::DoneReplicateBookmark(
+                mut replication_context,
+                (source_bookmark, requested_bookmark),
+                api_response,
+                mut remaining_bookmarks,
+            ) => {
+                let is_new =
+                    requested_bookmark.id.is_none() && !requested_bookmark.exists_remotely();
+                let account = replication_context.account.clone();
+                if let Some(response) = api_response {
+                    if let Some(remote_bookmark) = response.conflict {
+                        // NOTE: (vkhitrin) the source bookmark takes precedence, it is applied
+                        //       on top of the remote bookmark and submitted again.
+                        let replicated_bookmark = replication::replicate_bookmark(
+                            &source_bookmark,
+                            Some(&remote_bookmark),
+                        )
+                        .filter(|_| replication_context.conflict_attempts < MAX_CONFLICT_RETRIES);
+                        if let Some(replicated_bookmark) = replicated_bookmark {
+                            replication_context.conflict_attempts += 1;
+                            remaining_bookmarks.insert(0, (source_bookmark, replicated_bookmark));
+                            commands.push(self.update(
+                                ApplicationAction::StartReplicationForBookmark(
+                                    replication_context,
+                                    remaining_bookmarks,
+                                ),
+                            ));
+                            return Task::batch(commands);
+                        }
+                        // NOTE: (vkhitrin) the remote bookmark kept changing, it is cached as is
+                        //       and the source bookmark is reported as not replicated.
+                        log::warn!(
+                            "Bookmark {} was not replicated, it kept conflicting with remote changes",
+                            source_bookmark.url
+                        );
+                        if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                            block_on(async {
+                                db::SqliteDatabase::update_bookmark(
+                                    database,
+                                    &remote_bookmark,
+                                    &remote_bookmark,
+                                )
+                                .await;
+                            });
+                        }
+                        replication_context.summary.conflicted += 1;
+                    } else if response.error.is_none() {
+                        if let Some(mut bkmrk) = response.bookmark {
+                            bkmrk.is_owner = Some(true);
+                            if is_new && !response.is_new {
+                                // NOTE: (vkhitrin) the bookmark exists in the target account
+                                //       but was not synced yet, it is updated instead.
+                                let replicated_bookmark =
+                                    replication::replicate_bookmark(&source_bookmark, Some(&bkmrk))
+                                        .unwrap_or(bkmrk);
+                                remaining_bookmarks
+                                    .insert(0, (source_bookmark, replicated_bookmark));
+                                commands.push(self.update(
+                                    ApplicationAction::StartReplicationForBookmark(
+                                        replication_context,
+                                        remaining_bookmarks,
+                                    ),
+                                ));
+                                return Task::batch(commands);
+                            }
+                            if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                                block_on(async {
+                                    if is_new {
+                                        db::SqliteDatabase::add_bookmark(database, &bkmrk).await;
+                                    } else {
+                                        db::SqliteDatabase::update_bookmark(
+                                            database,
+                                            &requested_bookmark,
+                                            &bkmrk,
+                                        )
+                                        .await;
+                                    }
+                                });
+                            }
+                            if is_new {
+                                replication_context.summary.created += 1;
+                            } else {
+                                replication_context.summary.updated += 1;
+                            }
+                        }
+                    } else if response.unreachable {
+                        if let Some(bkmrk) = response.bookmark {
+                            if is_new {
+                                self.enqueue_pending_operation(
+                                    &account,
+                                    PendingOperationKind::Add,
+                                    bkmrk,
+                                );
+                                replication_context.summary.created += 1;
+                            } else {
+                                self.enqueue_pending_operation(
+                                    &account,
+                                    PendingOperationKind::Edit,
+                                    bkmrk,
+                                );
+                                replication_context.summary.updated += 1;
+                            }
+                        }
+                    } else {
+                        let error = response.error.unwrap();
+                        log::error!(
+                            "Replication failed: {}. Cancelling remaining {} bookmarks.",
+                            error,
+                            remaining_bookmarks.len()
+                        );
+                        self.operation_progress = None;
+                        commands.push(
+                            self.toasts
+                                .push(widget::toaster::Toast::new(error))
+                                .map(cosmic::Action::App),
+                        );
+                    }
+                }
+                if let Some(ref mut progress) = self.operation_progress {
+                    progress.current += 1;
+                }
+                replication_context.conflict_attempts = 0;
+                commands.push(self.update(ApplicationAction::StartReplicationForBookmark(
+                    replication_context,
+                    remaining_bookmarks,
+                )));
+            }
+            ApplicationAction::DoneReplication(replication_context) => {
+                self.state = ApplicationState::Ready;
+                self.operation_progress = None;
+
+                let epoch_timestamp = provider::current_timestamp();
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    if let Some(rule_id) = replication_context.rule.id {
+                        block_on(async {
+                            db::SqliteDatabase::update_replication_rule_summary(
+                                database,
+                                rule_id,
+                                &replication_context.summary,
+                                epoch_timestamp,
+                            )
+                            .await;
+                        });
+                    }
+                }
+                commands.push(
+                    self.toasts
+                        .push(widget::toaster::Toast::new(fl!(
+                            "replicated-bookmarks",
+                            created = replication_context.summary.created,
+                            updated = replication_context.summary.updated,
+                            skipped = replication_context.summary.skipped,
+                            conflicted = replication_context.summary.conflicted
+                        )))
+                        .map(cosmic::Action::App),
+                );
+                commands.push(self.update(ApplicationAction::LoadAccounts));
+                commands.push(self.update(ApplicationAction::LoadBookmarks));
+                commands.push(self.update(ApplicationAction::LoadTags));
+            }
+            ApplicationAction::CloseToast(id) => {
+                self.toasts.remove(id);
+            }
+            ApplicationAction::LoadBookmarks => {
                 match self.config.sort_option {
                     SortOption::BookmarksDateNewest => {
                         self.bookmarks_cursor.sort_option = SortOption::BookmarksDateNewest;
@@ -1979,6 +3828,9 @@ impl Application for Cosmicding {
                         self.bookmarks_cursor.sort_option =
                             SortOption::BookmarkAlphabeticalDescending;
                     }
+                    SortOption::BookmarksRelevance => {
+                        self.bookmarks_cursor.sort_option = SortOption::BookmarksRelevance;
+                    }
                 }
                 block_on(async {
                     self.bookmarks_cursor.fetch_next_results().await;
@@ -1992,7 +3844,21 @@ impl Application for Cosmicding {
                         );
                     }
                 }
+                if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+                    (
+                        self.bookmarks_view.pending_bookmark_ids,
+                        self.bookmarks_view.failed_bookmark_errors,
+                    ) = block_on(async {
+                        (
+                            db::SqliteDatabase::select_pending_bookmark_ids(database).await,
+                            db::SqliteDatabase::select_failed_pending_operations(database).await,
+                        )
+                    });
+                }
                 self.bookmarks_view.bookmarks = self.bookmarks_cursor.result.clone().unwrap();
+                if self.nav.data::<AppNavPage>(self.nav.active()) == Some(&AppNavPage::TagsView) {
+                    commands.push(self.update(ApplicationAction::LoadTags));
+                }
             }
             ApplicationAction::IncrementPageIndex(cursor_type) => {
                 if cursor_type == "bookmarks" {
@@ -2029,6 +3895,11 @@ impl Application for Cosmicding {
             ApplicationAction::StartFetchFaviconForBookmark(bookmark) => {
                 if let Some(favicon_url) = bookmark.favicon_url.clone() {
                     if !favicon_url.is_empty() {
+                        let favicon_account = self
+                            .context_accounts_list
+                            .iter()
+                            .find(|account| account.id == bookmark.user_account_id)
+                            .cloned();
                         if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
                             let favicon_url_clone = favicon_url.clone();
                             block_on(async {
@@ -2069,7 +3940,10 @@ impl Application for Cosmicding {
                                         )
                                     };
                                     commands.push(Task::perform(
-                                        provider::fetch_bookmark_favicon(favicon_url_clone.clone()),
+                                        provider::fetch_bookmark_favicon(
+                                            favicon_account.clone(),
+                                            favicon_url_clone.clone(),
+                                        ),
                                         message,
                                     ));
                                 }
@@ -2106,6 +3980,13 @@ impl Application for Cosmicding {
                 }
             }
             ApplicationAction::StartupCompleted => {
+                if secrets::is_locked() {
+                    self.dialog_pages.push_back(DialogPage::UnlockSecrets(
+                        secrets::requires_new_passphrase(),
+                        String::new(),
+                    ));
+                    return Task::batch(commands);
+                }
                 for account in self.accounts_view.accounts.clone() {
                     if !account.is_local_provider() {
                         commands.push(
@@ -2139,11 +4020,27 @@ impl Application for Cosmicding {
 
     fn on_nav_select(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
         self.nav.activate(id);
+        if self.nav.data::<AppNavPage>(id) == Some(&AppNavPage::TagsView) {
+            return Task::batch(vec![
+                self.update(ApplicationAction::LoadTags),
+                self.update_title(),
+            ]);
+        }
         self.update_title()
     }
 }
 
 impl Cosmicding {
+    fn migrate_api_tokens(&mut self) {
+        if let Some(ref mut database) = &mut self.accounts_cursor.database {
+            match block_on(async { db::SqliteDatabase::migrate_api_tokens(database).await }) {
+                Ok(0) => {}
+                Ok(count) => log::info!("Moved secrets of {count} accounts to the secret storage"),
+                Err(e) => log::error!("Failed to move API tokens to the secret storage: {e}"),
+            }
+        }
+    }
+
     #[allow(clippy::unused_self)]
     fn settings(&self) -> Element<'_, ApplicationAction> {
         widget::settings::view_column(vec![
@@ -2201,6 +4098,29 @@ impl Cosmicding {
                         ),
                 )
                 .into(),
+            widget::settings::section()
+                .title(fl!("sync"))
+                .add(
+                    widget::settings::item::builder(fl!("background-sync")).toggler(
+                        self.config.enable_background_sync,
+                        ApplicationAction::EnableBackgroundSync,
+                    ),
+                )
+                .add({
+                    widget::settings::item::builder(fl!(
+                        "sync-page-size",
+                        count = self.config.sync_page_size
+                    ))
+                    .control(
+                        widget::slider(
+                            50..=1000,
+                            self.config.sync_page_size,
+                            ApplicationAction::SetSyncPageSize,
+                        )
+                        .step(50),
+                    )
+                })
+                .into(),
             widget::settings::section()
                 .title(fl!("actions"))
                 .add(
@@ -2220,6 +4140,168 @@ impl Cosmicding {
         .into()
     }
 
+    // NOTE: (vkhitrin) pending operations are replayed before fetching bookmarks, this
+    //       prevents the sync from overriding changes that were made locally.
+    fn fetch_bookmarks_for_account(
+        &mut self,
+        account: Account,
+        remaining_accounts: Vec<Account>,
+    ) -> Task<ApplicationAction> {
+        let pending_operations = match (&mut self.bookmarks_cursor.database, account.id) {
+            (Some(database), Some(account_id)) => block_on(async {
+                db::SqliteDatabase::select_pending_operations(database, account_id).await
+            })
+            .into_iter()
+            .filter(|operation| operation.error.is_none())
+            .collect(),
+            _ => Vec::new(),
+        };
+        if pending_operations.is_empty() {
+            return self.stream_bookmarks_for_account(account, remaining_accounts);
+        }
+        let cloned_acc = account.clone();
+        Task::perform(
+            provider::replay_pending_operations(account, pending_operations),
+            move |results| {
+                cosmic::Action::App(ApplicationAction::DoneReplayPendingOperations(
+                    cloned_acc.clone(),
+                    results,
+                    remaining_accounts.clone(),
+                ))
+            },
+        )
+    }
+
+    fn stream_bookmarks_for_account(
+        &self,
+        account: Account,
+        remaining_accounts: Vec<Account>,
+    ) -> Task<ApplicationAction> {
+        Task::run(
+            provider::fetch_bookmarks_for_single_account(account, self.config.sync_page_size),
+            move |event| match event {
+                BookmarksSyncEvent::Page(page) => {
+                    cosmic::Action::App(ApplicationAction::ReceivedBookmarksPage(page))
+                }
+                BookmarksSyncEvent::Finished(response) => {
+                    cosmic::Action::App(ApplicationAction::DoneRefreshSingleAccount(
+                        response,
+                        remaining_accounts.clone(),
+                    ))
+                }
+            },
+        )
+    }
+
+    fn has_pending_operation(&self, bookmark: &Bookmark) -> bool {
+        bookmark
+            .id
+            .is_some_and(|id| self.bookmarks_view.pending_bookmark_ids.contains(&id))
+    }
+
+    // NOTE: (vkhitrin) changes against unreachable remote accounts are applied to the local
+    //       database and replayed during the next sync of the account.
+    fn queue_pending_operation(
+        &mut self,
+        account: &Account,
+        kind: PendingOperationKind,
+        bookmark: Bookmark,
+    ) -> Task<ApplicationAction> {
+        if account.id.is_none() {
+            return Task::none();
+        }
+        let bookmark_url = bookmark.url.clone();
+        self.enqueue_pending_operation(account, kind, bookmark);
+        Task::batch(vec![
+            self.toasts
+                .push(widget::toaster::Toast::new(fl!(
+                    "queued-bookmark-change",
+                    bkmrk = bookmark_url,
+                    acc = account.display_name.clone()
+                )))
+                .map(cosmic::Action::App),
+            self.update(ApplicationAction::LoadBookmarks),
+        ])
+    }
+
+    fn enqueue_pending_operation(
+        &mut self,
+        account: &Account,
+        kind: PendingOperationKind,
+        mut bookmark: Bookmark,
+    ) {
+        let Some(account_id) = account.id else {
+            return;
+        };
+        let epoch_timestamp = provider::current_timestamp();
+        if let Some(ref mut database) = &mut self.bookmarks_cursor.database {
+            block_on(async {
+                match kind {
+                    PendingOperationKind::Add => {
+                        let timestamp_string =
+                            Utc::now().format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string();
+                        bookmark.user_account_id = Some(account_id);
+                        bookmark.is_owner = Some(true);
+                        if bookmark.date_added.is_none() {
+                            bookmark.date_added = Some(timestamp_string.clone());
+                        }
+                        bookmark.date_modified = Some(timestamp_string);
+                        bookmark.id =
+                            Some(db::SqliteDatabase::add_bookmark(database, &bookmark).await);
+                    }
+                    PendingOperationKind::Edit => {
+                        db::SqliteDatabase::update_bookmark(database, &bookmark, &bookmark).await;
+                    }
+                    PendingOperationKind::Remove => {
+                        db::SqliteDatabase::delete_bookmark(database, &bookmark).await;
+                    }
+                }
+                db::SqliteDatabase::enqueue_pending_operation(
+                    database,
+                    &PendingOperation::new(account_id, kind, bookmark, epoch_timestamp),
+                )
+                .await;
+            });
+        }
+    }
+
+    // NOTE: (vkhitrin) similar to imports, remaining bookmarks are skipped after a failure.
+    fn fail_bulk_action(
+        &mut self,
+        bulk_context: &BulkActionContext,
+        error: String,
+        remaining_count: usize,
+    ) -> Task<ApplicationAction> {
+        log::error!(
+            "Bulk action failed: {}. Cancelling remaining {} bookmarks.",
+            error,
+            remaining_count
+        );
+        Task::batch(vec![
+            self.toasts
+                .push(widget::toaster::Toast::new(error))
+                .map(cosmic::Action::App),
+            self.update(ApplicationAction::CancelBulkAction(
+                bulk_context.operation_id,
+            )),
+        ])
+    }
+
+    fn continue_bulk_action(
+        &mut self,
+        mut bulk_context: BulkActionContext,
+        remaining_bookmarks: Vec<(Account, Bookmark)>,
+    ) -> Task<ApplicationAction> {
+        if let Some(ref mut progress) = self.operation_progress {
+            progress.current += 1;
+        }
+        bulk_context.conflict_attempts = 0;
+        self.update(ApplicationAction::StartBulkActionForBookmark(
+            bulk_context,
+            remaining_bookmarks,
+        ))
+    }
+
     fn update_config(&mut self) -> Task<ApplicationAction> {
         let theme = self.config.app_theme.theme();
         cosmic::command::set_theme(theme)
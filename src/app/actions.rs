@@ -1,13 +1,16 @@
 use crate::models::{
-    account::{Account, LinkdingAccountApiResponse},
-    bookmarks::{Bookmark, BookmarkRemoveResponse, DetailedResponse},
-    provider::Provider,
+    account::{Account, AccountDetails},
+    bookmarks::{Bookmark, BookmarkRemoveResponse, BookmarksPage, DetailedResponse},
+    bulk_action::BulkAction,
+    pending_operation::PendingOperationResult,
+    replication::{ReplicationRule, ReplicationSummary},
+    tag::TagOperation,
 };
 use crate::{
     app::{
         config::{AppTheme, CosmicConfig, SortOption},
         context::ContextPage,
-        dialog::DialogPage,
+        dialog::{ConflictResolution, DialogPage},
     },
     models::bookmarks::BookmarkCheckDetailsResponse,
 };
@@ -23,34 +26,70 @@ use std::{path::PathBuf, time::Instant};
 pub enum ApplicationAction {
     AccountsView(AccountsAction),
     AddAccountForm,
+    AddAccountHeader,
     AddBookmarkForm,
     AddBookmarkFormAccountIndex(usize),
+    AddReplicationRule(ReplicationRule),
     AppTheme(AppTheme),
     BookmarksView(BookmarksAction),
+    CancelBulkAction(u64),
     CloseToast(widget::ToastId),
+    CompleteBulkActionDialog(BulkAction, Vec<i64>),
     CompleteRemoveDialog(Option<i64>, Option<Bookmark>),
+    CompleteUnlockSecrets(String),
     ContextClose,
     DecrementPageIndex(String),
     DialogCancel,
     DialogUpdate(DialogPage),
-    DoneAddAccount(Account, Option<LinkdingAccountApiResponse>),
+    DoneAddAccount(Account, Option<AccountDetails>),
+    DoneBulkAction(BulkActionContext),
+    DoneBulkEditBookmark(
+        BulkActionContext,
+        Account,
+        Option<BookmarkCheckDetailsResponse>,
+        Vec<(Account, Bookmark)>,
+    ),
+    DoneBulkTransferBookmark(
+        BulkActionContext,
+        Account,
+        Bookmark,
+        Option<BookmarkCheckDetailsResponse>,
+        Vec<(Account, Bookmark)>,
+    ),
+    DoneBulkRemoveBookmark(
+        BulkActionContext,
+        Account,
+        Bookmark,
+        Option<BookmarkRemoveResponse>,
+        Vec<(Account, Bookmark)>,
+    ),
     DoneAddBookmark(
         Account,
         Option<BookmarkCheckDetailsResponse>,
         Option<ImportAction>,
         Vec<(Bookmark, ImportAction)>,
     ),
-    DoneEditAccount(Account, Option<LinkdingAccountApiResponse>),
+    DoneEditAccount(Account, Option<AccountDetails>),
     DoneEditBookmark(Account, Option<BookmarkCheckDetailsResponse>),
     DoneFetchFaviconForBookmark(String, Bytes),
-    DoneRefreshAccountProfile(Account, Option<LinkdingAccountApiResponse>),
+    DoneRefreshAccountProfile(Account, Option<AccountDetails>),
     DoneRefreshSingleAccount(DetailedResponse, Vec<Account>),
     DoneRemoveBookmark(Account, Bookmark, Option<BookmarkRemoveResponse>),
+    DoneReplayPendingOperations(Account, Vec<PendingOperationResult>, Vec<Account>),
+    DoneReplicateBookmark(
+        ReplicationContext,
+        (Bookmark, Bookmark),
+        Option<BookmarkCheckDetailsResponse>,
+        Vec<(Bookmark, Bookmark)>,
+    ),
+    DoneReplication(ReplicationContext),
     EditAccountForm(Account),
     EditBookmarkForm(i64, Bookmark),
     Empty,
+    EnableBackgroundSync(bool),
     EnableFavicons(bool),
     ExportBookmarksSelectAccounts(Vec<bool>),
+    FilterBookmarksByTag(String),
     ImportBookmarksSelectAccount(usize),
     StartExportBookmarks,
     StartImportBookmarks,
@@ -68,22 +107,42 @@ pub enum ApplicationAction {
     Key(Modifiers, Key),
     LoadAccounts,
     LoadBookmarks,
+    LoadTags,
     Modifiers(Modifiers),
     OpenAccountsPage,
+    OpenAddReplicationRuleDialog,
+    OpenBulkActionDialog(BulkAction, Vec<i64>),
     OpenExternalUrl(String),
     OpenPurgeFaviconsCache,
     OpenRemoveAccountDialog(Account),
     OpenRemoveBookmarkDialog(i64, Bookmark),
+    OpenTransferBookmarksDialog(Vec<i64>),
+    OpenTagOperationDialog(TagOperation),
     PurgeFaviconsCache,
+    ReceivedBookmarksPage(BookmarksPage),
     RemoveAccount(Account),
+    RemoveAccountHeader(usize),
+    RemoveReplicationRule(ReplicationRule),
+    ResolveBookmarkConflict(ConflictResolution),
     SearchActivate,
     SearchBookmarks(String),
+    SelectAllMatchingBookmarks,
     SetAccountAPIKey(String),
+    SetAccountCACertificatePath(String),
+    SetAccountClientCertificatePath(String),
+    SetAccountClientId(String),
+    SetAccountClientKeyPath(String),
+    SetAccountClientSecret(String),
     SetAccountDisplayName(String),
+    SetAccountHeaderName(usize, String),
+    SetAccountHeaderValue(usize, String),
     SetAccountInstance(String),
-    SetAccountProvider(Provider),
+    SetAccountProvider(String),
+    SetAccountProxyURL(String),
     SetAccountStatus(bool),
+    SetAccountSyncInterval(i64),
     SetAccountTrustInvalidCertificates(bool),
+    SetAccountUsername(String),
     SetBookmarkArchived(bool),
     SetBookmarkShared(bool),
     SetBookmarkTags(String),
@@ -91,8 +150,10 @@ pub enum ApplicationAction {
     SetBookmarkURL(String),
     SetBookmarkUnread(bool),
     SetItemsPerPage(u8),
+    SetSyncPageSize(u16),
     SortOption(SortOption),
     StartAddAccount(Account),
+    StartBackgroundSync,
     StartAddBookmark(
         Account,
         Bookmark,
@@ -106,17 +167,24 @@ pub enum ApplicationAction {
     StartRefreshBookmarksForAccount(Account),
     StartRefreshBookmarksForAllAccounts,
     StartRemoveBookmark(i64, Bookmark),
+    StartReplication(ReplicationRule),
+    StartReplicationForBookmark(ReplicationContext, Vec<(Bookmark, Bookmark)>),
+    StartBulkAction(BulkAction, Vec<i64>),
+    StartBulkActionForBookmark(BulkActionContext, Vec<(Account, Bookmark)>),
     StartupCompleted,
     SystemThemeModeChange,
+    TagsView(TagsAction),
     Tick(Instant),
     ToggleContextPage(ContextPage),
     UpdateConfig(CosmicConfig),
+    ViewAccountDetails(Account),
     ViewBookmarkNotes(Bookmark),
 }
 
 #[derive(Debug, Clone)]
 pub enum AccountsAction {
     AddAccount,
+    AddReplicationRule,
     CancelImport(u64),
     DecrementPageIndex,
     DeleteAccount(Account),
@@ -124,29 +192,74 @@ pub enum AccountsAction {
     IncrementPageIndex,
     OpenExternalURL(String),
     RefreshBookmarksForAccount(Account),
+    RemoveReplicationRule(ReplicationRule),
+    StartReplication(ReplicationRule),
     ToggleAccountStatus(Account),
+    ViewAccountDetails(Account),
 }
 
 #[derive(Debug, Clone)]
 pub enum BookmarksAction {
     AddBookmark,
+    BulkAction(BulkAction),
     CancelImport(u64),
     ClearSearch,
+    ClearSelection,
     DecrementPageIndex,
     DeleteBookmark(i64, Bookmark),
     EditBookmark(i64, Bookmark),
     EmptyMessage,
+    FilterByCollection(String),
     IncrementPageIndex,
     OpenAccountsPage,
+    OpenBulkActionDialog(BulkAction),
     OpenExternalURL(String),
+    OpenTransferDialog(Vec<i64>),
     RefreshBookmarks,
     SearchBookmarks(String),
+    SelectAllMatching,
+    SelectAllOnPage,
+    ToggleBookmarkSelection(usize, bool),
+    ToggleSelectionMode,
     ViewNotes(Bookmark),
 }
 
+#[derive(Debug, Clone)]
+pub enum TagsAction {
+    CancelOperation(u64),
+    ClearFilter,
+    DeleteTags,
+    FilterTags(String),
+    MergeTags,
+    OpenTag(String),
+    RenameTag,
+    ToggleTagSelection(String, bool),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ImportAction {
     pub import_id: u64,
     pub total_count: usize,
     pub current_index: usize,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkActionContext {
+    pub operation_id: u64,
+    pub action: BulkAction,
+    pub updated_count: usize,
+    pub skipped_count: usize,
+    // NOTE: (vkhitrin) attempts to resolve a conflict of the current bookmark.
+    pub conflict_attempts: usize,
+}
+
+// NOTE: (vkhitrin) the target account of the replication rule.
+#[derive(Debug, Clone)]
+pub struct ReplicationContext {
+    pub operation_id: u64,
+    pub rule: ReplicationRule,
+    pub account: Account,
+    pub summary: ReplicationSummary,
+    // NOTE: (vkhitrin) attempts to resolve a conflict of the current bookmark.
+    pub conflict_attempts: usize,
+}
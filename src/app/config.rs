@@ -16,6 +16,9 @@ pub struct CosmicConfig {
     pub sort_option: SortOption,
     pub items_per_page: u8,
     pub enable_favicons: bool,
+    pub sync_page_size: u16,
+    pub enable_background_sync: bool,
+    pub database_path: Option<String>,
 }
 
 impl CosmicConfig {
@@ -52,6 +55,9 @@ impl Default for CosmicConfig {
             sort_option: SortOption::BookmarksDateNewest,
             items_per_page: 10,
             enable_favicons: true,
+            sync_page_size: 100,
+            enable_background_sync: true,
+            database_path: None,
         }
     }
 }
@@ -88,4 +94,5 @@ pub enum SortOption {
     BookmarksDateOldest,
     BookmarkAlphabeticalAscending,
     BookmarkAlphabeticalDescending,
+    BookmarksRelevance,
 }
@@ -3,6 +3,7 @@ use crate::fl;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ContextPage {
     About,
+    AccountDetails,
     AddAccountForm,
     EditAccountForm,
     EditBookmarkForm,
@@ -16,6 +17,7 @@ impl ContextPage {
         match self {
             Self::About => fl!("about"),
             Self::Settings => fl!("settings"),
+            Self::AccountDetails => fl!("account-details"),
             Self::AddAccountForm => fl!("add-account"),
             Self::EditAccountForm => fl!("edit-account"),
             Self::NewBookmarkForm => fl!("add-bookmark"),
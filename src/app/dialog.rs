@@ -1,12 +1,34 @@
-use crate::models::{account::Account, bookmarks::Bookmark};
+use crate::models::{account::Account, bookmarks::Bookmark, tag::TagOperation};
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[allow(clippy::large_enum_variant)]
 pub enum DialogPage {
     RemoveAccount(Account),
+    // NOTE: (vkhitrin) accounts, selected source account, selected target account and
+    //       filter.
+    AddReplicationRule(Vec<Account>, usize, usize, String),
     RemoveBookmark(i64, Bookmark),
     PurgeFaviconsCache(),
     ExportBookmarks(Vec<Account>, Vec<bool>, Option<PathBuf>),
     ImportBookmarks(Vec<Account>, usize, Option<PathBuf>),
+    // NOTE: (vkhitrin) account, cached bookmark (if available), local bookmark, remote
+    //       bookmark and the pending operation that is resolved (if replayed).
+    BookmarkConflict(Account, Option<Bookmark>, Bookmark, Bookmark, Option<i64>),
+    BulkAddTags(Vec<i64>, String),
+    BulkRemoveBookmarks(Vec<i64>),
+    BulkRemoveTags(Vec<i64>, String),
+    TagOperation(TagOperation),
+    // NOTE: (vkhitrin) bookmarks, target accounts, selected target account and whether
+    //       bookmarks are removed from their source account.
+    TransferBookmarks(Vec<i64>, Vec<Account>, usize, bool),
+    // NOTE: (vkhitrin) whether a new passphrase is created and the passphrase.
+    UnlockSecrets(bool, String),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConflictResolution {
+    KeepLocal,
+    KeepRemote,
+    Merge,
 }
@@ -143,6 +143,13 @@ pub fn menu_bar<'a>(
                                 SortOption::BookmarkAlphabeticalDescending,
                             ),
                         ),
+                        Item::Divider,
+                        Item::CheckBox(
+                            fl!("bookmark-relevance"),
+                            None,
+                            matches!(sort_option, SortOption::BookmarksRelevance),
+                            MenuAction::SetSortBookmarks(SortOption::BookmarksRelevance),
+                        ),
                     ]
                 } else {
                     vec![
@@ -159,6 +166,8 @@ pub fn menu_bar<'a>(
                             None,
                             MenuAction::Empty,
                         ),
+                        Item::Divider,
+                        Item::ButtonDisabled(fl!("bookmark-relevance"), None, MenuAction::Empty),
                     ]
                 },
             ),
@@ -10,6 +10,7 @@ pub enum AppNavPage {
     #[default]
     BookmarksView,
     AccountsView,
+    TagsView,
 }
 
 impl Default for &AppNavPage {
@@ -23,12 +24,14 @@ impl AppNavPage {
         match self {
             Self::BookmarksView => fl!("bookmarks"),
             Self::AccountsView => fl!("accounts"),
+            Self::TagsView => fl!("tags"),
         }
     }
     pub fn icon(self) -> cosmic::widget::Icon {
         match self {
             Self::BookmarksView => icon::from_name("web-browser-symbolic").into(),
             Self::AccountsView => icon::from_name("contact-new-symbolic").into(),
+            Self::TagsView => icon::from_name("tag-symbolic").into(),
         }
     }
 
@@ -42,6 +45,7 @@ impl AppNavPage {
                     &app.accounts_cursor,
                     &app.timeline,
                     app.operation_progress.as_ref(),
+                    &app.context_accounts_list,
                 )
                 .map(ApplicationAction::AccountsView),
             AppNavPage::BookmarksView => app
@@ -55,10 +59,14 @@ impl AppNavPage {
                     &app.context_accounts_list,
                 )
                 .map(ApplicationAction::BookmarksView),
+            AppNavPage::TagsView => app
+                .tags_view
+                .view(app.operation_progress.as_ref())
+                .map(ApplicationAction::TagsView),
         }
     }
 
     pub fn all() -> &'static [Self] {
-        &[Self::AccountsView, Self::BookmarksView]
+        &[Self::AccountsView, Self::BookmarksView, Self::TagsView]
     }
 }
@@ -1,30 +1,178 @@
 use crate::{
-    app::{config::SortOption, APP, APPID, ORG, QUALIFIER},
-    models::{account::Account, bookmarks::Bookmark, favicon_cache::Favicon},
+    app::{
+        config::{CosmicConfig, SortOption},
+        APP, APPID, ORG, QUALIFIER,
+    },
+    models::{
+        account::{Account, RequestHeaders},
+        bookmarks::{Bookmark, RemoteKey},
+        favicon_cache::Favicon,
+        pending_operation::{PendingOperation, PendingOperationKind},
+        replication::{ReplicationRule, ReplicationSummary},
+        sync_run::{EndpointStatus, SyncCounts, SyncRun, MAX_SYNC_RUNS_PER_ACCOUNT},
+        tag::Tag,
+    },
+    provider::get_provider,
+    secrets,
+    utils::search::{self, BookmarkState, SearchFilter, SearchQuery},
 };
 use anyhow::{anyhow, Result};
 
-use std::path::Path;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 use sqlx::{migrate::MigrateDatabase, prelude::*, sqlite::Sqlite, SqlitePool};
 
 const DB_PATH: &str = constcat::concat!(APPID, "-db", ".sqlite");
+const DB_PATH_ENV: &str = "COSMICDING_DATABASE_PATH";
+// NOTE: (vkhitrin) SQLite may keep uncommitted transactions in files next to the database,
+//       they are moved along with it. The database itself is moved last, it indicates
+//       that the move was completed.
+const DB_COMPANION_SUFFIXES: [&str; 4] = ["-journal", "-wal", "-shm", ""];
+// NOTE: (vkhitrin) older SQLite versions limit statements to 999 variables.
+const MAX_BOUND_PARAMETERS: usize = 500;
+const INSERT_BOOKMARK_QUERY: &str = r"
+    INSERT INTO Bookmarks (
+        user_account_id,
+        provider_internal_id,
+        url,
+        title,
+        description,
+        notes,
+        web_archive_snapshot_url,
+        favicon_url,
+        preview_image_url,
+        is_archived,
+        unread,
+        shared,
+        tag_names,
+        date_added,
+        date_modified,
+        website_title,
+        website_description,
+        is_owner,
+        collection,
+        provider_remote_id,
+        reading_time)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21);";
+// NOTE: (vkhitrin) the host of a bookmark URL, the scheme, credentials, port, path, query
+//       and fragment are stripped.
+const BOOKMARK_HOST_QUERY: &str = r"
+    SELECT substr(authority, 1, instr(authority, ':') - 1) AS host FROM (
+        SELECT substr(authority, instr(authority, '@') + 1) || ':' AS authority FROM (
+            SELECT substr(remainder, 1, instr(remainder, '/') - 1) AS authority FROM (
+                SELECT replace(replace(substr(lower(Bookmarks.url), instr(Bookmarks.url, '://') + 3), '?', '/'), '#', '/') || '/' AS remainder
+            )
+        )
+    )";
+const UPDATE_BOOKMARK_BY_ID_QUERY: &str = r"
+    UPDATE Bookmarks SET
+        user_account_id=$1,
+        provider_internal_id=$2,
+        url=$3,
+        title=$4,
+        description=$5,
+        notes=$6,
+        web_archive_snapshot_url=$7,
+        favicon_url=$8,
+        preview_image_url=$9,
+        is_archived=$10,
+        unread=$11,
+        shared=$12,
+        tag_names=$13,
+        date_added=$14,
+        date_modified=$15,
+        website_title=$16,
+        website_description=$17,
+        is_owner=$18,
+        collection=$19,
+        provider_remote_id=$20,
+        reading_time=$21
+    WHERE id=$22;";
 
 #[derive(Debug, Clone)]
 pub struct SqliteDatabase {
     conn: SqlitePool,
 }
 
+// NOTE: (vkhitrin) bookmarks of the account before the sync started (by remote key), they
+//       are removed from the state once they were returned by the provider.
+#[derive(Debug, Clone, Default)]
+pub struct BookmarksSyncState {
+    is_loaded: bool,
+    existing_bookmarks: HashMap<RemoteKey, (i64, Option<String>, Option<bool>)>,
+    stale_bookmark_ids: Vec<i64>,
+    pending_bookmark_ids: HashSet<i64>,
+    pending_remote_keys: HashSet<RemoteKey>,
+    sync_cursor: Option<String>,
+    counts: SyncCounts,
+}
+
 impl SqliteDatabase {
+    // NOTE: (vkhitrin) the database is stored in the data directory, it is the only copy of
+    //       bookmarks of local accounts. It can be overridden using `COSMICDING_DATABASE_PATH`
+    //       or the `database_path` config entry (e.g. for portable setups).
     pub async fn create() -> Result<Self> {
         let directories = directories::ProjectDirs::from(QUALIFIER, ORG, APP).unwrap();
+        let db_path = match Self::database_path_override() {
+            Some(db_path) => db_path,
+            None => {
+                let db_path = directories.data_dir().join(DB_PATH);
+                Self::migrate_from_cache_dir(&directories.cache_dir().join(DB_PATH), &db_path)?;
+                db_path
+            }
+        };
+        if let Some(db_dir) = db_path.parent() {
+            std::fs::create_dir_all(db_dir)?;
+        }
         std::fs::create_dir_all(directories.cache_dir())?;
-        Self::ensure_db_exists(directories.cache_dir()).await
+        Self::ensure_db_exists(&db_path, directories.cache_dir()).await
     }
 
-    async fn ensure_db_exists(db_dir: &Path) -> Result<Self> {
-        let db_path = db_dir.join(DB_PATH);
+    fn database_path_override() -> Option<PathBuf> {
+        std::env::var(DB_PATH_ENV)
+            .ok()
+            .or(CosmicConfig::config().database_path)
+            .filter(|db_path| !db_path.trim().is_empty())
+            .map(PathBuf::from)
+    }
 
+    // NOTE: (vkhitrin) previous versions stored the database in the cache directory, it is
+    //       moved once (if a database does not exist in the data directory).
+    fn migrate_from_cache_dir(legacy_db_path: &Path, db_path: &Path) -> Result<()> {
+        if db_path.exists() || !legacy_db_path.exists() {
+            return Ok(());
+        }
+        if let Some(db_dir) = db_path.parent() {
+            std::fs::create_dir_all(db_dir)?;
+        }
+        for suffix in DB_COMPANION_SUFFIXES {
+            let source = PathBuf::from(format!("{}{suffix}", legacy_db_path.display()));
+            let destination = PathBuf::from(format!("{}{suffix}", db_path.display()));
+            if !source.exists() {
+                continue;
+            }
+            // NOTE: (vkhitrin) renaming fails if the directories are on different filesystems,
+            //       the file is copied next to the destination and renamed once it is complete.
+            if std::fs::rename(&source, &destination).is_err() {
+                let partial_destination =
+                    PathBuf::from(format!("{}.partial", destination.display()));
+                std::fs::copy(&source, &partial_destination)?;
+                std::fs::rename(&partial_destination, &destination)?;
+                std::fs::remove_file(&source)?;
+            }
+        }
+        log::info!(
+            "Moved database from {} to {}",
+            legacy_db_path.display(),
+            db_path.display()
+        );
+        Ok(())
+    }
+
+    async fn ensure_db_exists(db_path: &Path, cache_dir: &Path) -> Result<Self> {
         let db_path = db_path
             .to_str()
             .ok_or(anyhow!("can't convert path to str"))?;
@@ -35,7 +183,7 @@ impl SqliteDatabase {
 
         let conn = &SqlitePool::connect(db_path).await?;
 
-        let migration_path = db_dir.join("migrations");
+        let migration_path = cache_dir.join("migrations");
         std::fs::create_dir_all(&migration_path)?;
         include_dir::include_dir!("migrations")
             .extract(&migration_path)
@@ -66,7 +214,8 @@ impl SqliteDatabase {
     }
     pub async fn select_accounts(&mut self) -> Vec<Account> {
         let query: &str = "SELECT * FROM UserAccounts WHERE enabled = 1;";
-        let result: Vec<Account> = sqlx::query_as(query).fetch_all(&self.conn).await.unwrap();
+        let mut result: Vec<Account> = sqlx::query_as(query).fetch_all(&self.conn).await.unwrap();
+        resolve_api_tokens(&mut result).await;
 
         result
     }
@@ -79,26 +228,62 @@ impl SqliteDatabase {
             .await
             .unwrap();
 
-        let data: Vec<Account> = result
+        let mut data: Vec<Account> = result
             .iter()
             .map(|row| Account {
                 api_token: row.get("api_token"),
+                api_token_reference: row.get("api_token_reference"),
+                ca_certificate_path: row.get("ca_certificate_path"),
+                client_certificate_path: row.get("client_certificate_path"),
+                client_key_path: row.get("client_key_path"),
                 display_name: row.get("display_name"),
                 enable_public_sharing: row.get("enable_public_sharing"),
                 enable_sharing: row.get("enable_sharing"),
                 enabled: row.get("enabled"),
+                extra_headers: serde_json::from_str(row.get("extra_headers")).unwrap_or_default(),
                 id: row.get("id"),
                 instance: row.get("instance"),
+                last_full_sync_timestamp: row.get("last_full_sync_timestamp"),
                 last_sync_status: row.get("last_sync_status"),
                 last_sync_timestamp: row.get("last_sync_timestamp"),
                 provider_string: row.get("provider"),
                 provider_version: row.get("provider_version"),
+                proxy_url: row.get("proxy_url"),
+                sync_cursor: row.get("sync_cursor"),
+                sync_interval_minutes: row.get("sync_interval_minutes"),
                 trust_invalid_certs: row.get("trust_invalid_certs"),
+                username: row.get("username"),
+                client_id: row.get("client_id"),
+                client_secret: String::new(),
             })
             .collect();
+        resolve_api_tokens(&mut data).await;
         data
     }
     pub async fn delete_account(&mut self, account_id: i64) {
+        let reference_query: &str =
+            "SELECT api_token_reference, provider FROM UserAccounts WHERE id = $1;";
+        let (api_token_reference, provider_string): (Option<String>, String) =
+            sqlx::query_as(reference_query)
+                .bind(account_id)
+                .fetch_one(&self.conn)
+                .await
+                .unwrap();
+        if let Some(reference) = api_token_reference {
+            if let Err(e) = secrets::delete_secret(&reference).await {
+                log::error!("Failed to delete API token of account {account_id}: {e}");
+            }
+            if get_provider(&provider_string)
+                .is_some_and(|provider| provider.requires_client_credentials())
+            {
+                if let Err(e) = secrets::delete_secret(&client_secret_reference(&reference)).await {
+                    log::error!("Failed to delete client secret of account {account_id}: {e}");
+                }
+            }
+            if let Err(e) = secrets::delete_secret(&extra_headers_reference(&reference)).await {
+                log::warn!("Failed to delete headers of account {account_id}: {e}");
+            }
+        }
         let bookmarks_query: &str = "DELETE FROM UserAccounts WHERE id = $1;";
         sqlx::query(bookmarks_query)
             .bind(account_id)
@@ -106,38 +291,109 @@ impl SqliteDatabase {
             .await
             .unwrap();
     }
-    pub async fn update_account(&mut self, account: &Account) {
-        let query: &str = "UPDATE UserAccounts SET display_name=$2, instance=$3, api_token=$4, trust_invalid_certs=$5, enabled=$6, enable_sharing=$7, enable_public_sharing=$8, provider=$9, provider_version=$10 WHERE id=$1;";
+    pub async fn update_account(&mut self, account: &Account) -> Result<()> {
+        let (api_token_reference, extra_headers) = store_account_secrets(account).await?;
+        let query: &str = "UPDATE UserAccounts SET display_name=$2, instance=$3, api_token='', api_token_reference=$4, trust_invalid_certs=$5, enabled=$6, enable_sharing=$7, enable_public_sharing=$8, provider=$9, provider_version=$10, sync_interval_minutes=$11, ca_certificate_path=$12, client_certificate_path=$13, client_key_path=$14, proxy_url=$15, extra_headers=$16, username=$17, client_id=$18 WHERE id=$1;";
         sqlx::query(query)
             .bind(account.id)
             .bind(&account.display_name)
             .bind(&account.instance)
-            .bind(&account.api_token)
+            .bind(api_token_reference)
             .bind(account.trust_invalid_certs)
             .bind(account.enabled)
             .bind(account.enable_sharing)
             .bind(account.enable_public_sharing)
             .bind(&account.provider_string)
             .bind(&account.provider_version)
+            .bind(account.sync_interval_minutes)
+            .bind(&account.ca_certificate_path)
+            .bind(&account.client_certificate_path)
+            .bind(&account.client_key_path)
+            .bind(&account.proxy_url)
+            .bind(serde_json::to_string(&extra_headers).unwrap())
+            .bind(&account.username)
+            .bind(&account.client_id)
             .execute(&self.conn)
             .await
             .unwrap();
+        Ok(())
     }
-    pub async fn create_account(&mut self, account: &Account) {
-        let query: &str = "INSERT INTO UserAccounts (display_name, instance, api_token, last_sync_status, last_sync_timestamp, trust_invalid_certs, enabled, enable_sharing, enable_public_sharing, provider, provider_version) VALUES ($1, $2, $3, 0, 0, $4, $5, $6, $7, $8, $9);";
+    pub async fn create_account(&mut self, account: &Account) -> Result<()> {
+        let (api_token_reference, extra_headers) = store_account_secrets(account).await?;
+        let query: &str = "INSERT INTO UserAccounts (display_name, instance, api_token, api_token_reference, last_sync_status, last_sync_timestamp, trust_invalid_certs, enabled, enable_sharing, enable_public_sharing, provider, provider_version, sync_interval_minutes, ca_certificate_path, client_certificate_path, client_key_path, proxy_url, extra_headers, username, client_id) VALUES ($1, $2, '', $3, 0, 0, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17);";
         sqlx::query(query)
             .bind(&account.display_name)
             .bind(&account.instance)
-            .bind(&account.api_token)
+            .bind(api_token_reference)
             .bind(account.trust_invalid_certs)
             .bind(account.enabled)
             .bind(account.enable_sharing)
             .bind(account.enable_public_sharing)
             .bind(&account.provider_string)
             .bind(&account.provider_version)
+            .bind(account.sync_interval_minutes)
+            .bind(&account.ca_certificate_path)
+            .bind(&account.client_certificate_path)
+            .bind(&account.client_key_path)
+            .bind(&account.proxy_url)
+            .bind(serde_json::to_string(&extra_headers).unwrap())
+            .bind(&account.username)
+            .bind(&account.client_id)
             .execute(&self.conn)
             .await
             .unwrap();
+        Ok(())
+    }
+    // NOTE: (vkhitrin) API tokens and header values used to be stored in `UserAccounts`, they
+    //       are moved to the secret storage once it is available. The database is vacuumed
+    //       afterwards, otherwise the secrets remain in its free pages.
+    pub async fn migrate_api_tokens(&mut self) -> Result<usize> {
+        let query: &str =
+            "SELECT id, display_name, api_token FROM UserAccounts WHERE api_token != '';";
+        let rows = sqlx::query(query).fetch_all(&self.conn).await?;
+        for row in &rows {
+            let account_id: i64 = row.get("id");
+            let display_name: String = row.get("display_name");
+            let api_token: String = row.get("api_token");
+            let reference = secrets::new_reference();
+            secrets::store_secret(&reference, &display_name, &api_token).await?;
+            sqlx::query(
+                "UPDATE UserAccounts SET api_token='', api_token_reference=$2 WHERE id=$1;",
+            )
+            .bind(account_id)
+            .bind(reference)
+            .execute(&self.conn)
+            .await?;
+        }
+        let headers_query: &str = "SELECT id, display_name, api_token_reference, extra_headers FROM UserAccounts WHERE api_token_reference IS NOT NULL;";
+        let headers_rows = sqlx::query(headers_query).fetch_all(&self.conn).await?;
+        let mut migrated_count = rows.len();
+        for row in &headers_rows {
+            let extra_headers: RequestHeaders =
+                serde_json::from_str(row.get("extra_headers")).unwrap_or_default();
+            if !extra_headers.has_values() {
+                continue;
+            }
+            let account_id: i64 = row.get("id");
+            let display_name: String = row.get("display_name");
+            let reference: String = row.get("api_token_reference");
+            secrets::store_secret(
+                &extra_headers_reference(&reference),
+                &display_name,
+                &serde_json::to_string(&extra_headers)?,
+            )
+            .await?;
+            sqlx::query("UPDATE UserAccounts SET extra_headers=$2 WHERE id=$1;")
+                .bind(account_id)
+                .bind(serde_json::to_string(&extra_headers.without_values())?)
+                .execute(&self.conn)
+                .await?;
+            migrated_count += 1;
+        }
+        if migrated_count > 0 {
+            sqlx::query("VACUUM;").execute(&self.conn).await?;
+        }
+        Ok(migrated_count)
     }
     //NOTE: (vkhitrin) at the moment, this function is no longer required.
     //                 Perhaps it should be removed/refactored.
@@ -163,25 +419,195 @@ impl SqliteDatabase {
     //        .await
     //        .unwrap();
     //}
+    // NOTE: (vkhitrin) bookmarks are reconciled using their remote key, only
+    //       bookmarks that were added or modified remotely are written. Bookmarks that were
+    //       removed remotely can only be detected as part of a full sync.
     pub async fn aggregate_bookmarks_for_account(
         &mut self,
         account: &Account,
         bookmarks: Vec<Bookmark>,
         epoch_timestamp: i64,
         response_successful: bool,
-    ) {
-        let delete_query: &str = "DELETE FROM Bookmarks where user_account_id = $1;";
-        let update_timestamp_query =
-            "UPDATE UserAccounts SET last_sync_status=$2, last_sync_timestamp=$3 WHERE id=$1";
+        is_full_sync: bool,
+        is_complete: bool,
+    ) -> SyncCounts {
+        let mut sync_state = BookmarksSyncState::default();
         if response_successful {
-            sqlx::query(delete_query)
+            self.aggregate_bookmarks_page(account, bookmarks, &mut sync_state)
+                .await;
+        }
+        self.complete_bookmarks_sync(
+            account,
+            sync_state,
+            epoch_timestamp,
+            response_successful,
+            is_full_sync,
+            is_complete,
+        )
+        .await
+    }
+    // NOTE: (vkhitrin) pages are written as soon as they are fetched, the state tracks
+    //       bookmarks that were not returned (yet) and is completed once the sync finished.
+    pub async fn aggregate_bookmarks_page(
+        &mut self,
+        account: &Account,
+        bookmarks: Vec<Bookmark>,
+        sync_state: &mut BookmarksSyncState,
+    ) {
+        let existing_query: &str = "SELECT id, provider_internal_id, provider_remote_id, date_modified, is_owner FROM Bookmarks WHERE user_account_id = $1;";
+        let pending_query: &str =
+            "SELECT bookmark_id, payload FROM PendingOperations WHERE user_account_id = $1;";
+        // NOTE: (vkhitrin) local accounts are not synced, their bookmarks exist only in the
+        //       database.
+        if account.is_local_provider() {
+            return;
+        }
+        if !sync_state.is_loaded {
+            let rows = sqlx::query(existing_query)
                 .bind(account.id)
-                .execute(&self.conn)
+                .fetch_all(&self.conn)
+                .await
+                .unwrap();
+            for row in &rows {
+                match RemoteKey::new(
+                    row.get("provider_internal_id"),
+                    row.get("provider_remote_id"),
+                ) {
+                    Some(remote_key) => {
+                        sync_state.existing_bookmarks.insert(
+                            remote_key,
+                            (row.get("id"), row.get("date_modified"), row.get("is_owner")),
+                        );
+                    }
+                    None => sync_state.stale_bookmark_ids.push(row.get("id")),
+                }
+            }
+            let pending_rows = sqlx::query(pending_query)
+                .bind(account.id)
+                .fetch_all(&self.conn)
                 .await
                 .unwrap();
-            for bookmark in bookmarks {
-                self.add_bookmark(&bookmark).await;
+            for row in &pending_rows {
+                if let Some(bookmark_id) = row.get::<Option<i64>, _>("bookmark_id") {
+                    sync_state.pending_bookmark_ids.insert(bookmark_id);
+                }
+                if let Some(remote_key) = serde_json::from_str::<Bookmark>(row.get("payload"))
+                    .ok()
+                    .and_then(|bookmark| bookmark.remote_key())
+                {
+                    sync_state.pending_remote_keys.insert(remote_key);
+                }
+            }
+            sync_state.sync_cursor.clone_from(&account.sync_cursor);
+            sync_state.is_loaded = true;
+        }
+        let mut transaction = self.conn.begin().await.unwrap();
+        for bookmark in bookmarks {
+            if bookmark.date_modified > sync_state.sync_cursor {
+                sync_state.sync_cursor.clone_from(&bookmark.date_modified);
+            }
+            // NOTE: (vkhitrin) bookmarks with pending local changes are left untouched
+            //       until the changes are replayed.
+            let remote_key = bookmark.remote_key();
+            if let Some(remote_key) = remote_key
+                .as_ref()
+                .filter(|remote_key| sync_state.pending_remote_keys.contains(remote_key))
+            {
+                sync_state.existing_bookmarks.remove(remote_key);
+                continue;
             }
+            match remote_key
+                .and_then(|remote_key| sync_state.existing_bookmarks.remove(&remote_key))
+            {
+                Some((id, date_modified, is_owner)) => {
+                    if date_modified == bookmark.date_modified && is_owner == bookmark.is_owner {
+                        continue;
+                    }
+                    Self::bind_bookmark(sqlx::query(UPDATE_BOOKMARK_BY_ID_QUERY), &bookmark)
+                        .bind(id)
+                        .execute(&mut *transaction)
+                        .await
+                        .unwrap();
+                    sync_state.counts.updated += 1;
+                }
+                None => {
+                    Self::bind_bookmark(sqlx::query(INSERT_BOOKMARK_QUERY), &bookmark)
+                        .execute(&mut *transaction)
+                        .await
+                        .unwrap();
+                    sync_state.counts.added += 1;
+                }
+            }
+        }
+        transaction.commit().await.unwrap();
+    }
+    pub async fn complete_bookmarks_sync(
+        &mut self,
+        account: &Account,
+        mut sync_state: BookmarksSyncState,
+        epoch_timestamp: i64,
+        response_successful: bool,
+        is_full_sync: bool,
+        is_complete: bool,
+    ) -> SyncCounts {
+        let delete_query: &str = "DELETE FROM Bookmarks WHERE id = $1;";
+        let update_cursor_query: &str =
+            "UPDATE UserAccounts SET sync_cursor=$2, last_full_sync_timestamp=$3 WHERE id=$1";
+        let update_timestamp_query =
+            "UPDATE UserAccounts SET last_sync_status=$2, last_sync_timestamp=$3 WHERE id=$1";
+        if response_successful && !account.is_local_provider() {
+            // NOTE: (vkhitrin) an empty sync doesn't aggregate any page.
+            if !sync_state.is_loaded {
+                self.aggregate_bookmarks_page(account, Vec::new(), &mut sync_state)
+                    .await;
+            }
+            let mut stale_bookmark_ids = sync_state.stale_bookmark_ids;
+            // NOTE: (vkhitrin) bookmarks that are not owned by the account may be missing from
+            //       a partial sync, only owned bookmarks are considered stale.
+            if is_full_sync {
+                stale_bookmark_ids.extend(
+                    sync_state
+                        .existing_bookmarks
+                        .into_values()
+                        .filter(|(_, _, is_owner)| is_complete || *is_owner == Some(true))
+                        .map(|(id, _, _)| id),
+                );
+            } else {
+                stale_bookmark_ids.clear();
+            }
+            stale_bookmark_ids.retain(|id| !sync_state.pending_bookmark_ids.contains(id));
+            let mut transaction = self.conn.begin().await.unwrap();
+            for id in &stale_bookmark_ids {
+                sqlx::query(delete_query)
+                    .bind(id)
+                    .execute(&mut *transaction)
+                    .await
+                    .unwrap();
+            }
+            // NOTE: (vkhitrin) the cursor is kept after a partial sync, so bookmarks of the
+            //       failed endpoints are fetched during the next sync.
+            if is_complete {
+                sqlx::query(update_cursor_query)
+                    .bind(account.id)
+                    .bind(&sync_state.sync_cursor)
+                    .bind(if is_full_sync {
+                        epoch_timestamp
+                    } else {
+                        account.last_full_sync_timestamp
+                    })
+                    .execute(&mut *transaction)
+                    .await
+                    .unwrap();
+            }
+            transaction.commit().await.unwrap();
+            sync_state.counts.removed = stale_bookmark_ids.len();
+            log::info!(
+                "Synced account {}: {} added, {} updated, {} removed",
+                account.display_name,
+                sync_state.counts.added,
+                sync_state.counts.updated,
+                sync_state.counts.removed
+            );
         }
         sqlx::query(update_timestamp_query)
             .bind(account.id)
@@ -190,30 +616,20 @@ impl SqliteDatabase {
             .execute(&self.conn)
             .await
             .unwrap();
+        sync_state.counts
     }
-    pub async fn add_bookmark(&mut self, bookmark: &Bookmark) {
-        let query: &str = r"
-            INSERT INTO Bookmarks (
-                user_account_id,
-                provider_internal_id,
-                url,
-                title,
-                description,
-                notes,
-                web_archive_snapshot_url,
-                favicon_url,
-                preview_image_url,
-                is_archived,
-                unread,
-                shared,
-                tag_names,
-                date_added,
-                date_modified,
-                website_title,
-                website_description,
-                is_owner)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18);";
-        sqlx::query(query)
+    pub async fn add_bookmark(&mut self, bookmark: &Bookmark) -> i64 {
+        Self::bind_bookmark(sqlx::query(INSERT_BOOKMARK_QUERY), bookmark)
+            .execute(&self.conn)
+            .await
+            .unwrap()
+            .last_insert_rowid()
+    }
+    fn bind_bookmark<'q>(
+        query: sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+        bookmark: &'q Bookmark,
+    ) -> sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>> {
+        query
             .bind(bookmark.user_account_id)
             .bind(bookmark.provider_internal_id)
             .bind(&bookmark.url)
@@ -232,9 +648,9 @@ impl SqliteDatabase {
             .bind(&bookmark.website_title)
             .bind(&bookmark.website_description)
             .bind(bookmark.is_owner)
-            .execute(&self.conn)
-            .await
-            .unwrap();
+            .bind(&bookmark.collection)
+            .bind(&bookmark.provider_remote_id)
+            .bind(bookmark.reading_time)
     }
 
     pub async fn count_bookmarks_entries(&mut self) -> usize {
@@ -261,6 +677,8 @@ impl SqliteDatabase {
             SortOption::BookmarksDateOldest => "date_added ASC",
             SortOption::BookmarkAlphabeticalAscending => "title COLLATE NOCASE ASC",
             SortOption::BookmarkAlphabeticalDescending => "title COLLATE NOCASE DESC",
+            // NOTE: (vkhitrin) relevance is only applicable when searching.
+            SortOption::BookmarksRelevance => "date_added DESC",
         };
         let query: String = format!(
             r"
@@ -328,15 +746,19 @@ impl SqliteDatabase {
                         row.get("favicon_data"),
                         row.get("favicon_cache_last_sync_timestamp"),
                     )),
+                    collection: row.get("collection"),
+                    provider_remote_id: row.get("provider_remote_id"),
+                    reading_time: row.get("reading_time"),
                 }
             })
             .collect();
         data
     }
     pub async fn update_bookmark(&mut self, old_bookmark: &Bookmark, new_bookmark: &Bookmark) {
-        // NOTE: (vkhitrin) For local bookmarks (cosmicding), use id instead of provider_internal_id
-        let query: &str = if old_bookmark.provider_internal_id.is_some() {
-            r"
+        // NOTE: (vkhitrin) For local bookmarks (cosmicding), use id instead of the remote key
+        let query: &str = match old_bookmark.remote_key() {
+            Some(RemoteKey::Internal(_)) => {
+                r"
             UPDATE Bookmarks SET
                 url=$1,
                 title=$2,
@@ -352,10 +774,13 @@ impl SqliteDatabase {
                 date_added=$12,
                 date_modified=$13,
                 website_title=$14,
-                website_description=$15
-            WHERE provider_internal_id=$16 AND user_account_id=$17;"
-        } else {
-            r"
+                website_description=$15,
+                collection=$16,
+                reading_time=$17
+            WHERE provider_internal_id=$18 AND user_account_id=$19;"
+            }
+            Some(RemoteKey::Remote(_)) => {
+                r"
             UPDATE Bookmarks SET
                 url=$1,
                 title=$2,
@@ -371,17 +796,36 @@ impl SqliteDatabase {
                 date_added=$12,
                 date_modified=$13,
                 website_title=$14,
-                website_description=$15
-            WHERE id=$16 AND user_account_id=$17;"
-        };
-
-        let id = if old_bookmark.provider_internal_id.is_some() {
-            old_bookmark.provider_internal_id
-        } else {
-            old_bookmark.id
+                website_description=$15,
+                collection=$16,
+                reading_time=$17
+            WHERE provider_remote_id=$18 AND user_account_id=$19;"
+            }
+            None => {
+                r"
+            UPDATE Bookmarks SET
+                url=$1,
+                title=$2,
+                description=$3,
+                notes=$4,
+                web_archive_snapshot_url=$5,
+                favicon_url=$6,
+                preview_image_url=$7,
+                is_archived=$8,
+                unread=$9,
+                shared=$10,
+                tag_names=$11,
+                date_added=$12,
+                date_modified=$13,
+                website_title=$14,
+                website_description=$15,
+                collection=$16,
+                reading_time=$17
+            WHERE id=$18 AND user_account_id=$19;"
+            }
         };
 
-        sqlx::query(query)
+        let query = sqlx::query(query)
             .bind(&new_bookmark.url)
             .bind(&new_bookmark.title)
             .bind(&new_bookmark.description)
@@ -397,11 +841,17 @@ impl SqliteDatabase {
             .bind(&new_bookmark.date_modified)
             .bind(&new_bookmark.website_title)
             .bind(&new_bookmark.website_description)
-            .bind(id)
-            .bind(old_bookmark.user_account_id)
-            .execute(&self.conn)
-            .await
-            .unwrap();
+            .bind(&new_bookmark.collection)
+            .bind(new_bookmark.reading_time);
+        match old_bookmark.remote_key() {
+            Some(RemoteKey::Internal(provider_internal_id)) => query.bind(provider_internal_id),
+            Some(RemoteKey::Remote(provider_remote_id)) => query.bind(provider_remote_id),
+            None => query.bind(old_bookmark.id),
+        }
+        .bind(old_bookmark.user_account_id)
+        .execute(&self.conn)
+        .await
+        .unwrap();
     }
     pub async fn delete_all_bookmarks_of_account(&mut self, account_id: i64) {
         let query: &str = "DELETE FROM Bookmarks WHERE user_account_id = $1;";
@@ -419,70 +869,94 @@ impl SqliteDatabase {
             .await
             .unwrap();
     }
+    // NOTE: (vkhitrin) free text is searched using the FTS5 index, the returned snippets
+    //       are keyed by the bookmark ID.
     pub async fn search_bookmarks(
         &mut self,
-        search_string: String,
+        search_query: &SearchQuery,
         limit: u8,
         offset: usize,
         order_by: SortOption,
-    ) -> (usize, Vec<Bookmark>) {
+    ) -> (usize, Vec<Bookmark>, HashMap<i64, String>) {
+        let fts_query = search_query.fts_query();
         let order_by_string = match order_by {
-            SortOption::BookmarksDateNewest => "date_added DESC",
-            SortOption::BookmarksDateOldest => "date_added ASC",
-            SortOption::BookmarkAlphabeticalAscending => "title COLLATE NOCASE ASC",
-            SortOption::BookmarkAlphabeticalDescending => "title COLLATE NOCASE DESC",
+            SortOption::BookmarksDateNewest => "Bookmarks.date_added DESC",
+            SortOption::BookmarksDateOldest => "Bookmarks.date_added ASC",
+            SortOption::BookmarkAlphabeticalAscending => "Bookmarks.title COLLATE NOCASE ASC",
+            SortOption::BookmarkAlphabeticalDescending => "Bookmarks.title COLLATE NOCASE DESC",
+            // NOTE: (vkhitrin) matches in titles and tags are ranked higher.
+            SortOption::BookmarksRelevance if fts_query.is_some() => {
+                "bm25(BookmarksFTS, 2.0, 10.0, 4.0, 1.0, 5.0) ASC"
+            }
+            SortOption::BookmarksRelevance => "Bookmarks.date_added DESC",
         };
+        let (from_string, snippet_string) = if fts_query.is_some() {
+            (
+                "BookmarksFTS INNER JOIN Bookmarks ON Bookmarks.id = BookmarksFTS.rowid",
+                "snippet(BookmarksFTS, -1, $3, $4, '…', 16)",
+            )
+        } else {
+            ("Bookmarks", "NULL")
+        };
+        // NOTE: (vkhitrin) $1-$4 are reserved for pagination and snippets.
+        let (conditions, parameters) = Self::compile_search_query(search_query, 4);
+        let where_string = conditions.join(" AND ");
         let query = format!(
             r"
             WITH bookmark_count AS (
-                SELECT COUNT(*) AS count FROM Bookmarks
+                SELECT COUNT(*) AS count FROM {from_string}
                 INNER JOIN UserAccounts ON Bookmarks.user_account_id = UserAccounts.id
-                WHERE UserAccounts.enabled = 1 AND (
-                    coalesce(Bookmarks.url, '') || ' ' ||
-                    coalesce(Bookmarks.title, '') || ' ' ||
-                    coalesce(Bookmarks.description, '') || ' ' ||
-                    coalesce(Bookmarks.notes, '') || ' ' ||
-                    coalesce(Bookmarks.tag_names, '')
-                ) LIKE '%' || $1 || '%'
+                WHERE {where_string}
             )
             SELECT 
                 Bookmarks.*,
                 FaviconCache.favicon_url AS favicon_cache_favicon_url,
                 FaviconCache.favicon_data,
                 FaviconCache.last_sync_timestamp AS favicon_cache_last_sync_timestamp,
-                bookmark_count.count
+                bookmark_count.count,
+                {snippet_string} AS search_snippet
             FROM 
-                Bookmarks
+                {from_string}
             INNER JOIN 
                 UserAccounts ON Bookmarks.user_account_id = UserAccounts.id
             LEFT JOIN 
                 FaviconCache ON Bookmarks.favicon_url = FaviconCache.favicon_url,
                 bookmark_count
             WHERE 
-                UserAccounts.enabled = 1 AND (
-                    coalesce(Bookmarks.url, '') || ' ' ||
-                    coalesce(Bookmarks.title, '') || ' ' ||
-                    coalesce(Bookmarks.description, '') || ' ' ||
-                    coalesce(Bookmarks.notes, '') || ' ' ||
-                    coalesce(Bookmarks.tag_names, '')
-                ) LIKE '%' || $1 || '%'
+                {where_string}
             ORDER BY {order_by_string}
-            LIMIT $2 OFFSET $3;
+            LIMIT $1 OFFSET $2;
             "
         );
 
-        let result = sqlx::query(&query)
-            .bind(&search_string)
+        let mut sql_query = sqlx::query(&query)
             .bind(limit)
             .bind(offset.to_string())
-            .fetch_all(&self.conn)
-            .await
-            .unwrap();
+            .bind(search::SNIPPET_MATCH_START.to_string())
+            .bind(search::SNIPPET_MATCH_END.to_string());
+        for parameter in &parameters {
+            sql_query = sql_query.bind(parameter);
+        }
+        let result = match sql_query.fetch_all(&self.conn).await {
+            Ok(result) => result,
+            Err(e) => {
+                log::error!("Failed to search bookmarks using query {search_query:?}: {e}");
+                return (0, Vec::new(), HashMap::new());
+            }
+        };
 
         let row_count: usize = result
             .first()
             .map_or(0, |row| row.get::<i64, _>("count") as usize);
 
+        let snippets: HashMap<i64, String> = result
+            .iter()
+            .filter_map(|row| {
+                let snippet: Option<String> = row.get("search_snippet");
+                Some((row.get("id"), snippet?))
+            })
+            .collect();
+
         let data: Vec<Bookmark> = result
             .iter()
             .map(|row| {
@@ -519,30 +993,217 @@ impl SqliteDatabase {
                         row.get("favicon_data"),
                         row.get("favicon_cache_last_sync_timestamp"),
                     )),
+                    collection: row.get("collection"),
+                    provider_remote_id: row.get("provider_remote_id"),
+                    reading_time: row.get("reading_time"),
                 }
             })
             .collect();
-        (row_count, data)
+        (row_count, data, snippets)
+    }
+    // NOTE: (vkhitrin) compiles a search query into SQL conditions, values are never
+    //       formatted into the query and are bound as parameters (numbered after
+    //       `reserved_parameters`).
+    fn compile_search_query(
+        search_query: &SearchQuery,
+        reserved_parameters: usize,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut conditions: Vec<String> = vec!["UserAccounts.enabled = 1".to_string()];
+        let mut parameters: Vec<String> = Vec::new();
+        let mut bind = |value: String| {
+            parameters.push(value);
+            format!("${}", reserved_parameters + parameters.len())
+        };
+        if let Some(fts_query) = search_query.fts_query() {
+            conditions.push(format!("BookmarksFTS MATCH {}", bind(fts_query)));
+        }
+        if let Some(excluded_fts_query) = search_query.excluded_fts_query() {
+            conditions.push(format!(
+                "Bookmarks.id NOT IN (SELECT rowid FROM BookmarksFTS WHERE BookmarksFTS MATCH {})",
+                bind(excluded_fts_query)
+            ));
+        }
+        for condition in &search_query.conditions {
+            let condition_string = match &condition.filter {
+                SearchFilter::Account(display_name) => format!(
+                    "UserAccounts.display_name = {} COLLATE NOCASE",
+                    bind(display_name.clone())
+                ),
+                SearchFilter::Added(comparison, date) => format!(
+                    "date(Bookmarks.date_added) {} {}",
+                    comparison.as_sql(),
+                    bind(date.format("%Y-%m-%d").to_string())
+                ),
+                // NOTE: (vkhitrin) matches the collection and its subcollections.
+                SearchFilter::Collection(collection) => {
+                    let parameter = bind(collection.clone());
+                    format!(
+                        "(Bookmarks.collection IS NOT NULL AND (Bookmarks.collection = {parameter} COLLATE NOCASE OR lower(substr(Bookmarks.collection, 1, length({parameter}) + 1)) = lower({parameter} || '/')))"
+                    )
+                }
+                SearchFilter::Is(BookmarkState::Archived) => "Bookmarks.is_archived = 1".to_string(),
+                SearchFilter::Is(BookmarkState::Shared) => "Bookmarks.shared = 1".to_string(),
+                SearchFilter::Is(BookmarkState::Unread) => "Bookmarks.unread = 1".to_string(),
+                // NOTE: (vkhitrin) matches the host and its subdomains.
+                SearchFilter::Site(site) => {
+                    let parameter = bind(site.clone());
+                    format!(
+                        "(instr(Bookmarks.url, '://') > 0 AND EXISTS (SELECT 1 FROM ({BOOKMARK_HOST_QUERY}) WHERE host = {parameter} OR substr(host, -length({parameter}) - 1) = '.' || {parameter}))"
+                    )
+                }
+                SearchFilter::Tag(tag) => format!(
+                    "EXISTS (SELECT 1 FROM BookmarkTags INNER JOIN Tags ON Tags.id = BookmarkTags.tag_id WHERE BookmarkTags.bookmark_id = Bookmarks.id AND Tags.name = {})",
+                    bind(tag.clone())
+                ),
+            };
+            if condition.negated {
+                conditions.push(format!("NOT ({condition_string})"));
+            } else {
+                conditions.push(condition_string);
+            }
+        }
+        (conditions, parameters)
+    }
+    // NOTE: (vkhitrin) only bookmarks of enabled accounts are counted.
+    pub async fn select_tags(&mut self) -> Vec<Tag> {
+        let query: &str = r"
+            SELECT
+                Tags.name,
+                COUNT(BookmarkTags.bookmark_id) AS bookmarks_count
+            FROM
+                Tags
+            INNER JOIN
+                BookmarkTags ON BookmarkTags.tag_id = Tags.id
+            INNER JOIN
+                Bookmarks ON Bookmarks.id = BookmarkTags.bookmark_id
+            INNER JOIN
+                UserAccounts ON Bookmarks.user_account_id = UserAccounts.id
+            WHERE
+                UserAccounts.enabled = 1
+            GROUP BY
+                Tags.id
+            ORDER BY
+                Tags.name COLLATE NOCASE ASC;
+            ";
+        sqlx::query_as(query).fetch_all(&self.conn).await.unwrap()
+    }
+    // NOTE: (vkhitrin) bookmarks shared by other users can't be edited.
+    // NOTE: (vkhitrin) tag names are bound in chunks, SQLite limits the amount of variables
+    //       in a single statement.
+    pub async fn select_bookmarks_with_tags(
+        &mut self,
+        tag_names: &[String],
+    ) -> Result<Vec<Bookmark>> {
+        let mut bookmarks: Vec<Bookmark> = Vec::new();
+        for tag_names_chunk in tag_names.chunks(MAX_BOUND_PARAMETERS) {
+            let parameters: Vec<String> = (1..=tag_names_chunk.len())
+                .map(|i| format!("${i}"))
+                .collect();
+            let query = format!(
+                r"
+                SELECT
+                    Bookmarks.*
+                FROM
+                    Bookmarks
+                INNER JOIN
+                    UserAccounts ON Bookmarks.user_account_id = UserAccounts.id
+                WHERE
+                    UserAccounts.enabled = 1
+                    AND Bookmarks.is_owner = 1
+                    AND EXISTS (
+                        SELECT 1 FROM BookmarkTags
+                        INNER JOIN Tags ON Tags.id = BookmarkTags.tag_id
+                        WHERE BookmarkTags.bookmark_id = Bookmarks.id AND Tags.name IN ({})
+                    );
+                ",
+                parameters.join(", ")
+            );
+            let mut sql_query = sqlx::query(&query);
+            for tag_name in tag_names_chunk {
+                sql_query = sql_query.bind(tag_name);
+            }
+            let rows = sql_query.fetch_all(&self.conn).await?;
+            bookmarks.extend(rows.iter().map(Self::bookmark_from_row));
+        }
+        bookmarks.sort_by_key(|bookmark| (bookmark.user_account_id, bookmark.id));
+        bookmarks.dedup_by_key(|bookmark| bookmark.id);
+        Ok(bookmarks)
+    }
+    pub async fn select_bookmarks_by_ids(&mut self, bookmark_ids: &[i64]) -> Result<Vec<Bookmark>> {
+        let mut bookmarks: Vec<Bookmark> = Vec::new();
+        for bookmark_ids_chunk in bookmark_ids.chunks(MAX_BOUND_PARAMETERS) {
+            let parameters: Vec<String> = (1..=bookmark_ids_chunk.len())
+                .map(|i| format!("${i}"))
+                .collect();
+            let query = format!(
+                "SELECT * FROM Bookmarks WHERE id IN ({});",
+                parameters.join(", ")
+            );
+            let mut sql_query = sqlx::query(&query);
+            for bookmark_id in bookmark_ids_chunk {
+                sql_query = sql_query.bind(bookmark_id);
+            }
+            let rows = sql_query.fetch_all(&self.conn).await?;
+            bookmarks.extend(rows.iter().map(Self::bookmark_from_row));
+        }
+        bookmarks.sort_by_key(|bookmark| (bookmark.user_account_id, bookmark.id));
+        bookmarks.dedup_by_key(|bookmark| bookmark.id);
+        Ok(bookmarks)
+    }
+    // NOTE: (vkhitrin) used to select all bookmarks matching a search query (across all
+    //       pages), bookmarks shared by other users can't be selected.
+    pub async fn select_matching_bookmark_ids(&mut self, search_query: &SearchQuery) -> Vec<i64> {
+        let from_string = if search_query.fts_query().is_some() {
+            "BookmarksFTS INNER JOIN Bookmarks ON Bookmarks.id = BookmarksFTS.rowid"
+        } else {
+            "Bookmarks"
+        };
+        let (mut conditions, parameters) = Self::compile_search_query(search_query, 0);
+        conditions.push("Bookmarks.is_owner = 1".to_string());
+        let query = format!(
+            r"
+            SELECT
+                Bookmarks.id
+            FROM
+                {from_string}
+            INNER JOIN
+                UserAccounts ON Bookmarks.user_account_id = UserAccounts.id
+            WHERE
+                {};
+            ",
+            conditions.join(" AND ")
+        );
+        let mut sql_query = sqlx::query_scalar(&query);
+        for parameter in &parameters {
+            sql_query = sql_query.bind(parameter);
+        }
+        match sql_query.fetch_all(&self.conn).await {
+            Ok(result) => result,
+            Err(e) => {
+                log::error!("Failed to select bookmarks using query {search_query:?}: {e}");
+                Vec::new()
+            }
+        }
     }
     pub async fn select_single_account(&mut self, account_id: i64) -> Account {
         let query: &str = "SELECT * FROM UserAccounts WHERE id = $1;";
-        let result: Account = sqlx::query_as(query)
+        let mut result: Account = sqlx::query_as(query)
             .bind(account_id)
             .fetch_one(&self.conn)
             .await
             .unwrap();
+        resolve_api_token(&mut result).await;
         result
     }
     pub async fn check_if_account_exists(&mut self, url: &String, api_token: &String) -> bool {
-        let query: &str =
-            "SELECT COUNT(*) FROM UserAccounts WHERE instance = $1 AND api_token = $2;";
-        let result: bool = sqlx::query_scalar(query)
+        let query: &str = "SELECT * FROM UserAccounts WHERE instance = $1;";
+        let mut result: Vec<Account> = sqlx::query_as(query)
             .bind(url)
-            .bind(api_token)
-            .fetch_one(&self.conn)
+            .fetch_all(&self.conn)
             .await
             .unwrap();
-        result
+        resolve_api_tokens(&mut result).await;
+        result.iter().any(|account| &account.api_token == api_token)
     }
     pub async fn check_if_favicon_cache_exists(
         &mut self,
@@ -583,6 +1244,338 @@ impl SqliteDatabase {
         let query: &str = "DELETE FROM FaviconCache;";
         sqlx::query(query).execute(&self.conn).await.unwrap();
     }
+    // NOTE: (vkhitrin) consecutive operations on the same bookmark are collapsed, only
+    //       the latest state of the bookmark is replayed.
+    pub async fn enqueue_pending_operation(&mut self, operation: &PendingOperation) {
+        let existing_query: &str = "SELECT id, operation FROM PendingOperations WHERE user_account_id = $1 AND bookmark_id = $2 ORDER BY id DESC LIMIT 1;";
+        let update_query: &str =
+            "UPDATE PendingOperations SET provider_internal_id = $2, payload = $3, error = NULL WHERE id = $1;";
+        let delete_query: &str = "DELETE FROM PendingOperations WHERE id = $1;";
+        let insert_query: &str = "INSERT INTO PendingOperations (user_account_id, bookmark_id, provider_internal_id, operation, payload, created_timestamp) VALUES ($1, $2, $3, $4, $5, $6);";
+        let payload = serde_json::to_string(&operation.bookmark).unwrap();
+        let existing_operation: Option<(i64, String)> = match operation.bookmark_id {
+            Some(bookmark_id) => sqlx::query_as(existing_query)
+                .bind(operation.user_account_id)
+                .bind(bookmark_id)
+                .fetch_optional(&self.conn)
+                .await
+                .unwrap(),
+            None => None,
+        };
+        if let Some((existing_id, existing_kind)) = existing_operation {
+            match (
+                PendingOperationKind::from_str(&existing_kind),
+                operation.kind,
+            ) {
+                (
+                    Some(PendingOperationKind::Add | PendingOperationKind::Edit),
+                    PendingOperationKind::Edit,
+                ) => {
+                    sqlx::query(update_query)
+                        .bind(existing_id)
+                        .bind(operation.bookmark.provider_internal_id)
+                        .bind(&payload)
+                        .execute(&self.conn)
+                        .await
+                        .unwrap();
+                    return;
+                }
+                (_, PendingOperationKind::Remove) => {
+                    sqlx::query(delete_query)
+                        .bind(existing_id)
+                        .execute(&self.conn)
+                        .await
+                        .unwrap();
+                }
+                _ => {}
+            }
+        }
+        // NOTE: (vkhitrin) bookmarks that were never created remotely have nothing to remove.
+        if operation.kind == PendingOperationKind::Remove && !operation.bookmark.exists_remotely() {
+            return;
+        }
+        sqlx::query(insert_query)
+            .bind(operation.user_account_id)
+            .bind(operation.bookmark_id)
+            .bind(operation.bookmark.provider_internal_id)
+            .bind(operation.kind.to_string())
+            .bind(&payload)
+            .bind(operation.created_timestamp)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn select_pending_operations(&mut self, account_id: i64) -> Vec<PendingOperation> {
+        let query: &str = "SELECT * FROM PendingOperations WHERE user_account_id = $1 ORDER BY id;";
+        let result = sqlx::query(query)
+            .bind(account_id)
+            .fetch_all(&self.conn)
+            .await
+            .unwrap();
+
+        result
+            .iter()
+            .filter_map(|row| {
+                let kind = PendingOperationKind::from_str(row.get("operation"))?;
+                let bookmark: Bookmark = serde_json::from_str(row.get("payload")).ok()?;
+                Some(PendingOperation {
+                    id: row.get("id"),
+                    user_account_id: row.get("user_account_id"),
+                    bookmark_id: row.get("bookmark_id"),
+                    kind,
+                    bookmark,
+                    created_timestamp: row.get("created_timestamp"),
+                    error: row.get("error"),
+                })
+            })
+            .collect()
+    }
+    pub async fn select_pending_bookmark_ids(&mut self) -> HashSet<i64> {
+        let query: &str =
+            "SELECT bookmark_id FROM PendingOperations WHERE bookmark_id IS NOT NULL;";
+        let result: Vec<i64> = sqlx::query_scalar(query)
+            .fetch_all(&self.conn)
+            .await
+            .unwrap();
+        result.into_iter().collect()
+    }
+    pub async fn select_failed_pending_operations(&mut self) -> HashMap<i64, String> {
+        let query: &str = "SELECT bookmark_id, error FROM PendingOperations WHERE bookmark_id IS NOT NULL AND error IS NOT NULL;";
+        let result: Vec<(i64, String)> = sqlx::query_as(query).fetch_all(&self.conn).await.unwrap();
+        result.into_iter().collect()
+    }
+    // NOTE: (vkhitrin) operations rejected by the provider are kept with their error, they
+    //       are not replayed again until the bookmark is modified.
+    pub async fn fail_pending_operation(&mut self, operation_id: i64, error: &str) {
+        let query: &str = "UPDATE PendingOperations SET error = $2 WHERE id = $1;";
+        sqlx::query(query)
+            .bind(operation_id)
+            .bind(error)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn delete_pending_operation(&mut self, operation_id: i64) {
+        let query: &str = "DELETE FROM PendingOperations WHERE id = $1;";
+        sqlx::query(query)
+            .bind(operation_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn delete_all_pending_operations_of_account(&mut self, account_id: i64) {
+        let query: &str = "DELETE FROM PendingOperations WHERE user_account_id = $1;";
+        sqlx::query(query)
+            .bind(account_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    // NOTE: (vkhitrin) a bookmark that was created while the provider was unreachable is
+    //       linked to the remote bookmark, the remote bookmark may have already existed.
+    pub async fn complete_pending_bookmark(&mut self, bookmark_id: i64, bookmark: &Bookmark) {
+        let delete_query: &str = "DELETE FROM Bookmarks WHERE user_account_id = $1 AND (provider_internal_id = $2 OR provider_remote_id = $3) AND id != $4;";
+        sqlx::query(delete_query)
+            .bind(bookmark.user_account_id)
+            .bind(bookmark.provider_internal_id)
+            .bind(&bookmark.provider_remote_id)
+            .bind(bookmark_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+        Self::bind_bookmark(sqlx::query(UPDATE_BOOKMARK_BY_ID_QUERY), bookmark)
+            .bind(bookmark_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn select_replication_rules(&mut self) -> Vec<ReplicationRule> {
+        let query: &str = "SELECT * FROM ReplicationRules ORDER BY id;";
+        sqlx::query_as(query).fetch_all(&self.conn).await.unwrap()
+    }
+    pub async fn add_replication_rule(&mut self, rule: &ReplicationRule) {
+        let query: &str = "INSERT INTO ReplicationRules (source_account_id, target_account_id, filter) VALUES ($1, $2, $3);";
+        sqlx::query(query)
+            .bind(rule.source_account_id)
+            .bind(rule.target_account_id)
+            .bind(&rule.filter)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn update_replication_rule_summary(
+        &mut self,
+        rule_id: i64,
+        summary: &ReplicationSummary,
+        epoch_timestamp: i64,
+    ) {
+        let query: &str = "UPDATE ReplicationRules SET last_run_timestamp = $2, last_created_count = $3, last_updated_count = $4, last_skipped_count = $5, last_conflicted_count = $6 WHERE id = $1;";
+        sqlx::query(query)
+            .bind(rule_id)
+            .bind(epoch_timestamp)
+            .bind(summary.created as i64)
+            .bind(summary.updated as i64)
+            .bind(summary.skipped as i64)
+            .bind(summary.conflicted as i64)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn delete_replication_rule(&mut self, rule_id: i64) {
+        let query: &str = "DELETE FROM ReplicationRules WHERE id = $1;";
+        sqlx::query(query)
+            .bind(rule_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn delete_all_replication_rules_of_account(&mut self, account_id: i64) {
+        let query: &str =
+            "DELETE FROM ReplicationRules WHERE source_account_id = $1 OR target_account_id = $1;";
+        sqlx::query(query)
+            .bind(account_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    // NOTE: (vkhitrin) only bookmarks owned by the source account are replicated.
+    pub async fn select_replication_source_bookmarks(
+        &mut self,
+        account_id: i64,
+        search_query: &SearchQuery,
+    ) -> Vec<Bookmark> {
+        let from_string = if search_query.fts_query().is_some() {
+            "BookmarksFTS INNER JOIN Bookmarks ON Bookmarks.id = BookmarksFTS.rowid"
+        } else {
+            "Bookmarks"
+        };
+        let (mut conditions, parameters) = Self::compile_search_query(search_query, 1);
+        conditions.push("Bookmarks.user_account_id = $1".to_string());
+        conditions.push("Bookmarks.is_owner = 1".to_string());
+        let query = format!(
+            r"
+            SELECT
+                Bookmarks.*
+            FROM
+                {from_string}
+            INNER JOIN
+                UserAccounts ON Bookmarks.user_account_id = UserAccounts.id
+            WHERE
+                {}
+            ORDER BY
+                Bookmarks.id ASC;
+            ",
+            conditions.join(" AND ")
+        );
+        let mut sql_query = sqlx::query(&query).bind(account_id);
+        for parameter in &parameters {
+            sql_query = sql_query.bind(parameter);
+        }
+        match sql_query.fetch_all(&self.conn).await {
+            Ok(result) => result.iter().map(Self::bookmark_from_row).collect(),
+            Err(e) => {
+                log::error!("Failed to select bookmarks using query {search_query:?}: {e}");
+                Vec::new()
+            }
+        }
+    }
+    pub async fn add_sync_run(&mut self, sync_run: &SyncRun) {
+        let insert_query: &str = "INSERT INTO SyncRuns (user_account_id, start_timestamp, end_timestamp, successful, endpoint_statuses, added_count, updated_count, removed_count, error) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9);";
+        let prune_query: &str = "DELETE FROM SyncRuns WHERE user_account_id = $1 AND id NOT IN (SELECT id FROM SyncRuns WHERE user_account_id = $1 ORDER BY start_timestamp DESC, id DESC LIMIT $2);";
+        sqlx::query(insert_query)
+            .bind(sync_run.user_account_id)
+            .bind(sync_run.start_timestamp)
+            .bind(sync_run.end_timestamp)
+            .bind(sync_run.successful)
+            .bind(serde_json::to_string(&sync_run.endpoint_statuses).unwrap())
+            .bind(sync_run.added_count)
+            .bind(sync_run.updated_count)
+            .bind(sync_run.removed_count)
+            .bind(&sync_run.error)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+        sqlx::query(prune_query)
+            .bind(sync_run.user_account_id)
+            .bind(MAX_SYNC_RUNS_PER_ACCOUNT)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn select_sync_runs(&mut self, account_id: i64, limit: i64) -> Vec<SyncRun> {
+        let query: &str = "SELECT * FROM SyncRuns WHERE user_account_id = $1 ORDER BY start_timestamp DESC, id DESC LIMIT $2;";
+        let result = sqlx::query(query)
+            .bind(account_id)
+            .bind(limit)
+            .fetch_all(&self.conn)
+            .await
+            .unwrap();
+
+        result
+            .iter()
+            .map(|row| {
+                let endpoint_statuses: Vec<EndpointStatus> =
+                    serde_json::from_str(row.get("endpoint_statuses")).unwrap_or_default();
+                SyncRun {
+                    id: row.get("id"),
+                    user_account_id: row.get("user_account_id"),
+                    start_timestamp: row.get("start_timestamp"),
+                    end_timestamp: row.get("end_timestamp"),
+                    successful: row.get("successful"),
+                    endpoint_statuses,
+                    added_count: row.get("added_count"),
+                    updated_count: row.get("updated_count"),
+                    removed_count: row.get("removed_count"),
+                    error: row.get("error"),
+                }
+            })
+            .collect()
+    }
+    pub async fn delete_all_sync_runs_of_account(&mut self, account_id: i64) {
+        let query: &str = "DELETE FROM SyncRuns WHERE user_account_id = $1;";
+        sqlx::query(query)
+            .bind(account_id)
+            .execute(&self.conn)
+            .await
+            .unwrap();
+    }
+    pub async fn select_single_bookmark(&mut self, bookmark_id: i64) -> Option<Bookmark> {
+        let query: &str = "SELECT * FROM Bookmarks WHERE id = $1;";
+        let row = sqlx::query(query)
+            .bind(bookmark_id)
+            .fetch_optional(&self.conn)
+            .await
+            .ok()??;
+        Some(Self::bookmark_from_row(&row))
+    }
+    // NOTE: (vkhitrin) bookmarks of the account matching any of the URLs, keyed by their URL.
+    pub async fn select_bookmarks_by_urls(
+        &mut self,
+        account_id: i64,
+        urls: &[String],
+    ) -> Result<HashMap<String, Bookmark>> {
+        let mut bookmarks: HashMap<String, Bookmark> = HashMap::new();
+        for urls_chunk in urls.chunks(MAX_BOUND_PARAMETERS) {
+            let parameters: Vec<String> = (2..=urls_chunk.len() + 1)
+                .map(|i| format!("${i}"))
+                .collect();
+            let query = format!(
+                "SELECT * FROM Bookmarks WHERE user_account_id = $1 AND url IN ({});",
+                parameters.join(", ")
+            );
+            let mut sql_query = sqlx::query(&query).bind(account_id);
+            for url in urls_chunk {
+                sql_query = sql_query.bind(url);
+            }
+            let rows = sql_query.fetch_all(&self.conn).await?;
+            bookmarks.extend(rows.iter().map(|row| {
+                let bookmark = Self::bookmark_from_row(row);
+                (bookmark.url.clone(), bookmark)
+            }));
+        }
+        Ok(bookmarks)
+    }
     pub async fn find_bookmark_by_url(&mut self, account_id: i64, url: &str) -> Option<Bookmark> {
         let query: &str = "SELECT * FROM Bookmarks WHERE user_account_id = $1 AND url = $2;";
         let result = sqlx::query(query)
@@ -595,6 +1588,9 @@ impl SqliteDatabase {
         result.as_ref()?;
 
         let row = result.unwrap();
+        Some(Self::bookmark_from_row(&row))
+    }
+    fn bookmark_from_row(row: &sqlx::sqlite::SqliteRow) -> Bookmark {
         let tags_string: String = row.get("tag_names");
         let mut tags: Vec<String> = Vec::new();
         if !tags_string.is_empty() {
@@ -604,7 +1600,7 @@ impl SqliteDatabase {
                 .collect();
         }
 
-        Some(Bookmark {
+        Bookmark {
             id: row.get("id"),
             provider_internal_id: row.get("provider_internal_id"),
             user_account_id: row.get("user_account_id"),
@@ -625,6 +1621,689 @@ impl SqliteDatabase {
             website_description: row.get("website_description"),
             is_owner: row.get("is_owner"),
             favicon_cached: None,
-        })
+            collection: row.get("collection"),
+            provider_remote_id: row.get("provider_remote_id"),
+            reading_time: row.get("reading_time"),
+        }
+    }
+}
+
+async fn resolve_api_tokens(accounts: &mut [Account]) {
+    for account in accounts {
+        resolve_api_token(account).await;
+    }
+}
+
+async fn resolve_api_token(account: &mut Account) {
+    if let Some(reference) = &account.api_token_reference {
+        match secrets::load_secret(reference).await {
+            Ok(api_token) => account.api_token = api_token,
+            Err(e) => log::error!(
+                "Failed to load API token of account {}: {e}",
+                account.display_name
+            ),
+        }
+        if account
+            .provider()
+            .is_some_and(|provider| provider.requires_client_credentials())
+        {
+            match secrets::load_secret(&client_secret_reference(reference)).await {
+                Ok(client_secret) => account.client_secret = client_secret,
+                Err(e) => log::error!(
+                    "Failed to load client secret of account {}: {e}",
+                    account.display_name
+                ),
+            }
+        }
+        if !account.extra_headers.0.is_empty() {
+            let extra_headers = secrets::load_secret(&extra_headers_reference(reference))
+                .await
+                .and_then(|extra_headers| Ok(serde_json::from_str(&extra_headers)?));
+            match extra_headers {
+                Ok(extra_headers) => account.extra_headers = extra_headers,
+                Err(e) => log::error!(
+                    "Failed to load headers of account {}: {e}",
+                    account.display_name
+                ),
+            }
+        }
+    }
+}
+
+// NOTE: (vkhitrin) the client secret and header values are stored next to the API token,
+//       their references are derived from the API token reference.
+fn client_secret_reference(api_token_reference: &str) -> String {
+    format!("{api_token_reference}-client-secret")
+}
+
+fn extra_headers_reference(api_token_reference: &str) -> String {
+    format!("{api_token_reference}-extra-headers")
+}
+
+// NOTE: (vkhitrin) an empty token does not replace a stored one, accounts are loaded without
+//       their token while the secret storage is locked. Header values are never written to
+//       the database, storing them fails (and the edit is refused) while it is locked.
+async fn store_account_secrets(account: &Account) -> Result<(Option<String>, RequestHeaders)> {
+    let has_api_token = !account.api_token.is_empty();
+    if !has_api_token && !account.extra_headers.has_values() {
+        return Ok((
+            account.api_token_reference.clone(),
+            account.extra_headers.without_values(),
+        ));
+    }
+    let reference = account
+        .api_token_reference
+        .clone()
+        .unwrap_or_else(secrets::new_reference);
+    if has_api_token {
+        secrets::store_secret(&reference, &account.display_name, &account.api_token).await?;
+        if !account.client_secret.is_empty() {
+            secrets::store_secret(
+                &client_secret_reference(&reference),
+                &account.display_name,
+                &account.client_secret,
+            )
+            .await?;
+        }
+    }
+    secrets::store_secret(
+        &extra_headers_reference(&reference),
+        &account.display_name,
+        &serde_json::to_string(&account.extra_headers)?,
+    )
+    .await?;
+    Ok((Some(reference), account.extra_headers.without_values()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::{migrate::Migrator, sqlite::SqlitePoolOptions};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("{APP}-{name}-{}-{nanos}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn companion_path(db_path: &Path, suffix: &str) -> PathBuf {
+        PathBuf::from(format!("{}{suffix}", db_path.display()))
+    }
+
+    // NOTE: (vkhitrin) every connection to an in-memory database opens a new database, the
+    //       pool is limited to a single connection that is never closed.
+    async fn memory_database() -> SqliteDatabase {
+        let conn = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        Migrator::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations"))
+            .await
+            .unwrap()
+            .run(&conn)
+            .await
+            .unwrap();
+        SqliteDatabase { conn }
+    }
+
+    async fn remote_account(db: &mut SqliteDatabase) -> Account {
+        let account = Account::new(
+            "Remote".to_string(),
+            String::new(),
+            "https://links.example.com".to_string(),
+            "linkding",
+        );
+        db.create_account(&account).await.unwrap();
+        db.select_accounts()
+            .await
+            .into_iter()
+            .find(|account| account.display_name == "Remote")
+            .unwrap()
+    }
+
+    fn remote_bookmark(
+        account: &Account,
+        provider_internal_id: i64,
+        date_modified: &str,
+    ) -> Bookmark {
+        Bookmark::new(
+            account.id,
+            Some(provider_internal_id),
+            format!("https://example.com/{provider_internal_id}"),
+            format!("Bookmark {provider_internal_id} ({date_modified})"),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            false,
+            false,
+            false,
+            Vec::new(),
+            Some("2024-01-01T00:00:00Z".to_string()),
+            Some(date_modified.to_string()),
+            Some(true),
+        )
+    }
+
+    async fn synced_titles(db: &SqliteDatabase, account: &Account) -> Vec<(i64, String)> {
+        sqlx::query_as("SELECT provider_internal_id, title FROM Bookmarks WHERE user_account_id = $1 ORDER BY provider_internal_id;")
+            .bind(account.id)
+            .fetch_all(&db.conn)
+            .await
+            .unwrap()
+    }
+
+    async fn remote_titles(db: &SqliteDatabase, account: &Account) -> Vec<(String, String)> {
+        sqlx::query_as("SELECT provider_remote_id, title FROM Bookmarks WHERE user_account_id = $1 ORDER BY provider_remote_id;")
+            .bind(account.id)
+            .fetch_all(&db.conn)
+            .await
+            .unwrap()
+    }
+
+    async fn sync(
+        db: &mut SqliteDatabase,
+        account: &Account,
+        bookmarks: Vec<Bookmark>,
+        is_full_sync: bool,
+    ) -> SyncCounts {
+        db.aggregate_bookmarks_for_account(account, bookmarks, 1, true, is_full_sync, true)
+            .await
+    }
+
+    #[tokio::test]
+    async fn aggregate_bookmarks_inserts_updates_and_skips_unchanged() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+        let first = "2024-01-01T00:00:00Z";
+        let second = "2024-02-01T00:00:00Z";
+
+        let counts = sync(
+            &mut db,
+            &account,
+            vec![
+                remote_bookmark(&account, 1, first),
+                remote_bookmark(&account, 2, first),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!((counts.added, counts.updated, counts.removed), (2, 0, 0));
+
+        let counts = sync(
+            &mut db,
+            &account,
+            vec![
+                remote_bookmark(&account, 1, first),
+                remote_bookmark(&account, 2, second),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!((counts.added, counts.updated, counts.removed), (0, 1, 0));
+        assert_eq!(
+            synced_titles(&db, &account).await,
+            vec![
+                (1, format!("Bookmark 1 ({first})")),
+                (2, format!("Bookmark 2 ({second})")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn complete_bookmarks_sync_removes_stale_bookmarks_only_on_full_sync() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+        let date_modified = "2024-01-01T00:00:00Z";
+        sync(
+            &mut db,
+            &account,
+            vec![
+                remote_bookmark(&account, 1, date_modified),
+                remote_bookmark(&account, 2, date_modified),
+            ],
+            true,
+        )
+        .await;
+
+        let counts = sync(&mut db, &account, Vec::new(), false).await;
+        assert_eq!(counts.removed, 0);
+        assert_eq!(synced_titles(&db, &account).await.len(), 2);
+
+        let counts = sync(
+            &mut db,
+            &account,
+            vec![remote_bookmark(&account, 1, date_modified)],
+            true,
+        )
+        .await;
+        assert_eq!((counts.added, counts.updated, counts.removed), (0, 0, 1));
+        assert_eq!(
+            synced_titles(&db, &account)
+                .await
+                .into_iter()
+                .map(|(provider_internal_id, _)| provider_internal_id)
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregate_bookmarks_reconciles_bookmarks_by_remote_identifier() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+        let first = "2024-01-01T00:00:00Z";
+        let second = "2024-02-01T00:00:00Z";
+        let karakeep_bookmark = |remote_id: &str, date_modified: &str| {
+            let mut bookmark = remote_bookmark(&account, 0, date_modified);
+            bookmark.provider_internal_id = None;
+            bookmark.provider_remote_id = Some(remote_id.to_string());
+            bookmark.title = format!("{remote_id} ({date_modified})");
+            bookmark
+        };
+        sync(
+            &mut db,
+            &account,
+            vec![karakeep_bookmark("a", first), karakeep_bookmark("b", first)],
+            true,
+        )
+        .await;
+
+        let counts = sync(
+            &mut db,
+            &account,
+            vec![karakeep_bookmark("a", second)],
+            true,
+        )
+        .await;
+        assert_eq!((counts.added, counts.updated, counts.removed), (0, 1, 1));
+        assert_eq!(
+            remote_titles(&db, &account).await,
+            vec![("a".to_string(), format!("a ({second})"))]
+        );
+
+        let mut edited_bookmark = karakeep_bookmark("a", second);
+        edited_bookmark.title = "Edited".to_string();
+        db.update_bookmark(&edited_bookmark, &edited_bookmark).await;
+        assert_eq!(
+            remote_titles(&db, &account).await,
+            vec![("a".to_string(), "Edited".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregate_bookmarks_skips_bookmarks_with_pending_operations() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+        let first = "2024-01-01T00:00:00Z";
+        let second = "2024-02-01T00:00:00Z";
+        sync(
+            &mut db,
+            &account,
+            vec![
+                remote_bookmark(&account, 1, first),
+                remote_bookmark(&account, 2, first),
+            ],
+            true,
+        )
+        .await;
+        let bookmark_id: i64 =
+            sqlx::query_scalar("SELECT id FROM Bookmarks WHERE provider_internal_id = 2;")
+                .fetch_one(&db.conn)
+                .await
+                .unwrap();
+        let mut pending_bookmark = remote_bookmark(&account, 2, first);
+        pending_bookmark.id = Some(bookmark_id);
+        db.enqueue_pending_operation(&PendingOperation::new(
+            account.id.unwrap(),
+            PendingOperationKind::Edit,
+            pending_bookmark,
+            1,
+        ))
+        .await;
+
+        let counts = sync(
+            &mut db,
+            &account,
+            vec![
+                remote_bookmark(&account, 1, first),
+                remote_bookmark(&account, 2, second),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!((counts.added, counts.updated, counts.removed), (0, 0, 0));
+
+        let counts = sync(
+            &mut db,
+            &account,
+            vec![remote_bookmark(&account, 1, first)],
+            true,
+        )
+        .await;
+        assert_eq!(counts.removed, 0);
+        assert_eq!(
+            synced_titles(&db, &account).await,
+            vec![
+                (1, format!("Bookmark 1 ({first})")),
+                (2, format!("Bookmark 2 ({first})")),
+            ]
+        );
+    }
+
+    #[test]
+    fn migrate_from_cache_dir_moves_database_and_companions() {
+        let root = temp_dir("migrate");
+        let legacy_db_path = root.join("cache").join(DB_PATH);
+        let db_path = root.join("data").join(DB_PATH);
+        std::fs::create_dir_all(legacy_db_path.parent().unwrap()).unwrap();
+        for suffix in ["", "-wal", "-shm"] {
+            std::fs::write(companion_path(&legacy_db_path, suffix), suffix).unwrap();
+        }
+
+        SqliteDatabase::migrate_from_cache_dir(&legacy_db_path, &db_path).unwrap();
+
+        for suffix in ["", "-wal", "-shm"] {
+            assert!(!companion_path(&legacy_db_path, suffix).exists());
+            assert_eq!(
+                std::fs::read_to_string(companion_path(&db_path, suffix)).unwrap(),
+                suffix
+            );
+        }
+        assert!(!companion_path(&db_path, "-journal").exists());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn migrate_from_cache_dir_keeps_existing_database() {
+        let root = temp_dir("existing");
+        let legacy_db_path = root.join("cache").join(DB_PATH);
+        let db_path = root.join("data").join(DB_PATH);
+        std::fs::create_dir_all(legacy_db_path.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+        std::fs::write(&legacy_db_path, "legacy").unwrap();
+        std::fs::write(companion_path(&legacy_db_path, "-wal"), "legacy").unwrap();
+        std::fs::write(&db_path, "current").unwrap();
+
+        SqliteDatabase::migrate_from_cache_dir(&legacy_db_path, &db_path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&db_path).unwrap(), "current");
+        assert!(legacy_db_path.exists());
+        assert!(!companion_path(&db_path, "-wal").exists());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn migrate_from_cache_dir_ignores_missing_database() {
+        let root = temp_dir("missing");
+        let legacy_db_path = root.join("cache").join(DB_PATH);
+        let db_path = root.join("data").join(DB_PATH);
+
+        SqliteDatabase::migrate_from_cache_dir(&legacy_db_path, &db_path).unwrap();
+
+        assert!(!db_path.exists());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    async fn pending_operation(
+        db: &mut SqliteDatabase,
+        account: &Account,
+        kind: PendingOperationKind,
+        provider_internal_id: Option<i64>,
+        title: &str,
+    ) {
+        let mut bookmark = remote_bookmark(account, 0, "2024-01-01T00:00:00Z");
+        bookmark.id = Some(1);
+        bookmark.provider_internal_id = provider_internal_id;
+        bookmark.title = title.to_string();
+        db.enqueue_pending_operation(&PendingOperation::new(
+            account.id.unwrap(),
+            kind,
+            bookmark,
+            1,
+        ))
+        .await;
+    }
+
+    async fn pending_operations(
+        db: &mut SqliteDatabase,
+        account: &Account,
+    ) -> Vec<(PendingOperationKind, Option<i64>, String)> {
+        db.select_pending_operations(account.id.unwrap())
+            .await
+            .into_iter()
+            .map(|operation| {
+                (
+                    operation.kind,
+                    operation.bookmark.provider_internal_id,
+                    operation.bookmark.title,
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn enqueue_pending_operation_folds_edits_into_previous_operation() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+
+        pending_operation(&mut db, &account, PendingOperationKind::Add, None, "Added").await;
+        pending_operation(
+            &mut db,
+            &account,
+            PendingOperationKind::Edit,
+            None,
+            "Edited",
+        )
+        .await;
+        assert_eq!(
+            pending_operations(&mut db, &account).await,
+            vec![(PendingOperationKind::Add, None, "Edited".to_string())]
+        );
+
+        sqlx::query("DELETE FROM PendingOperations;")
+            .execute(&db.conn)
+            .await
+            .unwrap();
+        pending_operation(
+            &mut db,
+            &account,
+            PendingOperationKind::Edit,
+            Some(5),
+            "First",
+        )
+        .await;
+        pending_operation(
+            &mut db,
+            &account,
+            PendingOperationKind::Edit,
+            Some(5),
+            "Second",
+        )
+        .await;
+        assert_eq!(
+            pending_operations(&mut db, &account).await,
+            vec![(PendingOperationKind::Edit, Some(5), "Second".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn enqueue_pending_operation_collapses_removals() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+
+        pending_operation(&mut db, &account, PendingOperationKind::Add, None, "Added").await;
+        pending_operation(
+            &mut db,
+            &account,
+            PendingOperationKind::Remove,
+            None,
+            "Added",
+        )
+        .await;
+        assert!(pending_operations(&mut db, &account).await.is_empty());
+
+        pending_operation(
+            &mut db,
+            &account,
+            PendingOperationKind::Edit,
+            Some(5),
+            "Edited",
+        )
+        .await;
+        pending_operation(
+            &mut db,
+            &account,
+            PendingOperationKind::Remove,
+            Some(5),
+            "Edited",
+        )
+        .await;
+        assert_eq!(
+            pending_operations(&mut db, &account).await,
+            vec![(PendingOperationKind::Remove, Some(5), "Edited".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn select_bookmarks_by_urls_selects_bookmarks_of_account() {
+        let mut db = memory_database().await;
+        let account = remote_account(&mut db).await;
+        let date_modified = "2024-01-01T00:00:00Z";
+        sync(
+            &mut db,
+            &account,
+            vec![
+                remote_bookmark(&account, 1, date_modified),
+                remote_bookmark(&account, 2, date_modified),
+            ],
+            true,
+        )
+        .await;
+
+        let bookmarks = db
+            .select_bookmarks_by_urls(
+                account.id.unwrap(),
+                &[
+                    "https://example.com/2".to_string(),
+                    "https://example.com/3".to_string(),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(bookmarks.len(), 1);
+        assert_eq!(
+            bookmarks["https://example.com/2"].provider_internal_id,
+            Some(2)
+        );
+
+        let bookmarks = db
+            .select_bookmarks_by_urls(
+                account.id.unwrap() + 1,
+                &["https://example.com/2".to_string()],
+            )
+            .await
+            .unwrap();
+        assert!(bookmarks.is_empty());
+    }
+
+    fn account_with_headers(api_token: &str) -> Account {
+        let mut account = Account::new(
+            "Headers".to_string(),
+            api_token.to_string(),
+            "https://links.example.com".to_string(),
+            "linkding",
+        );
+        account.extra_headers = RequestHeaders(vec![crate::models::account::RequestHeader {
+            name: "X-Api-Key".to_string(),
+            value: "header-secret".to_string(),
+        }]);
+        account
+    }
+
+    async fn stored_extra_headers(db: &SqliteDatabase) -> Vec<String> {
+        sqlx::query_scalar("SELECT extra_headers FROM UserAccounts WHERE display_name = 'Headers';")
+            .fetch_all(&db.conn)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_account_stores_header_values_as_secrets() {
+        secrets::initialize_for_tests();
+        let mut db = memory_database().await;
+        db.create_account(&account_with_headers("")).await.unwrap();
+        db.create_account(&account_with_headers("api-token"))
+            .await
+            .unwrap();
+
+        let stored_extra_headers = stored_extra_headers(&db).await;
+        assert_eq!(stored_extra_headers.len(), 2);
+        assert!(stored_extra_headers
+            .iter()
+            .all(|extra_headers| !extra_headers.contains("header-secret")));
+        for account in db
+            .select_accounts()
+            .await
+            .into_iter()
+            .filter(|account| account.display_name == "Headers")
+        {
+            assert_eq!(
+                account.extra_headers,
+                account_with_headers("").extra_headers
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn migrate_api_tokens_moves_secrets() {
+        secrets::initialize_for_tests();
+        let mut db = memory_database().await;
+        sqlx::query("INSERT INTO UserAccounts (display_name, instance, api_token, last_sync_status, last_sync_timestamp, trust_invalid_certs, enabled, enable_sharing, enable_public_sharing, provider, extra_headers) VALUES ('Legacy', 'https://links.example.com', 'api-token', 0, 0, 0, 1, 0, 0, 'linkding', $1);")
+            .bind(serde_json::to_string(&account_with_headers("").extra_headers).unwrap())
+            .execute(&db.conn)
+            .await
+            .unwrap();
+
+        assert_eq!(db.migrate_api_tokens().await.unwrap(), 2);
+
+        let (api_token, api_token_reference, extra_headers): (String, Option<String>, String) =
+            sqlx::query_as(
+                "SELECT api_token, api_token_reference, extra_headers FROM UserAccounts WHERE display_name = 'Legacy';",
+            )
+            .fetch_one(&db.conn)
+            .await
+            .unwrap();
+        assert!(api_token.is_empty());
+        assert!(!extra_headers.contains("header-secret"));
+        assert_eq!(
+            secrets::load_secret(&api_token_reference.unwrap())
+                .await
+                .unwrap(),
+            "api-token"
+        );
+        let account = db
+            .select_accounts()
+            .await
+            .into_iter()
+            .find(|account| account.display_name == "Legacy")
+            .unwrap();
+        assert_eq!(account.api_token, "api-token");
+        assert_eq!(
+            account.extra_headers,
+            account_with_headers("").extra_headers
+        );
+        assert_eq!(db.migrate_api_tokens().await.unwrap(), 0);
     }
 }
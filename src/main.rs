@@ -4,6 +4,7 @@ mod db;
 mod models;
 mod pages;
 mod provider;
+mod secrets;
 mod style;
 mod utils;
 mod widgets;
@@ -1,35 +1,121 @@
+use cosmic::widget;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use std::fmt;
 
-use crate::models::provider;
+use crate::{
+    models::provider::COSMICDING_PROVIDER,
+    provider::{get_provider, BookmarkProvider},
+};
+
+// NOTE: (vkhitrin) delta syncs can't detect bookmarks that were removed remotely, a full
+//       sync is performed periodically to reconcile removed bookmarks.
+pub const FULL_SYNC_INTERVAL_SECONDS: i64 = 86400;
+
+// NOTE: (vkhitrin) intervals (in minutes) that can be selected for background syncs, `0`
+//       disables background syncs of the account.
+pub const SYNC_INTERVAL_OPTIONS: [i64; 6] = [0, 15, 30, 60, 360, 1440];
+pub const DEFAULT_SYNC_INTERVAL_MINUTES: i64 = 60;
+// NOTE: (vkhitrin) the interval is doubled after each consecutive failed sync, up to
+//       2^MAX_SYNC_BACKOFF_EXPONENT times the interval.
+pub const MAX_SYNC_BACKOFF_EXPONENT: u32 = 5;
+
+// NOTE: (vkhitrin) a static header that is sent with every request of an account, in
+//       addition to the provider's authorization header.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+pub struct RequestHeader {
+    pub name: String,
+    pub value: String,
+}
+
+// NOTE: (vkhitrin) headers are stored as a JSON array in the database.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct RequestHeaders(pub Vec<RequestHeader>);
+
+impl TryFrom<String> for RequestHeaders {
+    type Error = serde_json::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        serde_json::from_str(&value)
+    }
+}
+
+impl RequestHeaders {
+    // NOTE: (vkhitrin) header values may contain credentials, they are stored in the secret
+    //       storage and only the header names are stored in the database.
+    pub fn without_values(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|header| RequestHeader {
+                    name: header.name.clone(),
+                    value: String::new(),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn has_values(&self) -> bool {
+        self.0.iter().any(|header| !header.value.is_empty())
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow, Eq, PartialEq)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Account {
     pub api_token: String,
+    // NOTE: (vkhitrin) the API token is stored in the secret storage, `api_token` is
+    //       populated from it when accounts are loaded from the database.
+    pub api_token_reference: Option<String>,
+    // NOTE: (vkhitrin) paths to PEM files, a CA bundle that is trusted in addition to the
+    //       system certificates and a client certificate/key pair used for mutual TLS.
+    pub ca_certificate_path: Option<String>,
+    pub client_certificate_path: Option<String>,
+    pub client_key_path: Option<String>,
+    // NOTE: (vkhitrin) OAuth client credentials, only used by providers that require them.
+    //       The client secret is stored in the secret storage alongside the API token.
+    pub client_id: Option<String>,
+    #[sqlx(skip)]
+    pub client_secret: String,
     pub display_name: String,
     pub enable_public_sharing: bool,
     pub enable_sharing: bool,
     pub enabled: bool,
+    #[sqlx(try_from = "String")]
+    pub extra_headers: RequestHeaders,
     pub id: Option<i64>,
     pub instance: String,
+    pub last_full_sync_timestamp: i64,
     pub last_sync_status: bool,
     pub last_sync_timestamp: i64,
     #[sqlx(rename = "provider")]
     #[serde(rename = "provider")]
     pub provider_string: String,
     pub provider_version: Option<String>,
+    pub proxy_url: Option<String>,
+    pub sync_cursor: Option<String>,
+    pub sync_interval_minutes: i64,
     pub trust_invalid_certs: bool,
+    // NOTE: (vkhitrin) only used by providers that authenticate using a username and a
+    //       password (stored as the API token).
+    pub username: Option<String>,
 }
 
 impl Account {
-    pub fn provider(&self) -> provider::Provider {
-        provider::Provider::from_str(&self.provider_string)
+    pub fn provider(&self) -> Option<&'static dyn BookmarkProvider> {
+        get_provider(&self.provider_string)
+    }
+
+    pub fn provider_icon(&self) -> widget::icon::Handle {
+        match self.provider() {
+            Some(provider) => provider.svg_icon(),
+            None => widget::icon::from_name("dialog-question-symbolic").handle(),
+        }
     }
 
-    pub fn set_provider(&mut self, provider: provider::Provider) {
-        self.provider_string = provider.to_string();
+    pub fn set_provider(&mut self, provider_string: &str) {
+        self.provider_string = provider_string.to_string();
     }
 }
 
@@ -42,32 +128,45 @@ impl AsRef<str> for Account {
 impl fmt::Display for Account {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.provider() {
-            provider::Provider::Cosmicding => write!(f, "{} [Local]", self.display_name),
-            provider::Provider::Linkding => write!(f, "{} [Linkding]", self.display_name),
+            _ if self.is_local_provider() => write!(f, "{} [Local]", self.display_name),
+            Some(provider) => write!(f, "{} [{}]", self.display_name, provider.display_name()),
+            None => write!(f, "{} [{}]", self.display_name, self.provider_string),
         }
     }
 }
 
 impl Account {
-    pub fn new(name: String, token: String, url: String, provider: provider::Provider) -> Self {
+    pub fn new(name: String, token: String, url: String, provider_string: &str) -> Self {
         Self {
             api_token: token,
+            api_token_reference: None,
+            ca_certificate_path: None,
+            client_certificate_path: None,
+            client_key_path: None,
+            client_id: None,
+            client_secret: String::new(),
             display_name: name,
             enable_public_sharing: false,
             enable_sharing: false,
             enabled: true,
+            extra_headers: RequestHeaders::default(),
             id: None,
             instance: url,
+            last_full_sync_timestamp: 0,
             last_sync_status: false,
             last_sync_timestamp: 0,
-            provider_string: provider.to_string(),
+            provider_string: provider_string.to_string(),
             provider_version: None,
+            proxy_url: None,
+            sync_cursor: None,
+            sync_interval_minutes: DEFAULT_SYNC_INTERVAL_MINUTES,
             trust_invalid_certs: false,
+            username: None,
         }
     }
 
     pub fn is_local_provider(&self) -> bool {
-        matches!(self.provider(), provider::Provider::Cosmicding)
+        self.provider_string == COSMICDING_PROVIDER
     }
 
     pub fn requires_remote_sync(&self, other: &Account) -> bool {
@@ -82,8 +181,35 @@ impl Account {
             || self.id != other.id
             || self.instance != other.instance
             || self.trust_invalid_certs != other.trust_invalid_certs
+            || self.username != other.username
+            || self.client_id != other.client_id
+            || self.client_secret != other.client_secret
             || self.provider_string != other.provider_string
             || self.provider_version != other.provider_version
+            || self.proxy_url != other.proxy_url
+            || self.extra_headers != other.extra_headers
+            || self.ca_certificate_path != other.ca_certificate_path
+            || self.client_certificate_path != other.client_certificate_path
+            || self.client_key_path != other.client_key_path
+    }
+
+    // NOTE: (vkhitrin) returns the cursor that should be used to fetch only bookmarks
+    //       modified since the previous sync, `None` indicates that a full sync is required.
+    pub fn delta_sync_cursor(&self, epoch_timestamp: i64) -> Option<&str> {
+        if epoch_timestamp - self.last_full_sync_timestamp >= FULL_SYNC_INTERVAL_SECONDS {
+            return None;
+        }
+        self.sync_cursor.as_deref()
+    }
+
+    // NOTE: (vkhitrin) returns when the account should be synced in the background,
+    //       `None` indicates that background syncs are disabled for the account.
+    pub fn next_sync_timestamp(&self, consecutive_failures: u32) -> Option<i64> {
+        if self.is_local_provider() || !self.enabled || self.sync_interval_minutes <= 0 {
+            return None;
+        }
+        let backoff_multiplier = 2_i64.pow(consecutive_failures.min(MAX_SYNC_BACKOFF_EXPONENT));
+        Some(self.last_sync_timestamp + self.sync_interval_minutes * 60 * backoff_multiplier)
     }
 }
 
@@ -119,3 +245,13 @@ pub struct LinkdingAccountApiResponse {
     pub version: Option<String>,
     pub web_archive_integration: String,
 }
+
+// NOTE: (vkhitrin) account details reported by a provider when an account is validated.
+#[derive(Debug, Clone, Default)]
+pub struct AccountDetails {
+    pub enable_public_sharing: bool,
+    pub enable_sharing: bool,
+    pub error: Option<String>,
+    pub successful: bool,
+    pub version: Option<String>,
+}
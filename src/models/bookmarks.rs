@@ -1,4 +1,4 @@
-use crate::models::account::Account;
+use crate::models::{account::Account, sync_run::EndpointStatus};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -26,6 +26,33 @@ pub struct Bookmark {
     pub date_modified: Option<String>,
     pub is_owner: Option<bool>,
     pub favicon_cached: Option<Favicon>,
+    // NOTE: (vkhitrin) path of the collection the bookmark belongs to (e.g. `Work/Projects`),
+    //       only set by providers that organize bookmarks into collections.
+    pub collection: Option<String>,
+    // NOTE: (vkhitrin) identifier of the bookmark for providers with non-numeric identifiers,
+    //       `provider_internal_id` is not set for them.
+    pub provider_remote_id: Option<String>,
+    // NOTE: (vkhitrin) estimated reading time (in minutes), only set by read-later providers.
+    pub reading_time: Option<i64>,
+}
+
+// NOTE: (vkhitrin) identifies a bookmark within its account on the provider, bookmarks are
+//       reconciled using it during a sync.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum RemoteKey {
+    Internal(i64),
+    Remote(String),
+}
+
+impl RemoteKey {
+    pub fn new(
+        provider_internal_id: Option<i64>,
+        provider_remote_id: Option<String>,
+    ) -> Option<Self> {
+        provider_remote_id
+            .map(RemoteKey::Remote)
+            .or(provider_internal_id.map(RemoteKey::Internal))
+    }
 }
 
 // NOTE: (vkhitrin) as of March 1st, 2025, linkding doesn't expose the user which shared the
@@ -89,8 +116,19 @@ impl Bookmark {
             date_modified: linkding_date_modified,
             is_owner: internal_workaround_is_owner,
             favicon_cached: None,
+            collection: None,
+            provider_remote_id: None,
+            reading_time: None,
         }
     }
+    pub fn remote_key(&self) -> Option<RemoteKey> {
+        RemoteKey::new(self.provider_internal_id, self.provider_remote_id.clone())
+    }
+    // NOTE: (vkhitrin) bookmarks that were created remotely, as opposed to bookmarks that
+    //       only exist locally (e.g. created while the provider was unreachable).
+    pub fn exists_remotely(&self) -> bool {
+        self.provider_internal_id.is_some() || self.provider_remote_id.is_some()
+    }
     pub fn merge(self, other: Self) -> Self {
         Self {
             id: self.id.or(other.id),
@@ -113,6 +151,64 @@ impl Bookmark {
             date_modified: self.date_modified.or(other.date_modified),
             is_owner: self.is_owner,
             favicon_cached: self.favicon_cached,
+            collection: other.collection.or(self.collection),
+            provider_remote_id: self.provider_remote_id.or(other.provider_remote_id),
+            reading_time: other.reading_time.or(self.reading_time),
+        }
+    }
+    // NOTE: (vkhitrin) fields that were modified locally (compared to the cached bookmark)
+    //       are preferred, the remaining fields are taken from the remote bookmark.
+    //       When the cached bookmark is not available, local fields are always preferred.
+    //       Tags that were added or removed locally are applied on top of the remote tags.
+    pub fn merge_conflict(self, cached: Option<&Self>, remote: Self) -> Self {
+        fn pick<T: PartialEq>(local: T, cached: Option<&T>, remote: T) -> T {
+            match cached {
+                Some(cached) if *cached == local => remote,
+                _ => local,
+            }
+        }
+        let mut tag_names = remote.tag_names;
+        for tag in &self.tag_names {
+            let is_added = cached.is_none_or(|cached| !cached.tag_names.contains(tag));
+            if is_added && !tag_names.contains(tag) {
+                tag_names.push(tag.clone());
+            }
+        }
+        if let Some(cached) = cached {
+            tag_names.retain(|tag| self.tag_names.contains(tag) || !cached.tag_names.contains(tag));
+        }
+        Self {
+            id: self.id.or(remote.id),
+            user_account_id: self.user_account_id.or(remote.user_account_id),
+            provider_internal_id: self.provider_internal_id.or(remote.provider_internal_id),
+            url: pick(self.url, cached.map(|c| &c.url), remote.url),
+            title: pick(self.title, cached.map(|c| &c.title), remote.title),
+            description: pick(
+                self.description,
+                cached.map(|c| &c.description),
+                remote.description,
+            ),
+            website_title: remote.website_title.or(self.website_title),
+            website_description: remote.website_description.or(self.website_description),
+            notes: pick(self.notes, cached.map(|c| &c.notes), remote.notes),
+            web_archive_snapshot_url: remote.web_archive_snapshot_url,
+            favicon_url: remote.favicon_url.or(self.favicon_url),
+            preview_image_url: remote.preview_image_url.or(self.preview_image_url),
+            is_archived: pick(
+                self.is_archived,
+                cached.map(|c| &c.is_archived),
+                remote.is_archived,
+            ),
+            unread: pick(self.unread, cached.map(|c| &c.unread), remote.unread),
+            shared: pick(self.shared, cached.map(|c| &c.shared), remote.shared),
+            tag_names,
+            date_added: remote.date_added.or(self.date_added),
+            date_modified: remote.date_modified,
+            is_owner: self.is_owner,
+            favicon_cached: self.favicon_cached,
+            collection: remote.collection.or(self.collection),
+            provider_remote_id: self.provider_remote_id.or(remote.provider_remote_id),
+            reading_time: remote.reading_time.or(self.reading_time),
         }
     }
 }
@@ -131,6 +227,12 @@ pub struct DetailedResponse {
     pub timestamp: i64,
     pub successful: bool,
     pub bookmarks: Option<Vec<Bookmark>>,
+    // NOTE: (vkhitrin) a full sync returns every bookmark of the account, which allows
+    //       removing local bookmarks that no longer exist remotely.
+    pub is_full_sync: bool,
+    pub start_timestamp: i64,
+    pub endpoint_statuses: Vec<EndpointStatus>,
+    pub error: Option<String>,
 }
 
 impl DetailedResponse {
@@ -145,8 +247,54 @@ impl DetailedResponse {
             timestamp: response_timestamp,
             successful: response_successful,
             bookmarks: response_bookmarks,
+            is_full_sync: true,
+            start_timestamp: response_timestamp,
+            endpoint_statuses: Vec::new(),
+            error: None,
         }
     }
+
+    // NOTE: (vkhitrin) a response may be successful while some of the optional endpoints
+    //       failed, the fetched bookmarks are then only a partial view of the account.
+    pub fn is_complete(&self) -> bool {
+        self.successful
+            && self
+                .endpoint_statuses
+                .iter()
+                .all(|endpoint_status| endpoint_status.successful)
+    }
+}
+
+// NOTE: (vkhitrin) a single page of bookmarks fetched from a remote provider, pages are
+//       reported as soon as they are fetched to allow displaying the sync progress.
+#[derive(Debug, Clone)]
+pub struct BookmarksPage {
+    pub account: Account,
+    pub bookmarks: Vec<Bookmark>,
+    pub fetched_pages: usize,
+    pub total_pages: usize,
+}
+
+impl BookmarksPage {
+    pub fn new(
+        page_account: Account,
+        page_bookmarks: Vec<Bookmark>,
+        page_fetched_pages: usize,
+        page_total_pages: usize,
+    ) -> Self {
+        Self {
+            account: page_account,
+            bookmarks: page_bookmarks,
+            fetched_pages: page_fetched_pages,
+            total_pages: page_total_pages,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BookmarksSyncEvent {
+    Page(BookmarksPage),
+    Finished(DetailedResponse),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,10 +318,97 @@ pub struct BookmarkCheckDetailsResponse {
     pub error: Option<String>,
     pub is_new: bool,
     pub successful: bool,
+    // NOTE: (vkhitrin) internal field to represent a provider that could not be reached,
+    //       the operation can be retried later.
+    pub unreachable: bool,
+    // NOTE: (vkhitrin) internal field containing the remote bookmark when it was modified
+    //       since it was cached, the edit is not submitted.
+    pub conflict: Option<Bookmark>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct BookmarkRemoveResponse {
     pub error: Option<String>,
     pub successful: bool,
+    // NOTE: (vkhitrin) internal field to represent a provider that could not be reached,
+    //       the operation can be retried later.
+    pub unreachable: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(title: &str, notes: &str, tag_names: &[&str]) -> Bookmark {
+        Bookmark::new(
+            Some(1),
+            Some(10),
+            "https://example.com".to_string(),
+            title.to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            notes.to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            false,
+            false,
+            false,
+            tag_names.iter().map(ToString::to_string).collect(),
+            None,
+            Some("2024-01-01T00:00:00Z".to_string()),
+            Some(true),
+        )
+    }
+
+    #[test]
+    fn merge_conflict_keeps_local_and_remote_changes() {
+        let cached = bookmark("Cached", "Cached", &["rust"]);
+        let local = bookmark("Local", "Cached", &["rust"]);
+        let mut remote = bookmark("Cached", "Remote", &["rust"]);
+        remote.date_modified = Some("2024-02-01T00:00:00Z".to_string());
+
+        let merged = local.merge_conflict(Some(&cached), remote);
+
+        assert_eq!(merged.title, "Local");
+        assert_eq!(merged.notes, "Remote");
+        assert_eq!(
+            merged.date_modified.as_deref(),
+            Some("2024-02-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn merge_conflict_prefers_local_field_edited_on_both_sides() {
+        let cached = bookmark("Cached", "", &[]);
+        let local = bookmark("Local", "", &[]);
+        let remote = bookmark("Remote", "", &[]);
+
+        assert_eq!(local.merge_conflict(Some(&cached), remote).title, "Local");
+    }
+
+    #[test]
+    fn merge_conflict_applies_local_tag_changes_on_remote_tags() {
+        let cached = bookmark("", "", &["rust", "cosmic"]);
+        let local = bookmark("", "", &["rust", "local"]);
+        let remote = bookmark("", "", &["rust", "cosmic", "remote"]);
+
+        assert_eq!(
+            local.merge_conflict(Some(&cached), remote).tag_names,
+            vec!["rust", "remote", "local"]
+        );
+    }
+
+    #[test]
+    fn merge_conflict_without_cached_bookmark_prefers_local() {
+        let local = bookmark("Local", "", &["local"]);
+        let remote = bookmark("Remote", "Remote", &["remote"]);
+
+        let merged = local.merge_conflict(None, remote);
+
+        assert_eq!(merged.title, "Local");
+        assert_eq!(merged.notes, "");
+        assert_eq!(merged.tag_names, vec!["remote", "local"]);
+    }
 }
@@ -0,0 +1,57 @@
+use crate::models::{account::Account, bookmarks::Bookmark, tag::TagOperation};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum BulkAction {
+    AddTags(Vec<String>),
+    Archive,
+    CopyToAccount(Account),
+    MarkRead,
+    MarkUnread,
+    MoveToAccount(Account),
+    Remove,
+    RemoveTags(Vec<String>),
+    Share,
+    Unarchive,
+    Unshare,
+    UpdateTags(TagOperation),
+}
+
+impl BulkAction {
+    // NOTE: (vkhitrin) returns the edited bookmark, bookmarks that are not affected by the
+    //       action are skipped.
+    pub fn apply(&self, bookmark: &Bookmark) -> Option<Bookmark> {
+        let mut edited_bookmark = bookmark.clone();
+        match self {
+            Self::AddTags(tags) => {
+                for tag in tags {
+                    if !edited_bookmark
+                        .tag_names
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(tag))
+                    {
+                        edited_bookmark.tag_names.push(tag.clone());
+                    }
+                }
+            }
+            Self::Archive => edited_bookmark.is_archived = true,
+            Self::MarkRead => edited_bookmark.unread = false,
+            Self::MarkUnread => edited_bookmark.unread = true,
+            Self::CopyToAccount(account) | Self::MoveToAccount(account) => {
+                return (bookmark.user_account_id != account.id).then_some(edited_bookmark);
+            }
+            Self::Remove => return Some(edited_bookmark),
+            Self::RemoveTags(tags) => {
+                edited_bookmark.tag_names =
+                    TagOperation::Delete(tags.clone()).apply(&bookmark.tag_names);
+            }
+            Self::Share => edited_bookmark.shared = true,
+            Self::Unarchive => edited_bookmark.is_archived = false,
+            Self::Unshare => edited_bookmark.shared = false,
+            Self::UpdateTags(operation) => {
+                edited_bookmark.tag_names = operation.apply(&bookmark.tag_names);
+            }
+        }
+        (edited_bookmark != *bookmark).then_some(edited_bookmark)
+    }
+}
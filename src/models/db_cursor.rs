@@ -1,6 +1,8 @@
 use crate::app::config::SortOption;
 use crate::db::SqliteDatabase;
 use crate::models::{account::Account, bookmarks::Bookmark};
+use crate::utils::search;
+use std::collections::HashMap;
 
 pub trait Pagination {
     async fn refresh_count(&mut self);
@@ -11,7 +13,9 @@ pub trait Pagination {
 #[derive(Debug, Default, Clone)]
 pub struct BookmarksPaginationCursor {
     offset: usize,
+    pub search_error: Option<String>,
     pub search_query: Option<String>,
+    pub search_snippets: HashMap<i64, String>,
     pub current_page: usize,
     pub database: Option<SqliteDatabase>,
     pub items_per_page: u8,
@@ -25,7 +29,9 @@ impl BookmarksPaginationCursor {
     pub fn new(database: SqliteDatabase) -> Self {
         Self {
             offset: 0,
+            search_error: None,
             search_query: None,
+            search_snippets: HashMap::new(),
             current_page: 1,
             database: Some(database),
             items_per_page: 0,
@@ -66,6 +72,8 @@ impl Pagination for BookmarksPaginationCursor {
         self.refresh_offset(self.current_page - 1).await;
         if let Some(database) = &mut self.database {
             if self.search_query.is_none() {
+                self.search_error = None;
+                self.search_snippets.clear();
                 self.result = Some(
                     database
                         .select_bookmarks_with_limit(
@@ -76,15 +84,26 @@ impl Pagination for BookmarksPaginationCursor {
                         .await,
                 );
             } else {
-                let (count, bookmarks) = database
-                    .search_bookmarks(
-                        self.search_query.as_ref().unwrap().clone(),
-                        self.items_per_page,
-                        self.offset,
-                        self.sort_option,
-                    )
-                    .await;
+                let (count, bookmarks, snippets) =
+                    match search::parse_search_query(self.search_query.as_ref().unwrap()) {
+                        Ok(search_query) => {
+                            self.search_error = None;
+                            database
+                                .search_bookmarks(
+                                    &search_query,
+                                    self.items_per_page,
+                                    self.offset,
+                                    self.sort_option,
+                                )
+                                .await
+                        }
+                        Err(e) => {
+                            self.search_error = Some(e);
+                            (0, Vec::new(), HashMap::new())
+                        }
+                    };
                 self.total_entries = count;
+                self.search_snippets = snippets;
                 self.refresh_count().await;
                 if bookmarks.is_empty() {
                     self.result = Some([].to_vec());
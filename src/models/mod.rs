@@ -1,7 +1,12 @@
 pub mod account;
 pub mod bookmarks;
+pub mod bulk_action;
 pub mod db_cursor;
 pub mod favicon_cache;
 pub mod operation;
+pub mod pending_operation;
 pub mod provider;
+pub mod replication;
+pub mod sync_run;
 pub mod sync_status;
+pub mod tag;
@@ -0,0 +1,73 @@
+use crate::models::bookmarks::Bookmark;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PendingOperationKind {
+    Add,
+    Edit,
+    Remove,
+}
+
+impl fmt::Display for PendingOperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PendingOperationKind::Add => write!(f, "add"),
+            PendingOperationKind::Edit => write!(f, "edit"),
+            PendingOperationKind::Remove => write!(f, "remove"),
+        }
+    }
+}
+
+impl PendingOperationKind {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "add" => Some(PendingOperationKind::Add),
+            "edit" => Some(PendingOperationKind::Edit),
+            "remove" => Some(PendingOperationKind::Remove),
+            _ => None,
+        }
+    }
+}
+
+// NOTE: (vkhitrin) a local change against a remote account that could not reach the
+//       provider, it is applied to the local database and replayed on the next sync.
+#[derive(Debug, Clone)]
+pub struct PendingOperation {
+    pub id: Option<i64>,
+    pub user_account_id: i64,
+    pub bookmark_id: Option<i64>,
+    pub kind: PendingOperationKind,
+    pub bookmark: Bookmark,
+    pub created_timestamp: i64,
+    pub error: Option<String>,
+}
+
+impl PendingOperation {
+    pub fn new(
+        operation_account_id: i64,
+        operation_kind: PendingOperationKind,
+        mut operation_bookmark: Bookmark,
+        operation_timestamp: i64,
+    ) -> Self {
+        operation_bookmark.favicon_cached = None;
+        Self {
+            id: None,
+            user_account_id: operation_account_id,
+            bookmark_id: operation_bookmark.id,
+            kind: operation_kind,
+            bookmark: operation_bookmark,
+            created_timestamp: operation_timestamp,
+            error: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingOperationResult {
+    pub operation: PendingOperation,
+    pub bookmark: Option<Bookmark>,
+    pub conflict: Option<Bookmark>,
+    pub error: Option<String>,
+    pub unreachable: bool,
+}
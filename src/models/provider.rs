@@ -1,39 +1,17 @@
-use cosmic::widget;
-use serde::{Deserialize, Serialize};
-use std::fmt;
+// NOTE: (vkhitrin) provider strings are stored as part of accounts, providers are looked up
+//       in the provider registry using them.
+pub const COSMICDING_PROVIDER: &str = "cosmicding";
+pub const LINKDING_PROVIDER: &str = "linkding";
 
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
-pub enum Provider {
-    Cosmicding,
-    Linkding,
-}
-
-impl fmt::Display for Provider {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Provider::Cosmicding => write!(f, "cosmicding"),
-            Provider::Linkding => write!(f, "linkding"),
-        }
-    }
-}
-
-impl Provider {
-    pub fn from_str(s: &str) -> Self {
-        match s {
-            "cosmicding" => Provider::Cosmicding,
-            "linkding" => Provider::Linkding,
-            _ => Provider::Linkding,
-        }
-    }
-
-    pub fn svg_icon(&self) -> widget::icon::Handle {
-        match self {
-            Provider::Linkding => {
-                widget::icon::from_svg_bytes(include_bytes!("../../res/icons/linkding-logo.svg"))
-            }
-            Provider::Cosmicding => widget::icon::from_svg_bytes(include_bytes!(
-                "../../res/icons/hicolor/scalable/apps/com.vkhitrin.cosmicding.svg"
-            )),
-        }
-    }
+// NOTE: (vkhitrin) features that are optional across providers, the UI consults them
+//       to decide which bookmark fields can be modified.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ProviderCapabilities {
+    pub archiving: bool,
+    pub description: bool,
+    pub notes: bool,
+    pub sharing: bool,
+    pub snapshots: bool,
+    pub unread: bool,
 }
@@ -0,0 +1,133 @@
+use crate::models::bookmarks::Bookmark;
+use sqlx::FromRow;
+
+// NOTE: (vkhitrin) bookmarks of the source account that match the filter (a search query)
+//       are replicated to the target account, bookmarks are identified by their URL.
+#[derive(Debug, Clone, Default, FromRow, Eq, PartialEq)]
+pub struct ReplicationRule {
+    pub id: Option<i64>,
+    pub source_account_id: i64,
+    pub target_account_id: i64,
+    pub filter: String,
+    pub last_run_timestamp: i64,
+    pub last_created_count: i64,
+    pub last_updated_count: i64,
+    pub last_skipped_count: i64,
+    pub last_conflicted_count: i64,
+}
+
+impl ReplicationRule {
+    pub fn new(source_account_id: i64, target_account_id: i64, filter: String) -> Self {
+        Self {
+            source_account_id,
+            target_account_id,
+            filter: filter.trim().to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReplicationSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    // NOTE: (vkhitrin) bookmarks that kept conflicting with remote changes, the source
+    //       bookmark was not replicated.
+    pub conflicted: usize,
+}
+
+// NOTE: (vkhitrin) returns the bookmark to create (without an ID) or update in the target
+//       account, bookmarks that are already in sync are skipped.
+pub fn replicate_bookmark(source: &Bookmark, target: Option<&Bookmark>) -> Option<Bookmark> {
+    let Some(target) = target else {
+        let mut replicated_bookmark = source.clone();
+        replicated_bookmark.id = None;
+        replicated_bookmark.provider_internal_id = None;
+        replicated_bookmark.provider_remote_id = None;
+        replicated_bookmark.user_account_id = None;
+        replicated_bookmark.is_owner = Some(true);
+        replicated_bookmark.favicon_cached = None;
+        return Some(replicated_bookmark);
+    };
+    let mut replicated_bookmark = target.clone();
+    replicated_bookmark.title.clone_from(&source.title);
+    replicated_bookmark
+        .description
+        .clone_from(&source.description);
+    replicated_bookmark.notes.clone_from(&source.notes);
+    replicated_bookmark.tag_names.clone_from(&source.tag_names);
+    replicated_bookmark.is_archived = source.is_archived;
+    replicated_bookmark.unread = source.unread;
+    replicated_bookmark.shared = source.shared;
+    (replicated_bookmark != *target).then_some(replicated_bookmark)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(account_id: i64, provider_internal_id: i64, title: &str) -> Bookmark {
+        let mut bookmark = Bookmark::new(
+            Some(account_id),
+            Some(provider_internal_id),
+            "https://example.com".to_string(),
+            title.to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            false,
+            true,
+            false,
+            vec!["rust".to_string()],
+            None,
+            Some("2024-01-01T00:00:00Z".to_string()),
+            Some(false),
+        );
+        bookmark.id = Some(provider_internal_id);
+        bookmark
+    }
+
+    #[test]
+    fn replicate_bookmark_creates_missing_bookmark() {
+        let mut source = bookmark(1, 10, "Source");
+        source.provider_remote_id = Some("remote".to_string());
+
+        let replicated_bookmark = replicate_bookmark(&source, None).unwrap();
+
+        assert_eq!(replicated_bookmark.id, None);
+        assert_eq!(replicated_bookmark.user_account_id, None);
+        assert_eq!(replicated_bookmark.provider_internal_id, None);
+        assert_eq!(replicated_bookmark.provider_remote_id, None);
+        assert_eq!(replicated_bookmark.is_owner, Some(true));
+        assert_eq!(replicated_bookmark.title, "Source");
+    }
+
+    #[test]
+    fn replicate_bookmark_updates_target_bookmark() {
+        let mut source = bookmark(1, 10, "Source");
+        source.is_archived = true;
+        let target = bookmark(2, 20, "Target");
+
+        let replicated_bookmark = replicate_bookmark(&source, Some(&target)).unwrap();
+
+        assert_eq!(replicated_bookmark.id, Some(20));
+        assert_eq!(replicated_bookmark.user_account_id, Some(2));
+        assert_eq!(replicated_bookmark.provider_internal_id, Some(20));
+        assert_eq!(replicated_bookmark.title, "Source");
+        assert!(replicated_bookmark.is_archived);
+    }
+
+    #[test]
+    fn replicate_bookmark_skips_bookmark_in_sync() {
+        let source = bookmark(1, 10, "Title");
+        let mut target = bookmark(2, 20, "Title");
+        target.date_modified = Some("2024-02-01T00:00:00Z".to_string());
+
+        assert_eq!(replicate_bookmark(&source, Some(&target)), None);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::models::bookmarks::DetailedResponse;
+use serde::{Deserialize, Serialize};
+
+// NOTE: (vkhitrin) older runs of an account are pruned when a new run is recorded.
+pub const MAX_SYNC_RUNS_PER_ACCOUNT: i64 = 50;
+
+// NOTE: (vkhitrin) the outcome of a provider endpoint requested during a sync, the status
+//       is empty when the endpoint could not be reached.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct EndpointStatus {
+    pub endpoint: String,
+    pub status: Option<u16>,
+    #[serde(default)]
+    pub successful: bool,
+}
+
+impl EndpointStatus {
+    pub fn new(endpoint: &str, status: Option<u16>, successful: bool) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            status,
+            successful,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SyncCounts {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+}
+
+// NOTE: (vkhitrin) a single sync of an account, recorded to diagnose failing syncs.
+#[derive(Debug, Clone, Default)]
+pub struct SyncRun {
+    pub id: Option<i64>,
+    pub user_account_id: i64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub successful: bool,
+    pub endpoint_statuses: Vec<EndpointStatus>,
+    pub added_count: i64,
+    pub updated_count: i64,
+    pub removed_count: i64,
+    pub error: Option<String>,
+}
+
+impl SyncRun {
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn new(
+        account_id: i64,
+        response: &DetailedResponse,
+        counts: SyncCounts,
+        end_timestamp: i64,
+    ) -> Self {
+        Self {
+            id: None,
+            user_account_id: account_id,
+            start_timestamp: response.start_timestamp,
+            end_timestamp,
+            successful: response.successful,
+            endpoint_statuses: response.endpoint_statuses.clone(),
+            added_count: counts.added as i64,
+            updated_count: counts.updated as i64,
+            removed_count: counts.removed as i64,
+            error: response.error.clone(),
+        }
+    }
+
+    pub fn duration_seconds(&self) -> i64 {
+        (self.end_timestamp - self.start_timestamp).max(0)
+    }
+}
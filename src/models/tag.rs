@@ -0,0 +1,63 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, Eq, PartialEq)]
+pub struct Tag {
+    pub bookmarks_count: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TagOperation {
+    Delete(Vec<String>),
+    Merge(Vec<String>, String),
+    Rename(String, String),
+}
+
+impl TagOperation {
+    pub fn source_tags(&self) -> Vec<String> {
+        match self {
+            Self::Delete(tags) | Self::Merge(tags, _) => tags.clone(),
+            Self::Rename(tag, _) => vec![tag.clone()],
+        }
+    }
+
+    pub fn target_tag(&self) -> Option<&str> {
+        match self {
+            Self::Delete(_) => None,
+            Self::Merge(_, tag) | Self::Rename(_, tag) => Some(tag.trim()),
+        }
+    }
+
+    // NOTE: (vkhitrin) linkding tags can't contain whitespaces.
+    pub fn is_valid(&self) -> bool {
+        !self.source_tags().is_empty()
+            && self
+                .target_tag()
+                .is_none_or(|tag| !tag.is_empty() && !tag.contains(char::is_whitespace))
+    }
+
+    // NOTE: (vkhitrin) tags are compared case-insensitively (similar to the database), the
+    //       target tag replaces the first matching tag, duplicates are dropped.
+    pub fn apply(&self, tag_names: &[String]) -> Vec<String> {
+        let source_tags = self.source_tags();
+        let target_tag = self.target_tag();
+        let mut updated_tags: Vec<String> = Vec::with_capacity(tag_names.len());
+        for tag in tag_names {
+            let candidate = if source_tags.iter().any(|s| s.eq_ignore_ascii_case(tag)) {
+                match target_tag {
+                    Some(target_tag) => target_tag,
+                    None => continue,
+                }
+            } else {
+                tag.as_str()
+            };
+            if !updated_tags
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(candidate))
+            {
+                updated_tags.push(candidate.to_string());
+            }
+        }
+        updated_tags
+    }
+}
@@ -5,8 +5,13 @@ use crate::app::{
 use crate::fl;
 use crate::{
     models::{
-        account::Account, db_cursor::AccountsPaginationCursor, operation::OperationProgress,
-        provider::Provider, sync_status::SyncStatus,
+        account::{Account, SYNC_INTERVAL_OPTIONS},
+        db_cursor::AccountsPaginationCursor,
+        operation::OperationProgress,
+        provider::LINKDING_PROVIDER,
+        replication::ReplicationRule,
+        sync_run::SyncRun,
+        sync_status::SyncStatus,
     },
     provider::ALLOWED_PROVIDERS,
     style::button::ButtonStyle,
@@ -28,6 +33,7 @@ use cosmic_time::{anim, Timeline};
 pub struct PageAccountsView {
     pub accounts: Vec<Account>,
     account_placeholder: Option<Account>,
+    pub replication_rules: Vec<ReplicationRule>,
 }
 
 impl PageAccountsView {
@@ -39,6 +45,7 @@ impl PageAccountsView {
         accounts_cursor: &AccountsPaginationCursor,
         refresh_animation: &Timeline,
         operation_progress: Option<&OperationProgress>,
+        all_accounts: &[Account],
     ) -> Element<'_, AccountsAction> {
         let spacing = theme::active().cosmic().spacing;
         let add_button = match app_state {
@@ -47,6 +54,15 @@ impl PageAccountsView {
             }
             _ => widget::button::standard(fl!("add-account")),
         };
+        let add_replication_rule_button = match app_state {
+            ApplicationState::Ready | ApplicationState::NoEnabledRemoteAccounts
+                if all_accounts.iter().filter(|acc| acc.enabled).count() > 1 =>
+            {
+                widget::button::standard(fl!("add-replication-rule"))
+                    .on_press(AccountsAction::AddReplicationRule)
+            }
+            _ => widget::button::standard(fl!("add-replication-rule")),
+        };
         let mut accounts = widget::list::list_column()
             .style(style::Container::Background)
             .list_item_padding(spacing.space_none)
@@ -147,9 +163,9 @@ impl PageAccountsView {
             });
 
             let tooltip_text = if let Some(version) = &account.provider_version {
-                format!("{}: {}", account.provider(), version)
+                format!("{}: {}", account.provider_string, version)
             } else {
-                account.provider().to_string()
+                account.provider_string.clone()
             };
 
             let provider_type_badge = widget::tooltip(
@@ -161,7 +177,7 @@ impl PageAccountsView {
 
             all_badges.push(provider_type_badge.into());
 
-            let provider_icon = widget::icon(account.provider().svg_icon());
+            let provider_icon = widget::icon(account.provider_icon());
             let account_name_widget: Element<'_, AccountsAction> = if account.is_local_provider() {
                 widget::text::body(account.display_name.clone()).into()
             } else {
@@ -233,6 +249,9 @@ impl PageAccountsView {
                         .font_size(12)
                         .on_press(AccountsAction::EditAccount(account.to_owned())),
                 };
+                let details_button = widget::button::link(fl!("details"))
+                    .font_size(12)
+                    .on_press(AccountsAction::ViewAccountDetails(account.to_owned()));
                 let remove_button = match app_state {
                     ApplicationState::Refreshing => widget::button::link(fl!("remove"))
                         .font_size(12)
@@ -241,11 +260,12 @@ impl PageAccountsView {
                         .font_size(12)
                         .on_press(AccountsAction::DeleteAccount(account.to_owned())),
                 };
-                let actions_row = widget::row::with_capacity(4)
+                let actions_row = widget::row::with_capacity(5)
                     .spacing(spacing.space_xs)
                     .push(refresh_button)
                     .push(toggle_status_button)
                     .push(edit_button)
+                    .push(details_button)
                     .push(remove_button);
                 columns.push(
                     actions_row
@@ -418,6 +438,7 @@ impl PageAccountsView {
             ])
             .push(animation_widget)
             .push(widget::horizontal_space())
+            .push(add_replication_rule_button)
             .push(add_button)
             .width(Length::Fill)
             .apply(widget::container)
@@ -444,6 +465,105 @@ impl PageAccountsView {
             main_column = main_column.push(progress_widget);
         }
 
+        if !self.replication_rules.is_empty() {
+            let mut replication_rules = widget::list::list_column()
+                .style(style::Container::Background)
+                .list_item_padding(spacing.space_none)
+                .divider_padding(spacing.space_none)
+                .spacing(spacing.space_xxxs)
+                .padding(spacing.space_none);
+            let account_name = |account_id: i64| {
+                all_accounts
+                    .iter()
+                    .find(|acc| acc.id == Some(account_id))
+                    .map_or_else(String::new, |acc| acc.display_name.clone())
+            };
+            for rule in &self.replication_rules {
+                let run_button = match app_state {
+                    ApplicationState::Refreshing => widget::button::link(fl!("run"))
+                        .font_size(12)
+                        .class(ButtonStyle::DisabledLink(false).into()),
+                    _ => widget::button::link(fl!("run"))
+                        .font_size(12)
+                        .on_press(AccountsAction::StartReplication(rule.to_owned())),
+                };
+                let remove_button = match app_state {
+                    ApplicationState::Refreshing => widget::button::link(fl!("remove"))
+                        .font_size(12)
+                        .class(ButtonStyle::DisabledLink(false).into()),
+                    _ => widget::button::link(fl!("remove"))
+                        .font_size(12)
+                        .on_press(AccountsAction::RemoveReplicationRule(rule.to_owned())),
+                };
+                let last_run_text = if rule.last_run_timestamp > 0 {
+                    let local_time: DateTime<Local> = DateTime::from(
+                        DateTime::from_timestamp(rule.last_run_timestamp, 0).expect(""),
+                    );
+                    fl!(
+                        "replication-last-run",
+                        time = local_time.format("%a, %d %b %Y %H:%M:%S").to_string(),
+                        created = rule.last_created_count,
+                        updated = rule.last_updated_count,
+                        skipped = rule.last_skipped_count,
+                        conflicted = rule.last_conflicted_count
+                    )
+                } else {
+                    fl!("replication-never-run")
+                };
+                replication_rules = replication_rules.add(
+                    widget::column::with_capacity(3)
+                        .push(
+                            widget::row::with_capacity(3)
+                                .spacing(spacing.space_xxs)
+                                .align_y(Alignment::Center)
+                                .push(widget::text::body(format!(
+                                    "{} → {}",
+                                    account_name(rule.source_account_id),
+                                    account_name(rule.target_account_id)
+                                )))
+                                .push(widget::horizontal_space())
+                                .push(
+                                    widget::text::caption(if rule.filter.is_empty() {
+                                        fl!("all-bookmarks")
+                                    } else {
+                                        rule.filter.clone()
+                                    })
+                                    .size(12),
+                                ),
+                        )
+                        .push(
+                            widget::row::with_capacity(2)
+                                .spacing(spacing.space_xs)
+                                .push(run_button)
+                                .push(remove_button),
+                        )
+                        .push(widget::text::body(last_run_text).size(12))
+                        .spacing(spacing.space_xxxs)
+                        .padding([
+                            spacing.space_xxs,
+                            spacing.space_xxs,
+                            spacing.space_xxs,
+                            spacing.space_xxxs,
+                        ])
+                        .apply(widget::container)
+                        .class(theme::Container::Background),
+                );
+            }
+            main_column = main_column
+                .push(
+                    widget::text::title4(fl!("replication-rules"))
+                        .apply(widget::container)
+                        .padding([
+                            spacing.space_none,
+                            spacing.space_none,
+                            spacing.space_xxs,
+                            spacing.space_none,
+                        ]),
+                )
+                .push(replication_rules)
+                .push(widget::Space::new(0, spacing.space_xs));
+        }
+
         main_column = main_column
             .push(accounts_widget)
             .push(page_navigation_widget);
@@ -458,6 +578,11 @@ impl PageAccountsView {
                     cosmic::Action::App(ApplicationAction::AddAccountForm)
                 }));
             }
+            AccountsAction::AddReplicationRule => {
+                commands.push(Task::perform(async {}, |()| {
+                    cosmic::Action::App(ApplicationAction::OpenAddReplicationRuleDialog)
+                }));
+            }
             AccountsAction::CancelImport(import_id) => {
                 commands.push(Task::perform(async {}, move |()| {
                     cosmic::Action::App(ApplicationAction::CancelImportBookmarks(import_id))
@@ -481,6 +606,16 @@ impl PageAccountsView {
                     ))
                 }));
             }
+            AccountsAction::RemoveReplicationRule(rule) => {
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::RemoveReplicationRule(rule.clone()))
+                }));
+            }
+            AccountsAction::StartReplication(rule) => {
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::StartReplication(rule.clone()))
+                }));
+            }
             AccountsAction::OpenExternalURL(url) => {
                 commands.push(Task::perform(async {}, move |()| {
                     cosmic::Action::App(ApplicationAction::OpenExternalUrl(url.clone()))
@@ -500,6 +635,11 @@ impl PageAccountsView {
                     ))
                 }));
             }
+            AccountsAction::ViewAccountDetails(account) => {
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::ViewAccountDetails(account.clone()))
+                }));
+            }
             AccountsAction::ToggleAccountStatus(account) => {
                 let mut updated_account = account.clone();
                 updated_account.enabled = !updated_account.enabled;
@@ -521,19 +661,33 @@ pub fn add_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
     let display_name_widget_text_input = widget::text_input("Name", account.display_name.clone())
         .on_input(ApplicationAction::SetAccountDisplayName);
     let provider_widget_title = widget::text::body("Provider");
-    let provider_selected = match account.provider() {
-        Provider::Linkding => Some(0),
-        Provider::Cosmicding => None,
-    };
-    let provider_dropdown = widget::dropdown(ALLOWED_PROVIDERS, provider_selected, |idx| {
-        let provider_name = ALLOWED_PROVIDERS.get(idx).unwrap_or(&"Linkding");
-        ApplicationAction::SetAccountProvider(Provider::from_str(provider_name))
-    });
-    let provider_icon = widget::icon(account.provider().svg_icon()).size(16);
+    let provider_selected = ALLOWED_PROVIDERS
+        .iter()
+        .position(|provider| provider.provider_string() == account.provider_string);
+    let provider_dropdown =
+        widget::dropdown(ALLOWED_PROVIDERS.as_slice(), provider_selected, |idx| {
+            let provider_string = ALLOWED_PROVIDERS
+                .get(idx)
+                .map_or(LINKDING_PROVIDER, |provider| provider.provider_string());
+            ApplicationAction::SetAccountProvider(provider_string.to_string())
+        });
+    let provider_icon = widget::icon(account.provider_icon()).size(16);
     let instance_widget_title = widget::text::body(fl!("instance"));
     let instance_widget_text_input = widget::text_input("Instance", account.instance.clone())
         .on_input(ApplicationAction::SetAccountInstance);
-    let api_key_widget_title = widget::text::body(fl!("api-key"));
+    let requires_username = account
+        .provider()
+        .is_some_and(|provider| provider.requires_username());
+    let requires_client_credentials = account
+        .provider()
+        .is_some_and(|provider| provider.requires_client_credentials());
+    let api_key_widget_title = widget::text::body(if requires_client_credentials {
+        fl!("password")
+    } else if requires_username {
+        fl!("app-password")
+    } else {
+        fl!("api-key")
+    });
     let api_key_widget_text_input = widget::text_input(fl!("token"), account.api_token.clone())
         .on_input(ApplicationAction::SetAccountAPIKey)
         .password();
@@ -601,6 +755,8 @@ pub fn add_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
                 .align_y(Alignment::Center),
         )
         .push(instance_widget_text_input)
+        .push_maybe(requires_username.then(|| username_widget(&account)))
+        .push_maybe(requires_client_credentials.then(|| client_credentials_widget(&account)))
         .push(
             widget::row::with_capacity(2)
                 .spacing(spacing.space_xxs)
@@ -615,6 +771,9 @@ pub fn add_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
                 .align_y(Alignment::Start),
         )
         .push(api_key_widget_text_input)
+        .push_maybe((!account.is_local_provider()).then(|| sync_interval_widget(&account)))
+        .push_maybe((!account.is_local_provider()).then(|| tls_widget(&account)))
+        .push_maybe((!account.is_local_provider()).then(|| network_widget(&account)))
         .push(
             widget::row::with_capacity(1)
                 .push(trust_invalid_certs_widget_toggler)
@@ -647,13 +806,25 @@ pub fn edit_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
     let display_name_widget_text_input = widget::text_input("Name", account.display_name.clone())
         .on_input(ApplicationAction::SetAccountDisplayName);
     let provider_widget_title = widget::text::body("Provider");
-    let provider_text = account.provider().to_string();
+    let provider_text = account.provider_string.clone();
     let provider_widget_text_input = widget::text_input("", provider_text);
-    let provider_icon = widget::icon(account.provider().svg_icon()).size(16);
+    let provider_icon = widget::icon(account.provider_icon()).size(16);
     let instance_widget_title = widget::text::body(fl!("instance"));
     let instance_widget_text_input = widget::text_input("Instance", account.instance.clone())
         .on_input(ApplicationAction::SetAccountInstance);
-    let api_key_widget_title = widget::text::body(fl!("api-key"));
+    let requires_username = account
+        .provider()
+        .is_some_and(|provider| provider.requires_username());
+    let requires_client_credentials = account
+        .provider()
+        .is_some_and(|provider| provider.requires_client_credentials());
+    let api_key_widget_title = widget::text::body(if requires_client_credentials {
+        fl!("password")
+    } else if requires_username {
+        fl!("app-password")
+    } else {
+        fl!("api-key")
+    });
     let api_key_widget_text_input = widget::text_input(fl!("token"), account.api_token.clone())
         .on_input(ApplicationAction::SetAccountAPIKey)
         .password();
@@ -759,6 +930,8 @@ pub fn edit_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
                 .align_y(Alignment::Center),
         )
         .push(instance_widget_text_input)
+        .push_maybe(requires_username.then(|| username_widget(&account)))
+        .push_maybe(requires_client_credentials.then(|| client_credentials_widget(&account)))
         .push(
             widget::row::with_capacity(2)
                 .spacing(spacing.space_xxs)
@@ -773,6 +946,9 @@ pub fn edit_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
                 .align_y(Alignment::Start),
         )
         .push(api_key_widget_text_input)
+        .push_maybe((!account.is_local_provider()).then(|| sync_interval_widget(&account)))
+        .push_maybe((!account.is_local_provider()).then(|| tls_widget(&account)))
+        .push_maybe((!account.is_local_provider()).then(|| network_widget(&account)))
         .push(
             widget::row::with_capacity(1)
                 .push(trust_invalid_certs_widget_toggler)
@@ -789,3 +965,353 @@ pub fn edit_account<'a>(account: Account) -> Element<'a, ApplicationAction> {
         .push(buttons_widget_container)
         .into()
 }
+
+fn username_widget<'a>(account: &Account) -> Element<'a, ApplicationAction> {
+    let spacing = theme::active().cosmic().spacing;
+    widget::column::with_capacity(2)
+        .spacing(spacing.space_xxs)
+        .push(
+            widget::row::with_capacity(2)
+                .spacing(spacing.space_xxs)
+                .push(widget::icon::from_name("avatar-default-symbolic"))
+                .push(widget::text::body(fl!("username")))
+                .padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_none,
+                ])
+                .align_y(Alignment::Center),
+        )
+        .push(
+            widget::text_input(
+                fl!("username"),
+                account.username.clone().unwrap_or_default(),
+            )
+            .on_input(ApplicationAction::SetAccountUsername),
+        )
+        .into()
+}
+
+fn client_credentials_widget<'a>(account: &Account) -> Element<'a, ApplicationAction> {
+    let spacing = theme::active().cosmic().spacing;
+    widget::column::with_capacity(4)
+        .spacing(spacing.space_xxs)
+        .push(
+            widget::row::with_capacity(2)
+                .spacing(spacing.space_xxs)
+                .push(widget::icon::from_name("application-x-addon-symbolic"))
+                .push(widget::text::body(fl!("client-id")))
+                .padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_none,
+                ])
+                .align_y(Alignment::Center),
+        )
+        .push(
+            widget::text_input(
+                fl!("client-id"),
+                account.client_id.clone().unwrap_or_default(),
+            )
+            .on_input(ApplicationAction::SetAccountClientId),
+        )
+        .push(
+            widget::row::with_capacity(2)
+                .spacing(spacing.space_xxs)
+                .push(widget::icon::from_name("system-lock-screen-symbolic"))
+                .push(widget::text::body(fl!("client-secret")))
+                .padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_none,
+                ])
+                .align_y(Alignment::Center),
+        )
+        .push(
+            widget::text_input(fl!("client-secret"), account.client_secret.clone())
+                .on_input(ApplicationAction::SetAccountClientSecret)
+                .password(),
+        )
+        .into()
+}
+
+fn sync_interval_widget<'a>(account: &Account) -> Element<'a, ApplicationAction> {
+    let spacing = theme::active().cosmic().spacing;
+    let sync_interval_labels: Vec<String> = SYNC_INTERVAL_OPTIONS
+        .iter()
+        .copied()
+        .map(sync_interval_label)
+        .collect();
+    let sync_interval_selected = SYNC_INTERVAL_OPTIONS
+        .iter()
+        .position(|minutes| *minutes == account.sync_interval_minutes);
+    widget::column::with_capacity(2)
+        .spacing(spacing.space_xxs)
+        .push(
+            widget::row::with_capacity(2)
+                .spacing(spacing.space_xxs)
+                .push(widget::icon::from_name("emblem-synchronizing-symbolic"))
+                .push(widget::text::body(fl!("sync-interval")))
+                .padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_none,
+                ])
+                .align_y(Alignment::Center),
+        )
+        .push(widget::dropdown(
+            sync_interval_labels,
+            sync_interval_selected,
+            |idx| ApplicationAction::SetAccountSyncInterval(SYNC_INTERVAL_OPTIONS[idx]),
+        ))
+        .into()
+}
+
+fn tls_widget<'a>(account: &Account) -> Element<'a, ApplicationAction> {
+    let spacing = theme::active().cosmic().spacing;
+    let ca_certificate_widget_text_input = widget::text_input(
+        fl!("ca-certificate-path"),
+        account.ca_certificate_path.clone().unwrap_or_default(),
+    )
+    .on_input(ApplicationAction::SetAccountCACertificatePath);
+    let client_certificate_widget_text_input = widget::text_input(
+        fl!("client-certificate-path"),
+        account.client_certificate_path.clone().unwrap_or_default(),
+    )
+    .on_input(ApplicationAction::SetAccountClientCertificatePath);
+    let client_key_widget_text_input = widget::text_input(
+        fl!("client-key-path"),
+        account.client_key_path.clone().unwrap_or_default(),
+    )
+    .on_input(ApplicationAction::SetAccountClientKeyPath);
+    widget::column::with_capacity(4)
+        .spacing(spacing.space_xxs)
+        .push(
+            widget::row::with_capacity(2)
+                .spacing(spacing.space_xxs)
+                .push(widget::icon::from_name("security-high-symbolic"))
+                .push(widget::text::body(fl!("tls-certificates")))
+                .padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_none,
+                ])
+                .align_y(Alignment::Center),
+        )
+        .push(ca_certificate_widget_text_input)
+        .push(client_certificate_widget_text_input)
+        .push(client_key_widget_text_input)
+        .into()
+}
+
+fn network_widget<'a>(account: &Account) -> Element<'a, ApplicationAction> {
+    let spacing = theme::active().cosmic().spacing;
+    let proxy_url_widget_text_input = widget::text_input(
+        fl!("proxy-url"),
+        account.proxy_url.clone().unwrap_or_default(),
+    )
+    .on_input(ApplicationAction::SetAccountProxyURL);
+    let mut headers_column =
+        widget::column::with_capacity(account.extra_headers.0.len()).spacing(spacing.space_xxs);
+    for (index, header) in account.extra_headers.0.iter().enumerate() {
+        headers_column = headers_column.push(
+            widget::row::with_capacity(3)
+                .spacing(spacing.space_xxs)
+                .align_y(Alignment::Center)
+                .push(
+                    widget::text_input(fl!("header-name"), header.name.clone())
+                        .on_input(move |name| ApplicationAction::SetAccountHeaderName(index, name)),
+                )
+                .push(
+                    widget::text_input(fl!("header-value"), header.value.clone())
+                        .on_input(move |value| {
+                            ApplicationAction::SetAccountHeaderValue(index, value)
+                        })
+                        .password(),
+                )
+                .push(
+                    widget::button::icon(widget::icon::from_name("user-trash-symbolic"))
+                        .on_press(ApplicationAction::RemoveAccountHeader(index)),
+                ),
+        );
+    }
+    widget::column::with_capacity(5)
+        .spacing(spacing.space_xxs)
+        .push(
+            widget::row::with_capacity(2)
+                .spacing(spacing.space_xxs)
+                .push(widget::icon::from_name("network-wired-symbolic"))
+                .push(widget::text::body(fl!("network")))
+                .padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_none,
+                ])
+                .align_y(Alignment::Center),
+        )
+        .push(proxy_url_widget_text_input)
+        .push(widget::text::body(fl!("request-headers")))
+        .push(headers_column)
+        .push(
+            widget::button::standard(fl!("add-header"))
+                .on_press(ApplicationAction::AddAccountHeader),
+        )
+        .into()
+}
+
+fn sync_interval_label(minutes: i64) -> String {
+    match minutes {
+        0 => fl!("disabled"),
+        minutes if minutes % 60 == 0 => fl!("sync-interval-hours", count = minutes / 60),
+        minutes => fl!("sync-interval-minutes", count = minutes),
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    let local_time: DateTime<Local> =
+        DateTime::from(DateTime::from_timestamp(timestamp, 0).expect(""));
+    local_time.format("%a, %d %b %Y %H:%M:%S").to_string()
+}
+
+pub fn account_details<'a>(
+    account: &'a Account,
+    sync_runs: &'a [SyncRun],
+) -> Element<'a, ApplicationAction> {
+    let spacing = theme::active().cosmic().spacing;
+    let detail_row = |label: String, value: String| {
+        widget::row::with_capacity(3)
+            .spacing(spacing.space_xxs)
+            .align_y(Alignment::Center)
+            .push(widget::text::body(label))
+            .push(widget::horizontal_space())
+            .push(widget::text::body(value))
+    };
+    let details_widget = widget::column::with_capacity(5)
+        .spacing(spacing.space_xxs)
+        .push(detail_row(
+            fl!("provider"),
+            account.provider_version.as_ref().map_or_else(
+                || account.provider_string.clone(),
+                |version| format!("{}: {version}", account.provider_string),
+            ),
+        ))
+        .push(detail_row(fl!("instance"), account.instance.clone()))
+        .push(detail_row(
+            fl!("sync-interval"),
+            sync_interval_label(account.sync_interval_minutes),
+        ))
+        .push(detail_row(
+            fl!("last-sync-time"),
+            format!(
+                "{} ({})",
+                format_timestamp(account.last_sync_timestamp),
+                if account.last_sync_status {
+                    fl!("successful")
+                } else {
+                    fl!("failed")
+                }
+            ),
+        ))
+        .push(detail_row(
+            fl!("last-full-sync-time"),
+            if account.last_full_sync_timestamp > 0 {
+                format_timestamp(account.last_full_sync_timestamp)
+            } else {
+                fl!("never")
+            },
+        ));
+
+    let mut sync_runs_widget = widget::list::list_column()
+        .style(style::Container::Background)
+        .list_item_padding(spacing.space_none)
+        .divider_padding(spacing.space_none)
+        .spacing(spacing.space_xxxs)
+        .padding(spacing.space_none);
+    for sync_run in sync_runs {
+        let status_icon = if sync_run.successful {
+            widget::icon::from_name("checkbox-checked-symbolic").size(16)
+        } else {
+            widget::icon::from_name("dialog-error-symbolic").size(16)
+        };
+        let endpoint_statuses = sync_run
+            .endpoint_statuses
+            .iter()
+            .map(|endpoint_status| {
+                format!(
+                    "{} {}",
+                    endpoint_status.endpoint,
+                    endpoint_status
+                        .status
+                        .map_or_else(|| fl!("unreachable"), |status| status.to_string())
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        sync_runs_widget = sync_runs_widget.add(
+            widget::column::with_capacity(4)
+                .push(
+                    widget::row::with_capacity(3)
+                        .spacing(spacing.space_xxs)
+                        .align_y(Alignment::Center)
+                        .push(status_icon)
+                        .push(widget::text::body(format_timestamp(
+                            sync_run.start_timestamp,
+                        )))
+                        .push(widget::horizontal_space())
+                        .push(
+                            widget::text::caption(fl!(
+                                "sync-run-duration",
+                                seconds = sync_run.duration_seconds()
+                            ))
+                            .size(12),
+                        ),
+                )
+                .push(
+                    widget::text::body(fl!(
+                        "sync-run-counts",
+                        added = sync_run.added_count,
+                        updated = sync_run.updated_count,
+                        removed = sync_run.removed_count
+                    ))
+                    .size(12),
+                )
+                .push_maybe(
+                    (!endpoint_statuses.is_empty())
+                        .then(|| widget::text::caption(endpoint_statuses).size(12)),
+                )
+                .push_maybe(sync_run.error.as_ref().map(|error| {
+                    widget::text::caption(error.clone())
+                        .size(12)
+                        .class(theme::Text::Color(
+                            theme::active().cosmic().destructive.base.into(),
+                        ))
+                }))
+                .spacing(spacing.space_xxxs)
+                .padding([
+                    spacing.space_xxs,
+                    spacing.space_xxs,
+                    spacing.space_xxs,
+                    spacing.space_xxxs,
+                ])
+                .apply(widget::container)
+                .class(theme::Container::Background),
+        );
+    }
+
+    widget::column::with_capacity(3)
+        .spacing(spacing.space_s)
+        .push(details_widget)
+        .push(widget::text::title4(fl!("sync-history")))
+        .push(if sync_runs.is_empty() {
+            Element::from(widget::text::body(fl!("no-sync-runs")))
+        } else {
+            sync_runs_widget.into()
+        })
+        .into()
+}
@@ -5,29 +5,40 @@ use crate::{
     },
     fl,
     models::{
-        account::Account, bookmarks::Bookmark, db_cursor::BookmarksPaginationCursor,
-        operation::OperationProgress, sync_status::SyncStatus,
+        account::Account, bookmarks::Bookmark, bulk_action::BulkAction,
+        db_cursor::BookmarksPaginationCursor, operation::OperationProgress,
+        sync_status::SyncStatus,
     },
+    provider,
     style::{button::ButtonStyle, text_editor::text_editor_class},
+    utils::search,
     widgets::progress_info::{operation_progress_widget, ProgressInfo},
 };
 use chrono::{DateTime, Local};
 use cosmic::{
     app::Task,
     cosmic_theme,
-    iced::{Alignment, Length},
+    iced::{keyboard::Modifiers, Alignment, Length},
     iced_core::text,
+    iced_widget::tooltip,
     style, theme,
     widget::{self},
     Apply, Element,
 };
 use cosmic_time::{anim, Timeline};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Default, Clone)]
 pub struct PageBookmarksView {
     bookmark_placeholder: Option<Bookmark>,
     pub bookmarks: Vec<Bookmark>,
+    pub failed_bookmark_errors: HashMap<i64, String>,
+    last_selected_index: Option<usize>,
+    pub modifiers: Modifiers,
+    pub pending_bookmark_ids: HashSet<i64>,
     pub search_id: Option<widget::Id>,
+    pub selected_bookmark_ids: HashSet<i64>,
+    pub selection_mode: bool,
     query_placeholder: String,
 }
 
@@ -50,11 +61,12 @@ impl PageBookmarksView {
             .spacing(spacing.space_xxxs)
             .padding(spacing.space_none);
 
-        for bookmark in &self.bookmarks {
+        for (index, bookmark) in self.bookmarks.iter().enumerate() {
             let bookmark_account_id = bookmark.user_account_id.unwrap();
             let date_added: DateTime<Local> =
                 bookmark.date_added.clone().unwrap().parse().expect("");
             let mut columns = Vec::new();
+            let reading_time = bookmark.reading_time.filter(|minutes| *minutes > 0);
             let favicon_data = bookmark
                 .favicon_cached
                 .as_ref()
@@ -67,15 +79,25 @@ impl PageBookmarksView {
                 widget::image::Handle::from_bytes(placeholder)
             };
             // Mandatory first row - title
+            let mut title_row = widget::row::with_capacity(3)
+                .spacing(spacing.space_xxs)
+                .padding([
+                    spacing.space_xxs,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                    spacing.space_xxxs,
+                ]);
+            if self.selection_mode && bookmark.is_owner == Some(true) {
+                let is_selected = bookmark
+                    .id
+                    .is_some_and(|id| self.selected_bookmark_ids.contains(&id));
+                title_row =
+                    title_row.push(widget::checkbox("", is_selected).on_toggle(move |checked| {
+                        BookmarksAction::ToggleBookmarkSelection(index, checked)
+                    }));
+            }
             columns.push(
-                widget::row::with_capacity(2)
-                    .spacing(spacing.space_xxs)
-                    .padding([
-                        spacing.space_xxs,
-                        spacing.space_xxs,
-                        spacing.space_none,
-                        spacing.space_xxxs,
-                    ])
+                title_row
                     .push(widget::image(favicon).width(16))
                     .push(
                         widget::button::link(bookmark.title.clone())
@@ -96,7 +118,10 @@ impl PageBookmarksView {
                         .padding([
                             spacing.space_xxxs,
                             spacing.space_xxs,
-                            if bookmark.tag_names.is_empty() {
+                            if bookmark.tag_names.is_empty()
+                                && bookmark.collection.is_none()
+                                && reading_time.is_none()
+                            {
                                 spacing.space_xxxs
                             } else {
                                 spacing.space_none
@@ -108,35 +133,85 @@ impl PageBookmarksView {
                         .into(),
                 );
             }
-            // Optional third row - tags
-            if !bookmark.tag_names.is_empty() {
-                columns.push(
-                    widget::row::with_capacity(2)
-                        .spacing(spacing.space_xs)
-                        .padding([
-                            if bookmark.description.is_empty() {
-                                spacing.space_xxxs
-                            } else {
-                                spacing.space_xxs
-                            },
-                            spacing.space_xxs,
-                            spacing.space_xxxs,
-                            spacing.space_xxxs,
-                        ])
-                        .push(
-                            widget::text::body(
-                                bookmark
-                                    .tag_names
-                                    .iter()
-                                    .map(|s| format!("#{s}"))
-                                    .collect::<Vec<String>>()
-                                    .join(" "),
+            // Optional search match row - snippet of the matched terms
+            if let Some(snippet) = bookmark
+                .id
+                .and_then(|id| bookmarks_cursor.search_snippets.get(&id))
+                .filter(|snippet| snippet.contains(search::SNIPPET_MATCH_START))
+            {
+                let mut snippet_row = widget::row::with_capacity(1).padding([
+                    spacing.space_xxxs,
+                    spacing.space_xxs,
+                    spacing.space_xxxs,
+                    spacing.space_xxxs,
+                ]);
+                for (segment, is_match) in search::parse_snippet(snippet) {
+                    snippet_row = snippet_row.push(if is_match {
+                        widget::text(segment).size(12).font(cosmic::font::bold())
+                    } else {
+                        widget::text(segment).size(12)
+                    });
+                }
+                columns.push(snippet_row.align_y(Alignment::Center).into());
+            }
+            // Optional third row - collection, reading time and tags
+            if !bookmark.tag_names.is_empty()
+                || bookmark.collection.is_some()
+                || reading_time.is_some()
+            {
+                let mut tags_row = widget::row::with_capacity(3)
+                    .spacing(spacing.space_xs)
+                    .padding([
+                        if bookmark.description.is_empty() {
+                            spacing.space_xxxs
+                        } else {
+                            spacing.space_xxs
+                        },
+                        spacing.space_xxs,
+                        spacing.space_xxxs,
+                        spacing.space_xxxs,
+                    ]);
+                if let Some(collection) = &bookmark.collection {
+                    tags_row = tags_row.push(
+                        widget::row::with_capacity(2)
+                            .spacing(spacing.space_xxxs)
+                            .push(widget::icon::from_name("folder-symbolic").size(12))
+                            .push(
+                                widget::button::link(collection.clone())
+                                    .font_size(12)
+                                    .tooltip(fl!("filter-by-collection"))
+                                    .on_press(BookmarksAction::FilterByCollection(
+                                        collection.clone(),
+                                    )),
                             )
-                            .size(12),
+                            .align_y(Alignment::Center),
+                    );
+                }
+                if let Some(minutes) = reading_time {
+                    tags_row = tags_row.push(
+                        widget::row::with_capacity(2)
+                            .spacing(spacing.space_xxxs)
+                            .push(widget::icon::from_name("alarm-symbolic").size(12))
+                            .push(
+                                widget::text::body(fl!("reading-time", minutes = minutes)).size(12),
+                            )
+                            .align_y(Alignment::Center),
+                    );
+                }
+                if !bookmark.tag_names.is_empty() {
+                    tags_row = tags_row.push(
+                        widget::text::body(
+                            bookmark
+                                .tag_names
+                                .iter()
+                                .map(|s| format!("#{s}"))
+                                .collect::<Vec<String>>()
+                                .join(" "),
                         )
-                        .align_y(Alignment::Center)
-                        .into(),
-                );
+                        .size(12),
+                    );
+                }
+                columns.push(tags_row.align_y(Alignment::Center).into());
             }
             // Mandatory fourth row - actions
             let edit_bookmark_button = match app_state {
@@ -155,6 +230,18 @@ impl PageBookmarksView {
                     BookmarksAction::DeleteBookmark(bookmark_account_id, bookmark.to_owned()),
                 ),
             };
+            let transfer_bookmark_button = match app_state {
+                ApplicationState::Refreshing => widget::button::link(fl!("move-copy"))
+                    .font_size(12)
+                    .class(ButtonStyle::DisabledLink(false).into()),
+                _ => widget::button::link(fl!("move-copy"))
+                    .font_size(12)
+                    .on_press_maybe(
+                        bookmark
+                            .id
+                            .map(|id| BookmarksAction::OpenTransferDialog(vec![id])),
+                    ),
+            };
             let notes_button = match app_state {
                 ApplicationState::Refreshing => widget::button::link(fl!("notes"))
                     .font_size(12)
@@ -185,11 +272,17 @@ impl PageBookmarksView {
             if bookmark.is_owner == Some(true) {
                 actions_row = actions_row.push(edit_bookmark_button);
                 actions_row = actions_row.push(remove_bookmark_button);
+                actions_row = actions_row.push(transfer_bookmark_button);
             }
-            if !bookmark.notes.is_empty() {
+            let capabilities = accounts
+                .iter()
+                .find(|account| account.id == Some(bookmark_account_id))
+                .map(provider::capabilities)
+                .unwrap_or_default();
+            if capabilities.notes && !bookmark.notes.is_empty() {
                 actions_row = actions_row.push(notes_button);
             }
-            if !bookmark.web_archive_snapshot_url.is_empty() {
+            if capabilities.snapshots && !bookmark.web_archive_snapshot_url.is_empty() {
                 actions_row = actions_row.push(snapshot_button);
             }
             columns.push(
@@ -226,12 +319,33 @@ impl PageBookmarksView {
                         widget::text(fl!("shared")).size(12)
                     });
             }
+            if let Some(error) = bookmark
+                .id
+                .and_then(|id| self.failed_bookmark_errors.get(&id))
+            {
+                details_row = details_row.push(widget::tooltip(
+                    widget::row::with_capacity(2)
+                        .spacing(spacing.space_xxxs)
+                        .align_y(Alignment::Center)
+                        .push(widget::icon::from_name("dialog-error-symbolic").size(12))
+                        .push(widget::text(fl!("failed-to-synchronize")).size(12)),
+                    widget::container(widget::text::body(error.clone())),
+                    tooltip::Position::Top,
+                ));
+            } else if bookmark
+                .id
+                .is_some_and(|id| self.pending_bookmark_ids.contains(&id))
+            {
+                details_row = details_row
+                    .push(widget::icon::from_name("emblem-synchronizing-symbolic").size(12))
+                    .push(widget::text(fl!("pending")).size(12));
+            }
 
             details_row = details_row.push(widget::horizontal_space());
 
             // Add provider logo and account name on the right side
             if let Some(account) = accounts.iter().find(|a| a.id == Some(bookmark_account_id)) {
-                let provider_icon = widget::icon(account.provider().svg_icon()).size(12);
+                let provider_icon = widget::icon(account.provider_icon()).size(12);
 
                 details_row = details_row
                     .push(provider_icon)
@@ -268,6 +382,15 @@ impl PageBookmarksView {
             widget::button::standard(fl!("refresh")).on_press(BookmarksAction::RefreshBookmarks)
         };
         let mut new_bookmark_button = widget::button::standard(fl!("add-bookmark"));
+        let selection_mode_button = widget::button::standard(if self.selection_mode {
+            fl!("cancel")
+        } else {
+            fl!("select")
+        })
+        .on_press_maybe(
+            (!self.bookmarks.is_empty() || self.selection_mode)
+                .then_some(BookmarksAction::ToggleSelectionMode),
+        );
 
         let animation_widget = match app_state {
             ApplicationState::Refreshing => anim![REFRESH_ICON, &refresh_animation, 16],
@@ -396,11 +519,131 @@ impl PageBookmarksView {
             .push(animation_widget)
             .push(search_input_widget)
             .push(refresh_button)
+            .push(selection_mode_button)
             .push(new_bookmark_button)
             .width(Length::Fill)
             .apply(widget::container)
             .into()]);
 
+        if let Some(search_error) = &bookmarks_cursor.search_error {
+            main_column =
+                main_column.push(
+                    widget::row::with_capacity(2)
+                        .push(widget::horizontal_space())
+                        .push(widget::text::caption(search_error.clone()).class(
+                            theme::Text::Color(theme::active().cosmic().destructive.base.into()),
+                        ))
+                        .padding([
+                            spacing.space_none,
+                            spacing.space_none,
+                            spacing.space_xxs,
+                            spacing.space_none,
+                        ]),
+                );
+        }
+
+        if self.selection_mode {
+            let is_bulk_enabled = !self.selected_bookmark_ids.is_empty()
+                && matches!(
+                    app_state,
+                    ApplicationState::Ready | ApplicationState::NoEnabledRemoteAccounts
+                );
+            let selected_bookmark_ids: Vec<i64> =
+                self.selected_bookmark_ids.iter().copied().collect();
+            let bulk_button = |label: String, message: BookmarksAction| {
+                let button = widget::button::link(label).font_size(12);
+                if is_bulk_enabled {
+                    button.on_press(message)
+                } else {
+                    button.class(ButtonStyle::DisabledLink(false).into())
+                }
+            };
+            main_column = main_column.push(
+                widget::column::with_capacity(2)
+                    .spacing(spacing.space_xxxs)
+                    .padding([
+                        spacing.space_none,
+                        spacing.space_none,
+                        spacing.space_xxs,
+                        spacing.space_none,
+                    ])
+                    .push(
+                        widget::row::with_capacity(5)
+                            .align_y(Alignment::Center)
+                            .spacing(spacing.space_xxs)
+                            .push(widget::text::body(fl!(
+                                "selected-count",
+                                count = self.selected_bookmark_ids.len()
+                            )))
+                            .push(widget::horizontal_space())
+                            .push(
+                                widget::button::link(fl!("select-page"))
+                                    .font_size(12)
+                                    .on_press(BookmarksAction::SelectAllOnPage),
+                            )
+                            .push(
+                                widget::button::link(fl!("select-all-matching"))
+                                    .font_size(12)
+                                    .on_press(BookmarksAction::SelectAllMatching),
+                            )
+                            .push(
+                                widget::button::link(fl!("clear-selection"))
+                                    .font_size(12)
+                                    .on_press(BookmarksAction::ClearSelection),
+                            ),
+                    )
+                    .push(
+                        widget::row::with_capacity(10)
+                            .align_y(Alignment::Center)
+                            .spacing(spacing.space_xxs)
+                            .push(bulk_button(
+                                fl!("archive"),
+                                BookmarksAction::BulkAction(BulkAction::Archive),
+                            ))
+                            .push(bulk_button(
+                                fl!("unarchive"),
+                                BookmarksAction::BulkAction(BulkAction::Unarchive),
+                            ))
+                            .push(bulk_button(
+                                fl!("mark-read"),
+                                BookmarksAction::BulkAction(BulkAction::MarkRead),
+                            ))
+                            .push(bulk_button(
+                                fl!("mark-unread"),
+                                BookmarksAction::BulkAction(BulkAction::MarkUnread),
+                            ))
+                            .push(bulk_button(
+                                fl!("share"),
+                                BookmarksAction::BulkAction(BulkAction::Share),
+                            ))
+                            .push(bulk_button(
+                                fl!("unshare"),
+                                BookmarksAction::BulkAction(BulkAction::Unshare),
+                            ))
+                            .push(bulk_button(
+                                fl!("add-tags"),
+                                BookmarksAction::OpenBulkActionDialog(BulkAction::AddTags(
+                                    Vec::new(),
+                                )),
+                            ))
+                            .push(bulk_button(
+                                fl!("remove-tags"),
+                                BookmarksAction::OpenBulkActionDialog(BulkAction::RemoveTags(
+                                    Vec::new(),
+                                )),
+                            ))
+                            .push(bulk_button(
+                                fl!("move"),
+                                BookmarksAction::OpenTransferDialog(selected_bookmark_ids.clone()),
+                            ))
+                            .push(bulk_button(
+                                fl!("remove"),
+                                BookmarksAction::OpenBulkActionDialog(BulkAction::Remove),
+                            )),
+                    ),
+            );
+        }
+
         if let Some(progress) = operation_progress {
             let progress_info = ProgressInfo {
                 total: progress.total,
@@ -473,6 +716,12 @@ impl PageBookmarksView {
                     cosmic::Action::App(ApplicationAction::SearchBookmarks(query.clone()))
                 }));
             }
+            BookmarksAction::FilterByCollection(collection) => {
+                commands.push(self.update(BookmarksAction::SearchBookmarks(format!(
+                    "collection:{}",
+                    search::quote(&collection)
+                ))));
+            }
             BookmarksAction::ClearSearch => {
                 if !self.query_placeholder.is_empty() {
                     self.query_placeholder = String::new();
@@ -486,6 +735,79 @@ impl PageBookmarksView {
                     cosmic::Action::App(ApplicationAction::OpenExternalUrl(url.clone()))
                 }));
             }
+            BookmarksAction::ToggleSelectionMode => {
+                self.selection_mode = !self.selection_mode;
+                if !self.selection_mode {
+                    self.selected_bookmark_ids.clear();
+                    self.last_selected_index = None;
+                }
+            }
+            // NOTE: (vkhitrin) holding shift selects (or deselects) the range of bookmarks
+            //       between the previously toggled bookmark and the current one.
+            BookmarksAction::ToggleBookmarkSelection(index, checked) => {
+                let range = match self.last_selected_index {
+                    Some(last_index) if self.modifiers.shift() => {
+                        last_index.min(index)..=last_index.max(index)
+                    }
+                    _ => index..=index,
+                };
+                if let Some(bookmarks) = self.bookmarks.get(range) {
+                    for bookmark_id in bookmarks
+                        .iter()
+                        .filter(|bookmark| bookmark.is_owner == Some(true))
+                        .filter_map(|bookmark| bookmark.id)
+                    {
+                        if checked {
+                            self.selected_bookmark_ids.insert(bookmark_id);
+                        } else {
+                            self.selected_bookmark_ids.remove(&bookmark_id);
+                        }
+                    }
+                }
+                self.last_selected_index = Some(index);
+            }
+            BookmarksAction::SelectAllOnPage => {
+                self.selected_bookmark_ids.extend(
+                    self.bookmarks
+                        .iter()
+                        .filter(|bookmark| bookmark.is_owner == Some(true))
+                        .filter_map(|bookmark| bookmark.id),
+                );
+            }
+            BookmarksAction::SelectAllMatching => {
+                commands.push(Task::perform(async {}, |()| {
+                    cosmic::Action::App(ApplicationAction::SelectAllMatchingBookmarks)
+                }));
+            }
+            BookmarksAction::ClearSelection => {
+                self.selected_bookmark_ids.clear();
+                self.last_selected_index = None;
+            }
+            BookmarksAction::BulkAction(action) => {
+                let bookmark_ids: Vec<i64> = self.selected_bookmark_ids.iter().copied().collect();
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::StartBulkAction(
+                        action.clone(),
+                        bookmark_ids.clone(),
+                    ))
+                }));
+            }
+            BookmarksAction::OpenBulkActionDialog(action) => {
+                let bookmark_ids: Vec<i64> = self.selected_bookmark_ids.iter().copied().collect();
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::OpenBulkActionDialog(
+                        action.clone(),
+                        bookmark_ids.clone(),
+                    ))
+                }));
+            }
+            BookmarksAction::OpenTransferDialog(bookmark_ids) => {
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::OpenTransferBookmarksDialog(
+                        bookmark_ids.clone(),
+                    ))
+                }));
+            }
             BookmarksAction::ViewNotes(bookmark) => {
                 commands.push(Task::perform(async {}, move |()| {
                     cosmic::Action::App(ApplicationAction::ViewBookmarkNotes(bookmark.clone()))
@@ -545,8 +867,8 @@ where
         widget::dropdown(accounts, Some(selected_account_index), move |idx| {
             ApplicationAction::AddBookmarkFormAccountIndex(idx)
         });
-    let provider_icon =
-        widget::icon(accounts[selected_account_index].provider().svg_icon()).size(16);
+    let provider_icon = widget::icon(accounts[selected_account_index].provider_icon()).size(16);
+    let capabilities = provider::capabilities(&accounts[selected_account_index]);
     let url_widget_title = widget::text::body(fl!("url"));
     let url_widget_text_input =
         widget::text_input("URL", bookmark.url.clone()).on_input(ApplicationAction::SetBookmarkURL);
@@ -577,38 +899,38 @@ where
         .on_input(ApplicationAction::SetBookmarkTags);
     let archived_widget_toggler = widget::toggler(bookmark.is_archived)
         .spacing(10)
-        .on_toggle_maybe(if accounts[selected_account_index].is_local_provider() {
-            None
-        } else {
+        .on_toggle_maybe(if capabilities.archiving {
             Some(ApplicationAction::SetBookmarkArchived)
-        })
-        .label(if accounts[selected_account_index].is_local_provider() {
-            fl!("archived") + " (" + &fl!("disabled") + ")"
         } else {
+            None
+        })
+        .label(if capabilities.archiving {
             fl!("archived")
+        } else {
+            fl!("archived") + " (" + &fl!("disabled") + ")"
         });
     let unread_widget_toggler = widget::toggler(bookmark.unread)
         .spacing(10)
-        .on_toggle_maybe(if accounts[selected_account_index].is_local_provider() {
-            None
-        } else {
+        .on_toggle_maybe(if capabilities.unread {
             Some(ApplicationAction::SetBookmarkUnread)
-        })
-        .label(if accounts[selected_account_index].is_local_provider() {
-            fl!("unread") + " (" + &fl!("disabled") + ")"
         } else {
+            None
+        })
+        .label(if capabilities.unread {
             fl!("unread")
+        } else {
+            fl!("unread") + " (" + &fl!("disabled") + ")"
         });
     let shared_widget_toggler = widget::toggler(bookmark.shared)
         .spacing(10)
-        .on_toggle_maybe(if accounts[selected_account_index].is_local_provider() {
+        .on_toggle_maybe(if !capabilities.sharing {
             None
         } else if accounts[selected_account_index].enable_sharing {
             Some(ApplicationAction::SetBookmarkShared)
         } else {
             None
         })
-        .label(if accounts[selected_account_index].is_local_provider() {
+        .label(if !capabilities.sharing {
             fl!("shared") + " (" + &fl!("disabled") + ")"
         } else if accounts[selected_account_index].enable_sharing {
             fl!("shared")
@@ -676,34 +998,42 @@ where
                 .align_y(Alignment::Center),
         )
         .push(title_widget_text_input)
-        .push(
-            widget::row::with_capacity(2)
-                .spacing(spacing.space_xxs)
-                .push(widget::icon::from_name("text-x-generic-symbolic"))
-                .push(description_widget_title)
-                .padding([
-                    spacing.space_xxxs,
-                    spacing.space_xxs,
-                    spacing.space_none,
-                    spacing.space_none,
-                ])
-                .align_y(Alignment::Start),
+        .push_maybe(
+            capabilities.description.then_some(
+                widget::row::with_capacity(2)
+                    .spacing(spacing.space_xxs)
+                    .push(widget::icon::from_name("text-x-generic-symbolic"))
+                    .push(description_widget_title)
+                    .padding([
+                        spacing.space_xxxs,
+                        spacing.space_xxs,
+                        spacing.space_none,
+                        spacing.space_none,
+                    ])
+                    .align_y(Alignment::Start),
+            ),
         )
-        .push(description_widget_text_editor)
-        .push(
-            widget::row::with_capacity(2)
-                .spacing(spacing.space_xxs)
-                .push(widget::icon::from_name("x-office-document-symbolic"))
-                .push(notes_widget_title)
-                .padding([
-                    spacing.space_xxxs,
-                    spacing.space_xxs,
-                    spacing.space_none,
-                    spacing.space_none,
-                ])
-                .align_y(Alignment::Center),
+        .push_maybe(
+            capabilities
+                .description
+                .then_some(description_widget_text_editor),
+        )
+        .push_maybe(
+            capabilities.notes.then_some(
+                widget::row::with_capacity(2)
+                    .spacing(spacing.space_xxs)
+                    .push(widget::icon::from_name("x-office-document-symbolic"))
+                    .push(notes_widget_title)
+                    .padding([
+                        spacing.space_xxxs,
+                        spacing.space_xxs,
+                        spacing.space_none,
+                        spacing.space_none,
+                    ])
+                    .align_y(Alignment::Center),
+            ),
         )
-        .push(notes_widget_text_editor)
+        .push_maybe(capabilities.notes.then_some(notes_widget_text_editor))
         .push(
             widget::row::with_capacity(2)
                 .spacing(spacing.space_xxs)
@@ -743,6 +1073,7 @@ where
     let account_widget_title = widget::text::body(fl!("account"));
     let account_widget_text_input =
         widget::text_input(&account.display_name, &account.display_name);
+    let capabilities = provider::capabilities(account);
     let url_widget_title = widget::text::body(fl!("url"));
     let url_widget_text_input =
         widget::text_input("URL", bookmark.url.clone()).on_input(ApplicationAction::SetBookmarkURL);
@@ -773,38 +1104,38 @@ where
         .on_input(ApplicationAction::SetBookmarkTags);
     let archived_widget_toggler = widget::toggler(bookmark.is_archived)
         .spacing(10)
-        .on_toggle_maybe(if account.is_local_provider() {
-            None
-        } else {
+        .on_toggle_maybe(if capabilities.archiving {
             Some(ApplicationAction::SetBookmarkArchived)
-        })
-        .label(if account.is_local_provider() {
-            fl!("archived") + " (" + &fl!("disabled") + ")"
         } else {
+            None
+        })
+        .label(if capabilities.archiving {
             fl!("archived")
+        } else {
+            fl!("archived") + " (" + &fl!("disabled") + ")"
         });
     let unread_widget_toggler = widget::toggler(bookmark.unread)
         .spacing(10)
-        .on_toggle_maybe(if account.is_local_provider() {
-            None
-        } else {
+        .on_toggle_maybe(if capabilities.unread {
             Some(ApplicationAction::SetBookmarkUnread)
-        })
-        .label(if account.is_local_provider() {
-            fl!("unread") + " (" + &fl!("disabled") + ")"
         } else {
+            None
+        })
+        .label(if capabilities.unread {
             fl!("unread")
+        } else {
+            fl!("unread") + " (" + &fl!("disabled") + ")"
         });
     let shared_widget_toggler = widget::toggler(bookmark.shared)
         .spacing(10)
-        .on_toggle_maybe(if account.is_local_provider() {
+        .on_toggle_maybe(if !capabilities.sharing {
             None
         } else if account.enable_sharing {
             Some(ApplicationAction::SetBookmarkShared)
         } else {
             None
         })
-        .label(if account.is_local_provider() {
+        .label(if !capabilities.sharing {
             fl!("shared") + " (" + &fl!("disabled") + ")"
         } else if account.enable_sharing {
             fl!("shared")
@@ -867,34 +1198,42 @@ where
                 .align_y(Alignment::Center),
         )
         .push(title_widget_text_input)
-        .push(
-            widget::row::with_capacity(2)
-                .spacing(spacing.space_xxs)
-                .push(widget::icon::from_name("text-x-generic-symbolic"))
-                .push(description_widget_title)
-                .padding([
-                    spacing.space_xxxs,
-                    spacing.space_xxs,
-                    spacing.space_none,
-                    spacing.space_none,
-                ])
-                .align_y(Alignment::Start),
+        .push_maybe(
+            capabilities.description.then_some(
+                widget::row::with_capacity(2)
+                    .spacing(spacing.space_xxs)
+                    .push(widget::icon::from_name("text-x-generic-symbolic"))
+                    .push(description_widget_title)
+                    .padding([
+                        spacing.space_xxxs,
+                        spacing.space_xxs,
+                        spacing.space_none,
+                        spacing.space_none,
+                    ])
+                    .align_y(Alignment::Start),
+            ),
         )
-        .push(description_widget_text_editor)
-        .push(
-            widget::row::with_capacity(2)
-                .spacing(spacing.space_xxs)
-                .push(widget::icon::from_name("x-office-document-symbolic"))
-                .push(notes_widget_title)
-                .padding([
-                    spacing.space_xxxs,
-                    spacing.space_xxs,
-                    spacing.space_none,
-                    spacing.space_none,
-                ])
-                .align_y(Alignment::Center),
+        .push_maybe(
+            capabilities
+                .description
+                .then_some(description_widget_text_editor),
+        )
+        .push_maybe(
+            capabilities.notes.then_some(
+                widget::row::with_capacity(2)
+                    .spacing(spacing.space_xxs)
+                    .push(widget::icon::from_name("x-office-document-symbolic"))
+                    .push(notes_widget_title)
+                    .padding([
+                        spacing.space_xxxs,
+                        spacing.space_xxs,
+                        spacing.space_none,
+                        spacing.space_none,
+                    ])
+                    .align_y(Alignment::Center),
+            ),
         )
-        .push(notes_widget_text_editor)
+        .push_maybe(capabilities.notes.then_some(notes_widget_text_editor))
         .push(
             widget::row::with_capacity(2)
                 .spacing(spacing.space_xxs)
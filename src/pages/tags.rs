@@ -0,0 +1,227 @@
+use crate::{
+    app::actions::{ApplicationAction, TagsAction},
+    fl,
+    models::{
+        operation::OperationProgress,
+        tag::{Tag, TagOperation},
+    },
+    widgets::progress_info::{operation_progress_widget, ProgressInfo},
+};
+use cosmic::{
+    app::Task,
+    iced::{Alignment, Length},
+    style, theme,
+    widget::{self},
+    Apply, Element,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct PageTagsView {
+    filter_placeholder: String,
+    pub selected_tags: Vec<String>,
+    pub tags: Vec<Tag>,
+}
+
+impl PageTagsView {
+    pub fn view<'a>(
+        &'a self,
+        operation_progress: Option<&'a OperationProgress>,
+    ) -> Element<'a, TagsAction> {
+        let spacing = theme::active().cosmic().spacing;
+        let filter = self.filter_placeholder.to_lowercase();
+        let filtered_tags: Vec<&Tag> = self
+            .tags
+            .iter()
+            .filter(|tag| filter.is_empty() || tag.name.to_lowercase().contains(&filter))
+            .collect();
+
+        let mut tags = widget::list::list_column()
+            .style(style::Container::Background)
+            .list_item_padding(spacing.space_none)
+            .divider_padding(spacing.space_none)
+            .spacing(spacing.space_xxxs)
+            .padding(spacing.space_none);
+
+        for tag in &filtered_tags {
+            let tag_name = tag.name.clone();
+            tags = tags.add(
+                widget::row::with_capacity(4)
+                    .spacing(spacing.space_xxs)
+                    .padding([
+                        spacing.space_xxs,
+                        spacing.space_m,
+                        spacing.space_xxs,
+                        spacing.space_xxxs,
+                    ])
+                    .push(
+                        widget::checkbox("", self.selected_tags.contains(&tag.name)).on_toggle(
+                            move |checked| {
+                                TagsAction::ToggleTagSelection(tag_name.clone(), checked)
+                            },
+                        ),
+                    )
+                    .push(
+                        widget::button::link(format!("#{}", tag.name))
+                            .on_press(TagsAction::OpenTag(tag.name.clone())),
+                    )
+                    .push(widget::horizontal_space())
+                    .push(widget::text::body(tag.bookmarks_count.to_string()))
+                    .align_y(Alignment::Center)
+                    .apply(widget::container)
+                    .class(theme::Container::Background),
+            );
+        }
+
+        let tags_widget: Element<'_, TagsAction> = if filtered_tags.is_empty() {
+            widget::container(widget::text::body(fl!("no-tags")))
+                .width(Length::Fill)
+                .align_x(cosmic::iced::alignment::Horizontal::Center)
+                .padding(spacing.space_m)
+                .into()
+        } else {
+            widget::column::with_capacity(1)
+                .spacing(spacing.space_xxs)
+                .push(tags)
+                .height(Length::Shrink)
+                .apply(widget::scrollable)
+                .height(Length::Fill)
+                .into()
+        };
+
+        let filter_input_widget =
+            widget::text_input::search_input(fl!("search"), self.filter_placeholder.clone())
+                .on_input(TagsAction::FilterTags)
+                .on_clear(TagsAction::ClearFilter);
+
+        let mut main_column = widget::column::with_capacity(4).push(
+            widget::row::with_capacity(2)
+                .align_y(Alignment::Center)
+                .spacing(spacing.space_xxs)
+                .padding([
+                    spacing.space_none,
+                    spacing.space_none,
+                    spacing.space_xxs,
+                    spacing.space_none,
+                ])
+                .push(widget::text::title3(fl!(
+                    "tags-with-count",
+                    count = self.tags.len()
+                )))
+                .push(widget::horizontal_space())
+                .push(filter_input_widget)
+                .width(Length::Fill),
+        );
+
+        if !self.selected_tags.is_empty() {
+            let is_idle = operation_progress.is_none();
+            main_column = main_column.push(
+                widget::row::with_capacity(5)
+                    .align_y(Alignment::Center)
+                    .spacing(spacing.space_xxs)
+                    .padding([
+                        spacing.space_none,
+                        spacing.space_none,
+                        spacing.space_xxs,
+                        spacing.space_none,
+                    ])
+                    .push(widget::text::body(fl!(
+                        "selected-count",
+                        count = self.selected_tags.len()
+                    )))
+                    .push(widget::horizontal_space())
+                    .push(widget::button::standard(fl!("rename")).on_press_maybe(
+                        (is_idle && self.selected_tags.len() == 1).then_some(TagsAction::RenameTag),
+                    ))
+                    .push(widget::button::standard(fl!("merge")).on_press_maybe(
+                        (is_idle && self.selected_tags.len() > 1).then_some(TagsAction::MergeTags),
+                    ))
+                    .push(
+                        widget::button::destructive(fl!("remove"))
+                            .on_press_maybe(is_idle.then_some(TagsAction::DeleteTags)),
+                    ),
+            );
+        }
+
+        if let Some(progress) = operation_progress {
+            let progress_info = ProgressInfo {
+                total: progress.total,
+                current: progress.current,
+                label: progress.operation_label.clone(),
+                cancellable: progress.cancellable,
+            };
+
+            let progress_widget = operation_progress_widget(
+                &progress_info,
+                if progress.cancellable {
+                    Some(TagsAction::CancelOperation(progress.operation_id))
+                } else {
+                    None
+                },
+            );
+
+            main_column = main_column.push(progress_widget);
+        }
+
+        main_column
+            .push(tags_widget)
+            .apply(widget::container)
+            .into()
+    }
+
+    pub fn update(&mut self, message: TagsAction) -> Task<ApplicationAction> {
+        let mut commands = Vec::new();
+        match message {
+            TagsAction::CancelOperation(operation_id) => {
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::CancelBulkAction(operation_id))
+                }));
+            }
+            TagsAction::DeleteTags => {
+                let operation = TagOperation::Delete(self.selected_tags.clone());
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::OpenTagOperationDialog(
+                        operation.clone(),
+                    ))
+                }));
+            }
+            TagsAction::FilterTags(filter) => {
+                self.filter_placeholder = filter;
+            }
+            TagsAction::ClearFilter => {
+                self.filter_placeholder = String::new();
+            }
+            TagsAction::MergeTags => {
+                let operation =
+                    TagOperation::Merge(self.selected_tags.clone(), self.selected_tags[0].clone());
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::OpenTagOperationDialog(
+                        operation.clone(),
+                    ))
+                }));
+            }
+            TagsAction::OpenTag(tag) => {
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::FilterBookmarksByTag(tag.clone()))
+                }));
+            }
+            TagsAction::RenameTag => {
+                let operation = TagOperation::Rename(
+                    self.selected_tags[0].clone(),
+                    self.selected_tags[0].clone(),
+                );
+                commands.push(Task::perform(async {}, move |()| {
+                    cosmic::Action::App(ApplicationAction::OpenTagOperationDialog(
+                        operation.clone(),
+                    ))
+                }));
+            }
+            TagsAction::ToggleTagSelection(tag, checked) => {
+                self.selected_tags.retain(|t| t != &tag);
+                if checked {
+                    self.selected_tags.push(tag);
+                }
+            }
+        }
+        Task::batch(commands)
+    }
+}
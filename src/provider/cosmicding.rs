@@ -1,15 +1,101 @@
 use crate::{
     db::SqliteDatabase,
     models::{
-        account::Account,
+        account::{Account, AccountDetails},
         bookmarks::{
-            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, DetailedResponse,
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksSyncEvent,
+            DetailedResponse,
         },
+        provider::{ProviderCapabilities, COSMICDING_PROVIDER},
     },
+    provider::BookmarkProvider,
 };
 use chrono::Utc;
+use cosmic::widget;
+use futures::{channel::mpsc::UnboundedSender, future::BoxFuture};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub struct CosmicdingProvider;
+
+impl BookmarkProvider for CosmicdingProvider {
+    fn provider_string(&self) -> &'static str {
+        COSMICDING_PROVIDER
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Cosmicding"
+    }
+
+    fn svg_icon(&self) -> widget::icon::Handle {
+        widget::icon::from_svg_bytes(include_bytes!(
+            "../../res/icons/hicolor/scalable/apps/com.vkhitrin.cosmicding.svg"
+        ))
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            description: true,
+            notes: true,
+            ..Default::default()
+        }
+    }
+
+    fn version(&self, _account_details: Option<&AccountDetails>) -> Option<String> {
+        Some(get_provider_version())
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        _page_size: u16,
+        _modified_since: Option<String>,
+        _sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move { fetch_bookmarks_for_account(&account).await })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(
+            account,
+            bookmark,
+            check_for_existing,
+            disable_scraping,
+        ))
+    }
+
+    // NOTE: (vkhitrin) local bookmarks are edited the same way they are populated, only
+    //       the modification timestamp is refreshed.
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(account, bookmark, false, false))
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    // NOTE: (vkhitrin) local provider has no remote account details.
+    fn fetch_account_details(
+        &self,
+        _account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(async { None })
+    }
+}
+
 pub fn get_provider_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
@@ -59,8 +145,12 @@ pub async fn populate_bookmark(
     //       Use ISO 8601 format with 'Z' suffix to match linkding API format
     let timestamp_string = Utc::now().format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string();
 
+    // NOTE: (vkhitrin) bookmarks transferred from other accounts keep their original
+    //       date_added.
     if is_new {
-        bookmark.date_added = Some(timestamp_string.clone());
+        if bookmark.date_added.is_none() {
+            bookmark.date_added = Some(timestamp_string.clone());
+        }
         bookmark.date_modified = Some(timestamp_string);
     } else {
         bookmark.date_modified = Some(timestamp_string);
@@ -0,0 +1,677 @@
+use crate::{
+    fl,
+    models::{
+        account::{Account, AccountDetails},
+        bookmarks::{
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksPage,
+            BookmarksSyncEvent, DetailedResponse,
+        },
+        provider::ProviderCapabilities,
+        sync_run::EndpointStatus,
+    },
+    provider::{
+        account_details_error, current_timestamp, edit_bookmark_without_conflicts,
+        http_client::http_client_for_account, http_error, is_unreachable_error,
+        normalize_unreserved_tag_name, not_synced_error, parse_response_timestamp,
+        remove_remote_bookmark, unescape_tag_name, BookmarkProvider,
+    },
+};
+use futures::{channel::mpsc::UnboundedSender, future::BoxFuture};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use urlencoding::encode;
+
+const KARAKEEP_BOOKMARKS_ENDPOINT: &str = "/api/v1/bookmarks";
+const KARAKEEP_USER_ENDPOINT: &str = "/api/v1/users/me";
+const KARAKEEP_MAX_PAGE_SIZE: u16 = 100;
+// NOTE: (vkhitrin) Karakeep bookmarks can be favourited, there is no equivalent field in
+//       cosmicding, favourited bookmarks are tagged using a reserved tag instead.
+const FAVOURITED_TAG: &str = "!favourited";
+
+pub struct KarakeepProvider;
+
+impl BookmarkProvider for KarakeepProvider {
+    fn provider_string(&self) -> &'static str {
+        "karakeep"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Karakeep"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            archiving: true,
+            description: true,
+            notes: true,
+            ..ProviderCapabilities::default()
+        }
+    }
+
+    fn authorization(&self, account: &Account) -> Option<String> {
+        Some(format!("Bearer {}", account.api_token))
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        page_size: u16,
+        _modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move {
+            match fetch_bookmarks_for_account(&account, page_size, &sender).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::error!("Error fetching Karakeep bookmarks: {e}");
+                    let mut response =
+                        DetailedResponse::new(account, current_timestamp(), false, None);
+                    response.error = Some(e.to_string());
+                    response
+                }
+            }
+        })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        _disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(account, bookmark, check_for_existing))
+    }
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(async move {
+            let mut api_response = BookmarkCheckDetailsResponse::default();
+            edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+            Some(api_response)
+        })
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    fn fetch_account_details(
+        &self,
+        account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(fetch_account_details(account))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KarakeepBookmarksResponse {
+    bookmarks: Vec<KarakeepBookmark>,
+    next_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KarakeepBookmark {
+    id: String,
+    created_at: String,
+    modified_at: Option<String>,
+    title: Option<String>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    favourited: bool,
+    note: Option<String>,
+    summary: Option<String>,
+    #[serde(default)]
+    tags: Vec<KarakeepTag>,
+    content: KarakeepContent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct KarakeepTag {
+    id: String,
+    name: String,
+}
+
+// NOTE: (vkhitrin) Karakeep stores links, text notes and assets (images, PDFs),
+//       only links are synced.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum KarakeepContent {
+    Link(KarakeepLinkContent),
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KarakeepLinkContent {
+    url: String,
+    title: Option<String>,
+    description: Option<String>,
+    image_url: Option<String>,
+    favicon: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct KarakeepBookmarkRequest<'a> {
+    url: &'a str,
+    title: Option<&'a str>,
+    summary: &'a str,
+    note: &'a str,
+    archived: bool,
+    favourited: bool,
+}
+
+impl<'a> KarakeepBookmarkRequest<'a> {
+    fn new(bookmark: &'a Bookmark) -> Self {
+        Self {
+            url: &bookmark.url,
+            title: (!bookmark.title.is_empty()).then_some(bookmark.title.as_str()),
+            summary: &bookmark.description,
+            note: &bookmark.notes,
+            archived: bookmark.is_archived,
+            favourited: bookmark.tag_names.iter().any(|tag| tag == FAVOURITED_TAG),
+        }
+    }
+}
+
+// NOTE: (vkhitrin) Karakeep identifiers are strings, the bookmark is tracked locally
+//       using `provider_remote_id`.
+fn remote_id(bookmark: &Bookmark) -> Result<&str, Box<dyn std::error::Error>> {
+    bookmark
+        .provider_remote_id
+        .as_deref()
+        .ok_or_else(not_synced_error)
+}
+
+fn bookmark_url(account: &Account, remote_id: &str) -> String {
+    format!(
+        "{}{KARAKEEP_BOOKMARKS_ENDPOINT}/{}",
+        account.instance,
+        encode(remote_id)
+    )
+}
+
+fn transform_bookmark(account: &Account, bookmark: KarakeepBookmark) -> Option<Bookmark> {
+    let KarakeepContent::Link(content) = bookmark.content else {
+        return None;
+    };
+    let mut tag_names: Vec<String> = bookmark
+        .tags
+        .iter()
+        .map(|tag| normalize_unreserved_tag_name(&tag.name))
+        .collect();
+    if bookmark.favourited && !tag_names.iter().any(|tag| tag == FAVOURITED_TAG) {
+        tag_names.push(FAVOURITED_TAG.to_string());
+    }
+    let date_modified = bookmark
+        .modified_at
+        .or_else(|| Some(bookmark.created_at.clone()));
+    let mut transformed_bookmark = Bookmark::new(
+        account.id,
+        None,
+        content.url,
+        bookmark.title.unwrap_or_default(),
+        bookmark.summary.unwrap_or_default(),
+        content.title.unwrap_or_default(),
+        content.description.unwrap_or_default(),
+        bookmark.note.unwrap_or_default(),
+        String::new(),
+        content.favicon.unwrap_or_default(),
+        content.image_url.unwrap_or_default(),
+        bookmark.archived,
+        false,
+        false,
+        tag_names,
+        Some(bookmark.created_at),
+        date_modified,
+        Some(true),
+    );
+    transformed_bookmark.provider_remote_id = Some(bookmark.id);
+    Some(transformed_bookmark)
+}
+
+fn parse_bookmark(
+    account: &Account,
+    bookmark: KarakeepBookmark,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    transform_bookmark(account, bookmark).ok_or_else(|| {
+        Box::new(std::io::Error::other(fl!("failed-to-parse-response")))
+            as Box<dyn std::error::Error>
+    })
+}
+
+// NOTE: (vkhitrin) Karakeep paginates bookmarks using a cursor, pages are fetched until
+//       no cursor is returned. Bookmarks can't be filtered by modification date, every sync
+//       is a full sync.
+pub async fn fetch_bookmarks_for_account(
+    account: &Account,
+    page_size: u16,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
+) -> Result<DetailedResponse, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let page_size = page_size.clamp(1, KARAKEEP_MAX_PAGE_SIZE);
+    // NOTE: (vkhitrin) the archived content of the bookmarks is not needed.
+    let endpoint_url = format!(
+        "{}{KARAKEEP_BOOKMARKS_ENDPOINT}?limit={page_size}&includeContent=false",
+        account.instance
+    );
+    let mut detailed_response = DetailedResponse::new(account.clone(), 0, false, None);
+    let mut endpoint_status = EndpointStatus::new(KARAKEEP_BOOKMARKS_ENDPOINT, None, false);
+    let mut bookmark_ids: HashSet<String> = HashSet::new();
+    let mut cursor: Option<String> = None;
+    let mut page: usize = 0;
+    loop {
+        let page_url = match &cursor {
+            Some(cursor) => format!("{endpoint_url}&cursor={}", encode(cursor)),
+            None => endpoint_url.clone(),
+        };
+        let response = http_client.get(&page_url).send().await?;
+        endpoint_status.status = Some(response.status().as_u16());
+        if response.status() != StatusCode::OK {
+            let error = http_error(response).await;
+            detailed_response.error = Some(format!("{KARAKEEP_BOOKMARKS_ENDPOINT}: {error}"));
+            break;
+        }
+        if detailed_response.timestamp == 0 {
+            detailed_response.timestamp = parse_response_timestamp(&response)?;
+        }
+        let karakeep_response = match response.json::<KarakeepBookmarksResponse>().await {
+            Ok(value) => value,
+            Err(e) => {
+                log::error!("Error parsing JSON: {e:?}");
+                detailed_response.error = Some(format!("{KARAKEEP_BOOKMARKS_ENDPOINT}: {e}"));
+                break;
+            }
+        };
+        page += 1;
+        // NOTE: (vkhitrin) a cursor that was already visited is treated as the last page.
+        let is_last_page =
+            karakeep_response.next_cursor.is_none() || karakeep_response.next_cursor == cursor;
+        let bookmarks: Vec<Bookmark> = karakeep_response
+            .bookmarks
+            .into_iter()
+            .filter(|bookmark| bookmark_ids.insert(bookmark.id.clone()))
+            .filter_map(|bookmark| transform_bookmark(account, bookmark))
+            .collect();
+        // NOTE: (vkhitrin) the total number of pages is unknown, another page is assumed
+        //       until the last page is returned.
+        let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+            account.clone(),
+            bookmarks,
+            page,
+            if is_last_page { page } else { page + 1 },
+        )));
+        if is_last_page {
+            endpoint_status.successful = true;
+            detailed_response.successful = true;
+            break;
+        }
+        cursor = karakeep_response.next_cursor;
+    }
+    if detailed_response.timestamp == 0 {
+        detailed_response.timestamp = current_timestamp();
+    }
+    detailed_response.endpoint_statuses.push(endpoint_status);
+    detailed_response.bookmarks = Some(Vec::new());
+    Ok(detailed_response)
+}
+
+async fn fetch_remote_bookmark(
+    account: &Account,
+    remote_id: &str,
+) -> Result<KarakeepBookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let response = http_client
+        .get(format!(
+            "{}?includeContent=false",
+            bookmark_url(account, remote_id)
+        ))
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<KarakeepBookmark>().await {
+            Ok(value) => Ok(value),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+// NOTE: (vkhitrin) tags are attached and detached using dedicated endpoints, tags that
+//       were not modified are left untouched (including tags attached by AI).
+async fn update_tags(
+    account: &Account,
+    remote_id: &str,
+    tag_names: &[String],
+    remote_tags: &[KarakeepTag],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let tags_url = format!("{}/tags", bookmark_url(account, remote_id));
+    let attached_tags: Vec<_> = tag_names
+        .iter()
+        .filter(|tag_name| *tag_name != FAVOURITED_TAG)
+        .filter(|tag_name| {
+            !remote_tags
+                .iter()
+                .any(|remote_tag| normalize_unreserved_tag_name(&remote_tag.name) == **tag_name)
+        })
+        .map(|tag_name| json!({ "tagName": unescape_tag_name(tag_name) }))
+        .collect();
+    let detached_tags: Vec<_> = remote_tags
+        .iter()
+        .filter(|remote_tag| !tag_names.contains(&normalize_unreserved_tag_name(&remote_tag.name)))
+        .map(|remote_tag| json!({ "tagId": remote_tag.id }))
+        .collect();
+    if !attached_tags.is_empty() {
+        let response = http_client
+            .post(&tags_url)
+            .json(&json!({ "tags": attached_tags }))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(http_error(response).await);
+        }
+    }
+    if !detached_tags.is_empty() {
+        let response = http_client
+            .delete(&tags_url)
+            .json(&json!({ "tags": detached_tags }))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(http_error(response).await);
+        }
+    }
+    Ok(())
+}
+
+async fn update_remote_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+    remote_bookmark: &KarakeepBookmark,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let response = http_client
+        .patch(bookmark_url(account, &remote_bookmark.id))
+        .json(&KarakeepBookmarkRequest::new(bookmark))
+        .send()
+        .await?;
+    if response.status() != StatusCode::OK {
+        return Err(http_error(response).await);
+    }
+    update_tags(
+        account,
+        &remote_bookmark.id,
+        &bookmark.tag_names,
+        &remote_bookmark.tags,
+    )
+    .await?;
+    parse_bookmark(
+        account,
+        fetch_remote_bookmark(account, &remote_bookmark.id).await?,
+    )
+}
+
+// NOTE: (vkhitrin) Karakeep returns the existing bookmark (instead of creating a new one)
+//       when a bookmark with the same URL exists, the bookmark is updated instead.
+//       Returns the bookmark and whether it was created.
+async fn create_remote_bookmark(
+    account: &Account,
+    mut bookmark: Bookmark,
+) -> Result<(Bookmark, bool), Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let mut request = serde_json::to_value(KarakeepBookmarkRequest::new(&bookmark))?;
+    request["type"] = json!("link");
+    let response = http_client
+        .post(format!("{}{KARAKEEP_BOOKMARKS_ENDPOINT}", account.instance))
+        .json(&request)
+        .send()
+        .await?;
+    let status = response.status();
+    if status != StatusCode::CREATED && status != StatusCode::OK {
+        return Err(http_error(response).await);
+    }
+    let remote_bookmark = match response.json::<KarakeepBookmark>().await {
+        Ok(value) => value,
+        Err(_e) => {
+            return Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            ))))
+        }
+    };
+    if status == StatusCode::CREATED {
+        update_tags(account, &remote_bookmark.id, &bookmark.tag_names, &[]).await?;
+        let created_bookmark = fetch_remote_bookmark(account, &remote_bookmark.id).await?;
+        return Ok((parse_bookmark(account, created_bookmark)?, true));
+    }
+    bookmark.provider_remote_id = Some(remote_bookmark.id.clone());
+    if bookmark.title.is_empty() {
+        bookmark.title = remote_bookmark.title.clone().unwrap_or_default();
+    }
+    if bookmark.description.is_empty() {
+        bookmark.description = remote_bookmark.summary.clone().unwrap_or_default();
+    }
+    if bookmark.notes.is_empty() {
+        bookmark.notes = remote_bookmark.note.clone().unwrap_or_default();
+    }
+    Ok((
+        update_remote_bookmark(account, &bookmark, &remote_bookmark).await?,
+        false,
+    ))
+}
+
+pub async fn populate_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+    check_for_existing: bool,
+) -> Option<BookmarkCheckDetailsResponse> {
+    let mut api_response = BookmarkCheckDetailsResponse::default();
+    if !check_for_existing {
+        edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+        return Some(api_response);
+    }
+    let result = create_remote_bookmark(&account, bookmark)
+        .await
+        .map_err(|e| (is_unreachable_error(e.as_ref()), e.to_string()));
+    match result {
+        Ok((value, is_new)) => {
+            api_response.is_new = is_new;
+            api_response.bookmark = Some(value);
+            api_response.successful = true;
+        }
+        Err((unreachable, e)) => {
+            log::error!("Error adding bookmark: {e}");
+            api_response.unreachable = unreachable;
+            api_response.error = Some(e);
+        }
+    }
+    Some(api_response)
+}
+
+async fn edit_remote_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+) {
+    edit_bookmark_without_conflicts(
+        bookmark,
+        api_response,
+        || async {
+            let remote_id = remote_id(bookmark)?;
+            let remote_bookmark = fetch_remote_bookmark(account, remote_id).await?;
+            Ok((
+                parse_bookmark(account, remote_bookmark.clone())?,
+                remote_bookmark,
+            ))
+        },
+        |remote_bookmark| async move {
+            update_remote_bookmark(account, bookmark, &remote_bookmark).await
+        },
+    )
+    .await;
+}
+
+pub async fn remove_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+) -> Option<BookmarkRemoveResponse> {
+    remove_remote_bookmark(|| async {
+        let rest_api_url = bookmark_url(&account, remote_id(&bookmark)?);
+        let http_client = http_client_for_account(&account)?;
+        Ok(http_client.delete(rest_api_url).send().await?)
+    })
+    .await
+}
+
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    let mut account_details = AccountDetails::default();
+    let rest_api_url = format!("{}{KARAKEEP_USER_ENDPOINT}", account.instance);
+    let http_client = match http_client_for_account(&account) {
+        Ok(http_client) => http_client,
+        Err(e) => {
+            log::error!("Error constructing HTTP client: {e}");
+            account_details.successful = false;
+            account_details.error = Some(e.to_string());
+            return Some(account_details);
+        }
+    };
+    match http_client.get(rest_api_url).send().await {
+        Ok(response) => match response.status() {
+            StatusCode::OK => {
+                account_details.successful = true;
+            }
+            StatusCode::UNAUTHORIZED => {
+                account_details.successful = false;
+                account_details.error = Some(fl!("invalid-api-token"));
+            }
+            StatusCode::NOT_FOUND => {
+                account_details.successful = false;
+                account_details.error = Some(fl!("failed-to-find-karakeep-api-endpoint"));
+            }
+            status => {
+                account_details.successful = false;
+                account_details.error = Some(fl!(
+                    "unexpected-http-return-code",
+                    http_rc = status.to_string()
+                ));
+            }
+        },
+        Err(e) => {
+            account_details.successful = false;
+            account_details.error = Some(account_details_error(&e.to_string()));
+            log::error!(
+                "Error fetching account {} details: {}",
+                account.display_name,
+                e
+            );
+        }
+    }
+    Some(account_details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        let mut account = Account::new(
+            "Karakeep".to_string(),
+            "token".to_string(),
+            "https://keep.example.com".to_string(),
+            "karakeep",
+        );
+        account.id = Some(8);
+        account
+    }
+
+    fn karakeep_bookmark(content: serde_json::Value) -> KarakeepBookmark {
+        serde_json::from_value(json!({
+            "id": "ieidlxygmwj87oxz5hxttoc8",
+            "createdAt": "2025-01-01T00:00:00.000Z",
+            "modifiedAt": null,
+            "title": "Example",
+            "archived": true,
+            "favourited": true,
+            "note": "Read later",
+            "summary": null,
+            "tags": [
+                {"id": "tag-1", "name": "Open Source"},
+                {"id": "tag-2", "name": "!important"}
+            ],
+            "content": content
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn transform_bookmark_parses_api_response() {
+        let bookmark = transform_bookmark(
+            &account(),
+            karakeep_bookmark(json!({
+                "type": "link",
+                "url": "https://example.com",
+                "title": "Example Domain",
+                "description": null,
+                "imageUrl": null,
+                "favicon": "https://example.com/favicon.ico"
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(bookmark.user_account_id, Some(8));
+        assert_eq!(bookmark.provider_internal_id, None);
+        assert_eq!(
+            bookmark.provider_remote_id.as_deref(),
+            Some("ieidlxygmwj87oxz5hxttoc8")
+        );
+        assert_eq!(bookmark.url, "https://example.com");
+        assert_eq!(bookmark.website_title.as_deref(), Some("Example Domain"));
+        assert!(bookmark.is_archived);
+        assert_eq!(
+            bookmark.tag_names,
+            vec!["Open-Source", "!!important", FAVOURITED_TAG]
+        );
+        assert_eq!(bookmark.date_modified, bookmark.date_added);
+    }
+
+    #[test]
+    fn transform_bookmark_skips_unsupported_content() {
+        let bookmark = transform_bookmark(
+            &account(),
+            karakeep_bookmark(json!({"type": "text", "text": "Note"})),
+        );
+
+        assert!(bookmark.is_none());
+    }
+
+    #[tokio::test]
+    async fn remove_bookmark_rejects_bookmark_that_was_not_synced() {
+        let mut bookmark = transform_bookmark(
+            &account(),
+            karakeep_bookmark(json!({"type": "link", "url": "https://example.com"})),
+        )
+        .unwrap();
+        bookmark.provider_remote_id = None;
+
+        let response = remove_bookmark(account(), bookmark).await.unwrap();
+
+        assert!(!response.successful);
+        assert!(!response.unreachable);
+        assert!(response.error.is_some());
+    }
+}
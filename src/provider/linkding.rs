@@ -1,245 +1,400 @@
 use crate::{
     fl,
     models::{
-        account::{Account, LinkdingAccountApiResponse},
+        account::{Account, AccountDetails, LinkdingAccountApiResponse},
         bookmarks::{
-            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, DetailedResponse,
-            LinkdingBookmarksApiCheckResponse, LinkdingBookmarksApiResponse,
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksPage,
+            BookmarksSyncEvent, DetailedResponse, LinkdingBookmarksApiCheckResponse,
+            LinkdingBookmarksApiResponse,
         },
+        provider::{ProviderCapabilities, LINKDING_PROVIDER},
+        sync_run::EndpointStatus,
+    },
+    provider::{
+        account_details_error, current_timestamp, edit_bookmark_without_conflicts,
+        http_client::{anonymous_http_client, http_client_for_account},
+        http_error, is_unreachable_error, not_synced_error, parse_response_timestamp,
+        remove_remote_bookmark, BookmarkProvider,
     },
     utils::json::parse_serde_json_value_to_raw_string,
 };
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use cosmic::iced_core::image::Bytes;
-use reqwest::{
-    header::{HeaderMap, HeaderValue, AUTHORIZATION},
-    ClientBuilder, StatusCode,
+use cosmic::{iced_core::image::Bytes, widget};
+use futures::{
+    channel::mpsc::UnboundedSender,
+    future::{self, BoxFuture},
 };
+use reqwest::{header::HeaderValue, StatusCode, Url};
 use serde_json::Value;
 use std::{
+    collections::HashSet,
     fmt::Write,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
 };
 use urlencoding::encode;
 
-//  NOTE: (vkhitrin) perhaps this method should be split into three:
-//        (1) fetch bookmarks
-//        (2) fetch archived bookmarks
-//        (3) fetch shared
-#[allow(clippy::too_many_lines)]
-#[allow(clippy::if_same_then_else)]
+pub struct LinkdingProvider;
+
+impl BookmarkProvider for LinkdingProvider {
+    fn provider_string(&self) -> &'static str {
+        LINKDING_PROVIDER
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Linkding"
+    }
+
+    fn svg_icon(&self) -> widget::icon::Handle {
+        widget::icon::from_svg_bytes(include_bytes!("../../res/icons/linkding-logo.svg"))
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            archiving: true,
+            description: true,
+            notes: true,
+            sharing: true,
+            snapshots: true,
+            unread: true,
+        }
+    }
+
+    fn authorization(&self, account: &Account) -> Option<String> {
+        Some(format!("Token {}", account.api_token))
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        page_size: u16,
+        modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move {
+            match fetch_bookmarks_for_account(
+                &account,
+                page_size,
+                modified_since.as_deref(),
+                &sender,
+            )
+            .await
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    log::error!("Error fetching linkding bookmarks: {e}");
+                    let epoch_timestamp = current_timestamp();
+                    let mut response = DetailedResponse::new(account, epoch_timestamp, false, None);
+                    response.error = Some(e.to_string());
+                    response
+                }
+            }
+        })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(
+            account,
+            bookmark,
+            check_for_existing,
+            disable_scraping,
+        ))
+    }
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(async move {
+            let mut api_response = BookmarkCheckDetailsResponse::default();
+            edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+            Some(api_response)
+        })
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    fn fetch_account_details(
+        &self,
+        account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(fetch_account_details(account))
+    }
+}
+
+// NOTE: (vkhitrin) linkding exposes owned, archived and shared bookmarks via three separate
+//       endpoints, each one is paginated using `limit`/`offset` query parameters.
+//       The endpoints of owned bookmarks are required for a successful sync, a failure of
+//       the shared endpoint only results in a partial sync.
+const LINKDING_BOOKMARKS_ENDPOINTS: &[(&str, bool)] = &[
+    ("/api/bookmarks/", true),
+    ("/api/bookmarks/archived/", true),
+    ("/api/bookmarks/shared/", false),
+];
+
+// NOTE: (vkhitrin) pages are counted across all endpoints, which are fetched concurrently.
+struct PagesProgress {
+    fetched_pages: AtomicUsize,
+    total_pages: AtomicUsize,
+}
+
+struct EndpointFetch {
+    status: EndpointStatus,
+    error: Option<String>,
+    timestamp: Option<i64>,
+    bookmark_ids: HashSet<i64>,
+    bookmarks: Vec<Bookmark>,
+}
+
+// NOTE: (vkhitrin) pages of owned bookmarks are sent through `sender` as soon as they are
+//       parsed, shared bookmarks are returned as part of the response once every endpoint
+//       was fetched, since linkding also lists the account's own shared bookmarks there.
+//       When `modified_since` is provided, only bookmarks modified after it are fetched.
 pub async fn fetch_bookmarks_for_account(
     account: &Account,
+    page_size: u16,
+    modified_since: Option<&str>,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
 ) -> Result<DetailedResponse, Box<dyn std::error::Error>> {
-    let mut detailed_response = DetailedResponse::new(account.clone(), 0, false, None);
-    let mut bookmarks: Vec<Bookmark> = Vec::new();
-    let mut headers = HeaderMap::new();
-    let rest_api_bookmarks_url: String = account.instance.clone() + "/api/bookmarks/";
-    let rest_api_archived_bookmarks_url: String =
-        account.instance.clone() + "/api/bookmarks/archived/";
-    let rest_api_shared_bookmarks_url: String = account.instance.clone() + "/api/bookmarks/shared/";
-    let http_client = ClientBuilder::new()
-        .danger_accept_invalid_certs(account.trust_invalid_certs)
-        .build()
-        .unwrap();
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Token {}", account.api_token)).unwrap(),
-    );
-    let bookmarks_response: reqwest::Response = http_client
-        .get(rest_api_bookmarks_url)
-        .headers(headers.clone())
-        .send()
-        .await?;
-    // NOTE: (vkhitrin) if no Date header was returned, we will use current time.
-    let bookmarks_parsed_date = bookmarks_response
-        .headers()
-        .get("Date")
-        .cloned()
-        .unwrap_or_else(|| HeaderValue::from_str(&Utc::now().to_rfc2822()).expect(""));
-    if bookmarks_response.status().is_success() {
-        detailed_response.successful = true;
-        let date: DateTime<Utc> =
-            DateTime::parse_from_rfc2822(bookmarks_parsed_date.to_str().unwrap())?
-                .with_timezone(&Utc);
-        let unix_timestamp = SystemTime::from(date).duration_since(UNIX_EPOCH)?.as_secs();
-        detailed_response.timestamp = unix_timestamp as i64;
-        let bookmarks_response_json = bookmarks_response
-            .json::<LinkdingBookmarksApiResponse>()
-            .await;
-        // Handle the Result
-        match bookmarks_response_json {
-            Ok(bookmarks_response) => {
-                for bookmark in bookmarks_response.results {
-                    let transformed_bookmark = Bookmark::new(
-                        account.id,
-                        bookmark.id,
-                        bookmark.url,
-                        bookmark.title,
-                        bookmark.description,
-                        bookmark.website_title.unwrap_or_else(String::new),
-                        bookmark.website_description.unwrap_or_else(String::new),
-                        bookmark.notes,
-                        bookmark.web_archive_snapshot_url,
-                        bookmark.favicon_url.unwrap_or_else(String::new),
-                        bookmark.preview_image_url.unwrap_or_else(String::new),
-                        bookmark.is_archived,
-                        bookmark.unread,
-                        bookmark.shared,
-                        bookmark.tag_names,
-                        bookmark.date_added,
-                        bookmark.date_modified,
-                        Some(true),
-                    );
-                    bookmarks.push(transformed_bookmark);
-                }
+    let http_client = http_client_for_account(account)?;
+    // NOTE: (vkhitrin) we assume a single page per endpoint until the first page of each
+    //       endpoint reports the total count.
+    let progress = PagesProgress {
+        fetched_pages: AtomicUsize::new(0),
+        total_pages: AtomicUsize::new(LINKDING_BOOKMARKS_ENDPOINTS.len()),
+    };
+    let endpoint_fetches = future::join_all(LINKDING_BOOKMARKS_ENDPOINTS.iter().map(
+        |(endpoint, is_owner)| {
+            fetch_bookmarks_from_endpoint(
+                &http_client,
+                account,
+                endpoint,
+                *is_owner,
+                page_size,
+                modified_since,
+                sender,
+                &progress,
+            )
+        },
+    ))
+    .await;
+
+    let mut detailed_response = DetailedResponse::new(account.clone(), 0, true, None);
+    detailed_response.is_full_sync = modified_since.is_none();
+    let mut owned_bookmark_ids: HashSet<i64> = HashSet::new();
+    let mut shared_bookmarks: Vec<Bookmark> = Vec::new();
+    for ((_, is_owner), endpoint_fetch) in LINKDING_BOOKMARKS_ENDPOINTS.iter().zip(endpoint_fetches)
+    {
+        if *is_owner {
+            detailed_response.successful &= endpoint_fetch.status.successful;
+            owned_bookmark_ids.extend(endpoint_fetch.bookmark_ids);
+        } else {
+            shared_bookmarks.extend(endpoint_fetch.bookmarks);
+        }
+        // NOTE: (vkhitrin) the earliest timestamp is used, to avoid skipping bookmarks that
+        //       were modified while other endpoints were fetched.
+        if let Some(timestamp) = endpoint_fetch.timestamp {
+            if detailed_response.timestamp == 0 || timestamp < detailed_response.timestamp {
+                detailed_response.timestamp = timestamp;
             }
+        }
+        if detailed_response.error.is_none() {
+            detailed_response.error = endpoint_fetch.error;
+        }
+        detailed_response
+            .endpoint_statuses
+            .push(endpoint_fetch.status);
+    }
+    if detailed_response.timestamp == 0 {
+        let epoch_timestamp = current_timestamp();
+        detailed_response.timestamp = epoch_timestamp;
+    }
+    shared_bookmarks.retain(|bookmark| {
+        !bookmark
+            .provider_internal_id
+            .is_some_and(|bookmark_id| owned_bookmark_ids.contains(&bookmark_id))
+    });
+    detailed_response.bookmarks = Some(shared_bookmarks);
+    Ok(detailed_response)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn fetch_bookmarks_from_endpoint(
+    http_client: &reqwest::Client,
+    account: &Account,
+    endpoint: &str,
+    is_owner: bool,
+    page_size: u16,
+    modified_since: Option<&str>,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
+    progress: &PagesProgress,
+) -> EndpointFetch {
+    let mut endpoint_fetch = EndpointFetch {
+        status: EndpointStatus::new(endpoint, None, false),
+        error: None,
+        timestamp: None,
+        bookmark_ids: HashSet::new(),
+        bookmarks: Vec::new(),
+    };
+    let endpoint_url = account.instance.clone() + endpoint;
+    let mut page_url = format!("{endpoint_url}?limit={page_size}&offset=0");
+    if let Some(modified_since) = modified_since {
+        let _ = write!(page_url, "&modified_since={}", encode(modified_since));
+    }
+    let mut expected_count: Option<u64> = None;
+    loop {
+        let response: reqwest::Response = match http_client.get(&page_url).send().await {
+            Ok(response) => response,
             Err(e) => {
-                log::error!("Error parsing JSON: {e:?}");
+                log::error!("Error fetching bookmarks from {endpoint}: {e}");
+                endpoint_fetch.error = Some(format!("{endpoint}: {e}"));
+                return endpoint_fetch;
             }
+        };
+        let status = response.status();
+        endpoint_fetch.status.status = Some(status.as_u16());
+        if !status.is_success() {
+            endpoint_fetch.error = Some(format!("{endpoint}: {status}"));
+            log::error!(
+                "HTTP Error while fetching bookmarks from {endpoint} {status:?}:\n{:?}",
+                response.text().await
+            );
+            return endpoint_fetch;
         }
-    } else {
-        log::error!(
-            "HTTP Error while fetching bookmarks {:?}:\n{:?}",
-            bookmarks_response.status(),
-            bookmarks_response.text().await
-        );
-    }
-    tokio::time::sleep(Duration::from_millis(0)).await;
-    let archived_bookmarks_response: reqwest::Response = http_client
-        .get(rest_api_archived_bookmarks_url)
-        .headers(headers.clone())
-        .send()
-        .await?;
-    // NOTE: (vkhitrin) if no Date header was returned, we will use current time.
-    let archived_bookmarks_parsed_date = archived_bookmarks_response
-        .headers()
-        .get("Date")
-        .cloned()
-        .unwrap_or_else(|| HeaderValue::from_str(&Utc::now().to_rfc2822()).expect(""));
-    tokio::time::sleep(Duration::from_millis(0)).await;
-    if archived_bookmarks_response.status().is_success() {
-        detailed_response.successful = true;
-        let date: DateTime<Utc> =
-            DateTime::parse_from_rfc2822(archived_bookmarks_parsed_date.to_str().unwrap())?
-                .with_timezone(&Utc);
-        let unix_timestamp = SystemTime::from(date).duration_since(UNIX_EPOCH)?.as_secs();
-        detailed_response.timestamp = unix_timestamp as i64;
-        let archived_bookmarks_response_json = archived_bookmarks_response
-            .json::<LinkdingBookmarksApiResponse>()
-            .await;
-        // Handle the Result
-        match archived_bookmarks_response_json {
-            Ok(archived_bookmarks_response) => {
-                for bookmark in archived_bookmarks_response.results {
-                    let transformed_bookmark = Bookmark::new(
-                        account.id,
-                        bookmark.id,
-                        bookmark.url,
-                        bookmark.title,
-                        bookmark.description,
-                        bookmark.website_title.unwrap_or_else(String::new),
-                        bookmark.website_description.unwrap_or_else(String::new),
-                        bookmark.notes,
-                        bookmark.web_archive_snapshot_url,
-                        bookmark.favicon_url.unwrap_or_else(String::new),
-                        bookmark.preview_image_url.unwrap_or_else(String::new),
-                        bookmark.is_archived,
-                        bookmark.unread,
-                        bookmark.shared,
-                        bookmark.tag_names,
-                        bookmark.date_added,
-                        bookmark.date_modified,
-                        Some(true),
-                    );
-                    bookmarks.push(transformed_bookmark);
+        if endpoint_fetch.timestamp.is_none() {
+            match parse_response_timestamp(&response) {
+                Ok(timestamp) => endpoint_fetch.timestamp = Some(timestamp),
+                Err(e) => {
+                    endpoint_fetch.error = Some(format!("{endpoint}: {e}"));
+                    log::error!("Error parsing response timestamp: {e}");
+                    return endpoint_fetch;
                 }
             }
+        }
+        let page = match response.json::<LinkdingBookmarksApiResponse>().await {
+            Ok(page) => page,
             Err(e) => {
+                endpoint_fetch.error = Some(format!("{endpoint}: {e}"));
                 log::error!("Error parsing JSON: {e:?}");
+                return endpoint_fetch;
             }
+        };
+        // NOTE: (vkhitrin) bookmarks that are added or removed while fetching shift the
+        //       offsets of the following pages, bookmarks may be skipped.
+        match expected_count {
+            None => {
+                let endpoint_pages = std::cmp::max(
+                    usize::try_from(page.count.div_ceil(u64::from(page_size.max(1))))
+                        .unwrap_or(usize::MAX),
+                    1,
+                );
+                progress
+                    .total_pages
+                    .fetch_add(endpoint_pages - 1, Ordering::Relaxed);
+                expected_count = Some(page.count);
+            }
+            Some(count) if count != page.count => {
+                log::warn!("Bookmarks of {endpoint} were modified while fetching");
+                endpoint_fetch.error = Some(format!(
+                    "{endpoint}: {}",
+                    fl!("bookmarks-modified-during-sync")
+                ));
+                return endpoint_fetch;
+            }
+            Some(_) => {}
         }
-    } else {
-        detailed_response.successful = false;
-        log::error!(
-            "HTTP Error while fetching archived bookmarks {:?}:\n{:?}",
-            archived_bookmarks_response.status(),
-            archived_bookmarks_response.text().await
-        );
-    }
-    tokio::time::sleep(Duration::from_millis(0)).await;
-    let shared_bookmarks_response: reqwest::Response = http_client
-        .get(rest_api_shared_bookmarks_url)
-        .headers(headers)
-        .send()
-        .await?;
-    // NOTE: (vkhitrin) if no Date header was returned, we will use current time.
-    let shared_bookmarks_parsed_date = shared_bookmarks_response
-        .headers()
-        .get("Date")
-        .cloned()
-        .unwrap_or_else(|| HeaderValue::from_str(&Utc::now().to_rfc2822()).expect(""));
-    if shared_bookmarks_response.status().is_success() {
-        detailed_response.successful = true;
-        let date: DateTime<Utc> =
-            DateTime::parse_from_rfc2822(shared_bookmarks_parsed_date.to_str().unwrap())?
-                .with_timezone(&Utc);
-        let unix_timestamp = SystemTime::from(date).duration_since(UNIX_EPOCH)?.as_secs();
-        detailed_response.timestamp = unix_timestamp as i64;
-        let shared_bookmarks_response_json = shared_bookmarks_response
-            .json::<LinkdingBookmarksApiResponse>()
-            .await;
-        // Handle the Result
-        match shared_bookmarks_response_json {
-            Ok(shared_bookmarks_response) => {
-                for bookmark in shared_bookmarks_response.results {
-                    let transformed_bookmark = Bookmark::new(
-                        account.id,
-                        bookmark.id,
-                        bookmark.url,
-                        bookmark.title,
-                        bookmark.description,
-                        bookmark.website_title.unwrap_or_else(String::new),
-                        bookmark.website_description.unwrap_or_else(String::new),
-                        bookmark.notes,
-                        bookmark.web_archive_snapshot_url,
-                        bookmark.favicon_url.unwrap_or_else(String::new),
-                        bookmark.preview_image_url.unwrap_or_else(String::new),
-                        bookmark.is_archived,
-                        bookmark.unread,
-                        bookmark.shared,
-                        bookmark.tag_names,
-                        bookmark.date_added,
-                        bookmark.date_modified,
-                        Some(false),
-                    );
-                    // NOTE: Do not populate bookmarks if they originate from the same
-                    // account based on linkding internal bookmark ID.
-                    // if !bookmarks.contains(&transformed_bookmark) {
-                    if !bookmarks.iter().any(|b| {
-                        b.provider_internal_id == transformed_bookmark.provider_internal_id
-                    }) {
-                        bookmarks.push(transformed_bookmark);
-                    } else if bookmarks.is_empty() {
-                        bookmarks.push(transformed_bookmark);
-                    }
+        let fetched_pages = progress.fetched_pages.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut bookmarks: Vec<Bookmark> = Vec::with_capacity(page.results.len());
+        for bookmark in page.results {
+            // NOTE: Do not populate bookmarks if they originate from the same
+            // account based on linkding internal bookmark ID.
+            if let Some(bookmark_id) = bookmark.id {
+                if !endpoint_fetch.bookmark_ids.insert(bookmark_id) {
+                    continue;
                 }
             }
-            Err(e) => {
-                log::error!("Error parsing JSON: {e:?}");
+            bookmarks.push(transform_api_bookmark(account, bookmark, is_owner));
+        }
+        if is_owner {
+            let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+                account.clone(),
+                bookmarks,
+                fetched_pages,
+                progress.total_pages.load(Ordering::Relaxed),
+            )));
+        } else {
+            endpoint_fetch.bookmarks.extend(bookmarks);
+            let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+                account.clone(),
+                Vec::new(),
+                fetched_pages,
+                progress.total_pages.load(Ordering::Relaxed),
+            )));
+        }
+        match page.next.as_deref().and_then(|next| Url::parse(next).ok()) {
+            // NOTE: (vkhitrin) linkding builds `next` using the host it was reached by,
+            //       which may differ from the configured instance when running behind a
+            //       reverse proxy, only the query is reused.
+            Some(next_url) => {
+                page_url = format!("{endpoint_url}?{}", next_url.query().unwrap_or_default());
             }
+            None => break,
         }
-    } else {
-        detailed_response.successful = false;
-        log::error!(
-            "HTTP Error while fetching archived bookmarks {:?}:\n{:?}",
-            shared_bookmarks_response.status(),
-            shared_bookmarks_response.text().await
-        );
     }
-    detailed_response.bookmarks = Some(bookmarks);
-    Ok(detailed_response)
+    if expected_count.is_some_and(|count| (endpoint_fetch.bookmark_ids.len() as u64) < count) {
+        log::warn!("Bookmarks of {endpoint} were skipped while fetching");
+        endpoint_fetch.error = Some(format!(
+            "{endpoint}: {}",
+            fl!("bookmarks-modified-during-sync")
+        ));
+        return endpoint_fetch;
+    }
+    endpoint_fetch.status.successful = true;
+    endpoint_fetch
+}
+
+fn is_instance_url(account: &Account, url: &str) -> bool {
+    match (Url::parse(&account.instance), Url::parse(url)) {
+        (Ok(instance_url), Ok(url)) => instance_url.origin() == url.origin(),
+        _ => false,
+    }
+}
+
+fn transform_api_bookmark(account: &Account, bookmark: Bookmark, is_owner: bool) -> Bookmark {
+    Bookmark::new(
+        account.id,
+        bookmark.id,
+        bookmark.url,
+        bookmark.title,
+        bookmark.description,
+        bookmark.website_title.unwrap_or_default(),
+        bookmark.website_description.unwrap_or_default(),
+        bookmark.notes,
+        bookmark.web_archive_snapshot_url,
+        bookmark.favicon_url.unwrap_or_default(),
+        bookmark.preview_image_url.unwrap_or_default(),
+        bookmark.is_archived,
+        bookmark.unread,
+        bookmark.shared,
+        bookmark.tag_names,
+        bookmark.date_added,
+        bookmark.date_modified,
+        Some(is_owner),
+    )
 }
 
 #[allow(clippy::too_many_lines)]
@@ -251,16 +406,15 @@ pub async fn populate_bookmark(
     disable_scraping: bool,
 ) -> Option<BookmarkCheckDetailsResponse> {
     let rest_api_url: String = account.instance.clone() + "/api/bookmarks/";
-    let mut headers = HeaderMap::new();
-    let http_client = ClientBuilder::new()
-        .danger_accept_invalid_certs(account.trust_invalid_certs)
-        .build()
-        .unwrap();
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Token {}", account.api_token)).unwrap(),
-    );
     let mut api_response = BookmarkCheckDetailsResponse::default();
+    let http_client = match http_client_for_account(&account) {
+        Ok(http_client) => http_client,
+        Err(e) => {
+            log::error!("Error constructing HTTP client: {e}");
+            api_response.error = Some(e.to_string());
+            return Some(api_response);
+        }
+    };
     let mut transformed_json_value: Value = serde_json::to_value(bookmark.clone()).unwrap();
     if let Some(obj) = transformed_json_value.as_object_mut() {
         obj.remove("id");
@@ -271,6 +425,8 @@ pub async fn populate_bookmark(
         obj.remove("web_archive_snapshot_url");
         obj.remove("favicon_url");
         obj.remove("preview_image_url");
+        // NOTE: (vkhitrin) linkding treats date_added as read-only, bookmarks created
+        //       through the API are always dated by the instance.
         obj.remove("date_added");
         obj.remove("date_modified");
         obj.remove("is_owner");
@@ -317,14 +473,22 @@ pub async fn populate_bookmark(
                             tags
                         } else {
                             Vec::new()
-                        }
+                        };
+                        // NOTE: (vkhitrin) bookmarks merged into an existing bookmark keep
+                        //       their state.
+                        bkmrk.is_archived = bookmark.is_archived;
+                        bkmrk.unread = bookmark.unread;
+                        bkmrk.shared = bookmark.shared;
                     }
                     api_response.bookmark = Some(bkmrk);
                 } else {
                     api_response.is_new = true;
                 }
             }
-            Err(_e) => api_response.error = Some(fl!("failed-to-parse-response")),
+            Err(e) => {
+                api_response.unreachable = is_unreachable_error(e.as_ref());
+                api_response.error = Some(fl!("failed-to-parse-response"));
+            }
         }
     } else {
         api_response.bookmark = Some(bookmark);
@@ -348,7 +512,6 @@ pub async fn populate_bookmark(
 
             let response_result = http_client
                 .post(&rest_api_url)
-                .headers(headers.clone())
                 .json(&transformed_json_value)
                 .send()
                 .await;
@@ -396,6 +559,7 @@ pub async fn populate_bookmark(
                     }
                 },
                 Err(e) => {
+                    api_response.unreachable = is_unreachable_error(&e);
                     api_response.error = Some(format!("Request failed: {e}"));
                     log::error!("Error sending request: {e}");
                     break;
@@ -406,13 +570,22 @@ pub async fn populate_bookmark(
         if retry_count > max_retries && api_response.error.is_none() {
             api_response.error = last_error;
         }
-    } else if let Some(bookmark) = &api_response.bookmark {
-        match edit_bookmark(&account, bookmark).await {
-            Ok(value) => {
-                api_response.bookmark = Some(value);
-                api_response.successful = true;
+    } else if let Some(bookmark) = api_response.bookmark.clone() {
+        // NOTE: (vkhitrin) bookmarks that were found on the instance are up to date, only
+        //       cached bookmarks are checked for conflicts.
+        if check_for_existing {
+            match edit_bookmark(&account, &bookmark).await {
+                Ok(value) => {
+                    api_response.bookmark = Some(value);
+                    api_response.successful = true;
+                }
+                Err(e) => {
+                    api_response.unreachable = is_unreachable_error(e.as_ref());
+                    api_response.error = Some(fl!("failed-to-parse-response"));
+                }
             }
-            Err(_e) => api_response.error = Some(fl!("failed-to-parse-response")),
+        } else {
+            edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
         }
     } else {
         api_response.error = Some(fl!("failed-to-parse-response"));
@@ -420,74 +593,35 @@ pub async fn populate_bookmark(
     Some(api_response)
 }
 
+fn bookmark_url(
+    account: &Account,
+    bookmark: &Bookmark,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let provider_internal_id = bookmark.provider_internal_id.ok_or_else(not_synced_error)?;
+    Ok(format!(
+        "{}/api/bookmarks/{provider_internal_id}/",
+        account.instance
+    ))
+}
+
 pub async fn remove_bookmark(
     account: Account,
     bookmark: Bookmark,
 ) -> Option<BookmarkRemoveResponse> {
-    let mut api_response: BookmarkRemoveResponse = BookmarkRemoveResponse::default();
-    let mut rest_api_url: String = String::new();
-    write!(
-        &mut rest_api_url,
-        "{}/api/bookmarks/{:?}/",
-        account.instance.clone(),
-        bookmark.provider_internal_id.unwrap()
-    )
-    .unwrap();
-    let mut headers = HeaderMap::new();
-    let http_client = ClientBuilder::new()
-        .danger_accept_invalid_certs(account.trust_invalid_certs)
-        .build()
-        .unwrap();
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Token {}", account.api_token)).unwrap(),
-    );
-    let response: reqwest::Response = http_client
-        .delete(rest_api_url)
-        .headers(headers)
-        .send()
-        .await
-        .unwrap();
-    match response.status() {
-        StatusCode::NO_CONTENT => {
-            api_response.successful = true;
-        }
-        status => {
-            api_response.error = Some(fl!(
-                "http-error",
-                http_rc = status.to_string(),
-                http_err = response.text().await.unwrap()
-            ));
-            log::error!(
-                "Error removing bookmark: {}",
-                api_response.error.as_ref().unwrap()
-            );
-        }
-    }
-    Some(api_response)
+    remove_remote_bookmark(|| async {
+        let rest_api_url = bookmark_url(&account, &bookmark)?;
+        let http_client = http_client_for_account(&account)?;
+        Ok(http_client.delete(rest_api_url).send().await?)
+    })
+    .await
 }
 
 pub async fn edit_bookmark(
     account: &Account,
     bookmark: &Bookmark,
 ) -> Result<Bookmark, Box<dyn std::error::Error>> {
-    let mut rest_api_url: String = String::new();
-    write!(
-        &mut rest_api_url,
-        "{}/api/bookmarks/{:?}/",
-        account.instance.clone(),
-        bookmark.provider_internal_id.unwrap()
-    )
-    .unwrap();
-    let mut headers = HeaderMap::new();
-    let http_client = ClientBuilder::new()
-        .danger_accept_invalid_certs(account.trust_invalid_certs)
-        .build()
-        .unwrap();
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Token {}", account.api_token)).unwrap(),
-    );
+    let rest_api_url = bookmark_url(account, bookmark)?;
+    let http_client = http_client_for_account(account)?;
     let mut transformed_json_value: Value = serde_json::to_value(bookmark)?;
     if let Some(obj) = transformed_json_value.as_object_mut() {
         obj.remove("id");
@@ -504,7 +638,6 @@ pub async fn edit_bookmark(
     }
     let response: reqwest::Response = http_client
         .patch(rest_api_url)
-        .headers(headers)
         .json(&transformed_json_value)
         .send()
         .await?;
@@ -524,9 +657,52 @@ pub async fn edit_bookmark(
                 ))))
             }
         },
+        _ => Err(http_error(response).await),
+    }
+}
+
+async fn edit_remote_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+) {
+    edit_bookmark_without_conflicts(
+        bookmark,
+        api_response,
+        || async {
+            let provider_internal_id =
+                bookmark.provider_internal_id.ok_or_else(not_synced_error)?;
+            let remote_bookmark = fetch_bookmark(account, provider_internal_id).await?;
+            Ok((remote_bookmark, ()))
+        },
+        |()| edit_bookmark(account, bookmark),
+    )
+    .await;
+}
+
+pub async fn fetch_bookmark(
+    account: &Account,
+    provider_internal_id: i64,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let mut rest_api_url: String = String::new();
+    write!(
+        &mut rest_api_url,
+        "{}/api/bookmarks/{provider_internal_id}/",
+        account.instance
+    )
+    .unwrap();
+    let http_client = http_client_for_account(account)?;
+    let response: reqwest::Response = http_client.get(rest_api_url).send().await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<Bookmark>().await {
+            Ok(value) => Ok(transform_api_bookmark(account, value, true)),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
         status => {
             let http_rc = status.to_string();
-            let http_err = response.text().await.unwrap();
+            let http_err = response.text().await.unwrap_or_default();
             log::error!("HTTP Error: {http_rc} {http_err}");
             Err(Box::new(std::io::Error::other(fl!(
                 "http-error",
@@ -537,21 +713,18 @@ pub async fn edit_bookmark(
     }
 }
 
-pub async fn fetch_account_details(account: Account) -> Option<LinkdingAccountApiResponse> {
-    let mut account_details: Option<LinkdingAccountApiResponse> =
-        Some(LinkdingAccountApiResponse::default());
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    let mut account_details = AccountDetails::default();
     match check_account_on_instance(&account).await {
-        Ok(mut details) => {
-            details.successful = Some(true);
-            account_details = Some(details);
+        Ok(details) => {
+            account_details.successful = true;
+            account_details.enable_sharing = details.enable_sharing;
+            account_details.enable_public_sharing = details.enable_public_sharing;
+            account_details.version = get_provider_version(&details);
         }
         Err(e) => {
-            account_details.as_mut().unwrap().successful = Some(false);
-            if e.to_string().contains("builder error") {
-                account_details.as_mut().unwrap().error = Some(fl!("provided-url-is-not-valid"));
-            } else {
-                account_details.as_mut().unwrap().error = Some(e.to_string());
-            }
+            account_details.successful = false;
+            account_details.error = Some(account_details_error(&e.to_string()));
 
             log::error!(
                 "Error fetching account {} details: {}",
@@ -560,7 +733,7 @@ pub async fn fetch_account_details(account: Account) -> Option<LinkdingAccountAp
             );
         }
     }
-    account_details
+    Some(account_details)
 }
 
 /// Get provider version for linkding from API response
@@ -573,19 +746,8 @@ pub async fn check_account_on_instance(
 ) -> Result<LinkdingAccountApiResponse, Box<dyn std::error::Error>> {
     let mut rest_api_url: String = String::new();
     write!(&mut rest_api_url, "{}/api/user/profile/", account.instance).unwrap();
-    let mut headers = HeaderMap::new();
-    let http_client = ClientBuilder::new()
-        .danger_accept_invalid_certs(account.trust_invalid_certs)
-        .build()?;
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Token {}", account.api_token)).unwrap(),
-    );
-    let response: reqwest::Response = http_client
-        .get(rest_api_url)
-        .headers(headers)
-        .send()
-        .await?;
+    let http_client = http_client_for_account(account)?;
+    let response: reqwest::Response = http_client.get(rest_api_url).send().await?;
     match response.status() {
         StatusCode::OK => match response.json::<LinkdingAccountApiResponse>().await {
             Ok(value) => Ok(value),
@@ -624,19 +786,8 @@ pub async fn check_bookmark_on_instance(
         )
         .unwrap();
     }
-    let mut headers = HeaderMap::new();
-    let http_client = ClientBuilder::new()
-        .danger_accept_invalid_certs(account.trust_invalid_certs)
-        .build()?;
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Token {}", account.api_token)).unwrap(),
-    );
-    let response: reqwest::Response = http_client
-        .get(rest_api_url)
-        .headers(headers)
-        .send()
-        .await?;
+    let http_client = http_client_for_account(account)?;
+    let response: reqwest::Response = http_client.get(rest_api_url).send().await?;
     match response.status() {
         StatusCode::OK => match response.json::<LinkdingBookmarksApiCheckResponse>().await {
             Ok(value) => Ok(value),
@@ -652,16 +803,26 @@ pub async fn check_bookmark_on_instance(
     }
 }
 
-pub async fn fetch_bookmark_favicon(url: String) -> Bytes {
+// NOTE: (vkhitrin) favicons served by the instance are fetched using the account's client,
+//       the account's headers are never sent to other hosts.
+pub async fn fetch_bookmark_favicon(account: Option<Account>, url: String) -> Bytes {
     let mut bytes: Bytes = Bytes::new();
-    let http_client = ClientBuilder::new()
-        .build()
-        .expect("Failed to construct HTTP client");
-    let response: reqwest::Response = http_client
-        .get(url)
-        .send()
-        .await
-        .expect("Failed fetching favicon");
+    let http_client = match account.filter(|account| is_instance_url(account, &url)) {
+        Some(account) => http_client_for_account(&account)
+            .inspect_err(|e| log::error!("Error constructing HTTP client: {e}"))
+            .ok(),
+        None => anonymous_http_client(),
+    };
+    let Some(http_client) = http_client else {
+        return bytes;
+    };
+    let response: reqwest::Response = match http_client.get(url).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            log::error!("Error fetching favicon: {e}");
+            return bytes;
+        }
+    };
     match response.status() {
         StatusCode::OK => match response.bytes().await {
             Ok(value) => bytes = value,
@@ -676,3 +837,198 @@ pub async fn fetch_bookmark_favicon(url: String) -> Bytes {
     }
     bytes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        let mut account = Account::new(
+            "Linkding".to_string(),
+            "token".to_string(),
+            "https://links.example.com".to_string(),
+            LINKDING_PROVIDER,
+        );
+        account.id = Some(7);
+        account
+    }
+
+    #[test]
+    fn transform_api_bookmark_parses_api_response() {
+        let response: LinkdingBookmarksApiResponse = serde_json::from_value(serde_json::json!({
+            "count": 1,
+            "next": null,
+            "previous": null,
+            "results": [{
+                "id": 42,
+                "url": "https://example.com",
+                "title": "Example",
+                "description": "",
+                "notes": "Read later",
+                "web_archive_snapshot_url": "",
+                "favicon_url": null,
+                "preview_image_url": null,
+                "is_archived": false,
+                "unread": true,
+                "shared": false,
+                "tag_names": ["rust", "cosmic"],
+                "date_added": "2024-01-01T00:00:00Z",
+                "date_modified": "2024-02-01T00:00:00Z",
+                "website_title": "",
+                "website_description": "An example"
+            }]
+        }))
+        .unwrap();
+
+        let bookmark = transform_api_bookmark(
+            &account(),
+            response.results.into_iter().next().unwrap(),
+            false,
+        );
+
+        assert_eq!(bookmark.user_account_id, Some(7));
+        assert_eq!(bookmark.provider_internal_id, Some(42));
+        assert_eq!(bookmark.website_title, None);
+        assert_eq!(bookmark.website_description.as_deref(), Some("An example"));
+        assert_eq!(bookmark.favicon_url, None);
+        assert_eq!(bookmark.tag_names, vec!["rust", "cosmic"]);
+        assert_eq!(
+            bookmark.date_modified.as_deref(),
+            Some("2024-02-01T00:00:00Z")
+        );
+        assert_eq!(bookmark.is_owner, Some(false));
+        assert!(bookmark.unread);
+    }
+
+    #[test]
+    fn is_instance_url_compares_origins() {
+        let account = account();
+        assert!(is_instance_url(
+            &account,
+            "https://links.example.com/static/favicon.png"
+        ));
+        assert!(!is_instance_url(
+            &account,
+            "http://links.example.com/favicon.png"
+        ));
+        assert!(!is_instance_url(
+            &account,
+            "https://example.com/favicon.png"
+        ));
+    }
+
+    fn api_page(count: u64, bookmark_ids: &[i64], next: Option<&str>) -> String {
+        let results: Vec<Value> = bookmark_ids
+            .iter()
+            .map(|bookmark_id| {
+                serde_json::json!({
+                    "id": bookmark_id,
+                    "url": format!("https://example.com/{bookmark_id}"),
+                    "title": "",
+                    "description": "",
+                    "notes": "",
+                    "web_archive_snapshot_url": "",
+                    "is_archived": false,
+                    "unread": false,
+                    "shared": false,
+                    "tag_names": []
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "count": count,
+            "next": next,
+            "previous": null,
+            "results": results
+        })
+        .to_string()
+    }
+
+    // NOTE: (vkhitrin) owned bookmarks are returned in two pages, the second page reports
+    //       `second_page_count` bookmarks in total.
+    async fn fetch_bookmarks(
+        account_id: i64,
+        second_page_count: u64,
+        shared_status: StatusCode,
+    ) -> DetailedResponse {
+        let (instance, _) = crate::provider::tests::serve(move |request_line| {
+            match request_line.split_once(' ').map_or("", |(_, path)| path) {
+                "/api/bookmarks/?limit=2&offset=0" => (
+                    StatusCode::OK,
+                    api_page(
+                        3,
+                        &[1, 2],
+                        Some("https://proxy.example.com/api/bookmarks/?limit=2&offset=2"),
+                    ),
+                ),
+                "/api/bookmarks/?limit=2&offset=2" => {
+                    (StatusCode::OK, api_page(second_page_count, &[3], None))
+                }
+                "/api/bookmarks/shared/?limit=2&offset=0" => {
+                    (shared_status, api_page(2, &[2, 4], None))
+                }
+                _ => (StatusCode::OK, api_page(0, &[], None)),
+            }
+        })
+        .await;
+        let mut account = account();
+        account.id = Some(account_id);
+        account.instance = instance;
+        let (sender, _receiver) = futures::channel::mpsc::unbounded();
+        fetch_bookmarks_for_account(&account, 2, None, &sender)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_bookmarks_for_account_fetches_every_endpoint() {
+        let response = fetch_bookmarks(9101, 3, StatusCode::OK).await;
+
+        assert!(response.successful);
+        assert!(response.is_complete());
+        assert_eq!(
+            response
+                .bookmarks
+                .unwrap()
+                .iter()
+                .map(|bookmark| (bookmark.provider_internal_id, bookmark.is_owner))
+                .collect::<Vec<_>>(),
+            vec![(Some(4), Some(false))]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_bookmarks_for_account_fails_when_pages_shifted() {
+        let response = fetch_bookmarks(9102, 2, StatusCode::OK).await;
+
+        assert!(!response.successful);
+        assert!(!response.is_complete());
+        assert!(response.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn fetch_bookmarks_for_account_is_partial_when_shared_endpoint_fails() {
+        let response = fetch_bookmarks(9103, 3, StatusCode::INTERNAL_SERVER_ERROR).await;
+
+        assert!(response.successful);
+        assert!(!response.is_complete());
+    }
+
+    #[tokio::test]
+    async fn remove_bookmark_rejects_bookmark_that_was_not_synced() {
+        let mut bookmark = transform_api_bookmark(
+            &account(),
+            serde_json::from_str(&api_page(1, &[1], None))
+                .map(|page: LinkdingBookmarksApiResponse| page.results[0].clone())
+                .unwrap(),
+            true,
+        );
+        bookmark.provider_internal_id = None;
+
+        let response = remove_bookmark(account(), bookmark).await.unwrap();
+
+        assert!(!response.successful);
+        assert!(!response.unreachable);
+        assert!(response.error.is_some());
+    }
+}
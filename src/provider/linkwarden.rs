@@ -0,0 +1,614 @@
+use crate::{
+    fl,
+    models::{
+        account::{Account, AccountDetails},
+        bookmarks::{
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksPage,
+            BookmarksSyncEvent, DetailedResponse,
+        },
+        provider::ProviderCapabilities,
+        sync_run::EndpointStatus,
+    },
+    provider::{
+        account_details_error, current_timestamp, edit_bookmark_without_conflicts,
+        http_client::http_client_for_account, http_error, is_unreachable_error, normalize_tag_name,
+        parse_response_timestamp, remove_remote_bookmark, BookmarkProvider, COLLECTION_SEPARATOR,
+    },
+};
+use futures::{channel::mpsc::UnboundedSender, future::BoxFuture};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+const LINKWARDEN_COLLECTIONS_ENDPOINT: &str = "/api/v1/collections";
+const LINKWARDEN_LINKS_ENDPOINT: &str = "/api/v1/links";
+
+pub struct LinkwardenProvider;
+
+impl BookmarkProvider for LinkwardenProvider {
+    fn provider_string(&self) -> &'static str {
+        "linkwarden"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Linkwarden"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            description: true,
+            ..ProviderCapabilities::default()
+        }
+    }
+
+    fn authorization(&self, account: &Account) -> Option<String> {
+        Some(format!("Bearer {}", account.api_token))
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        _page_size: u16,
+        _modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move {
+            match fetch_bookmarks_for_account(&account, &sender).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::error!("Error fetching Linkwarden bookmarks: {e}");
+                    let mut response =
+                        DetailedResponse::new(account, current_timestamp(), false, None);
+                    response.error = Some(e.to_string());
+                    response
+                }
+            }
+        })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        _disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(account, bookmark, check_for_existing))
+    }
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(async move {
+            let mut api_response = BookmarkCheckDetailsResponse::default();
+            edit_link(&account, &bookmark, &mut api_response).await;
+            Some(api_response)
+        })
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    fn fetch_account_details(
+        &self,
+        account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(fetch_account_details(account))
+    }
+}
+
+// NOTE: (vkhitrin) every Linkwarden API response is wrapped in a `response` field.
+#[derive(Debug, Deserialize)]
+struct LinkwardenResponse<T> {
+    response: T,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LinkwardenCollection {
+    id: i64,
+    name: String,
+    #[serde(default)]
+    parent_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct LinkwardenTag {
+    name: String,
+}
+
+// NOTE: (vkhitrin) links of uploaded files (PDFs, images) don't have a URL, they are not
+//       synced.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LinkwardenLink {
+    id: i64,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    collection_id: Option<i64>,
+    #[serde(default)]
+    tags: Vec<LinkwardenTag>,
+    #[serde(default)]
+    created_at: Option<String>,
+    #[serde(default)]
+    updated_at: Option<String>,
+}
+
+// NOTE: (vkhitrin) collections are nested using their parent, each collection is
+//       represented by its path (e.g. `Work/Projects`).
+type CollectionPaths = HashMap<i64, String>;
+
+fn build_collection_paths(collections: &[LinkwardenCollection]) -> CollectionPaths {
+    let collections_by_id: HashMap<i64, &LinkwardenCollection> = collections
+        .iter()
+        .map(|collection| (collection.id, collection))
+        .collect();
+    let mut collection_paths = CollectionPaths::with_capacity(collections.len());
+    for collection in collections {
+        let mut names: Vec<&str> = vec![collection.name.as_str()];
+        let mut visited: HashSet<i64> = HashSet::from([collection.id]);
+        let mut parent_id = collection.parent_id;
+        // NOTE: (vkhitrin) parents that are not accessible to the account (e.g. a collection
+        //       that was shared without its parent) end the path.
+        while let Some(parent) = parent_id.and_then(|id| collections_by_id.get(&id)) {
+            if !visited.insert(parent.id) {
+                break;
+            }
+            names.push(parent.name.as_str());
+            parent_id = parent.parent_id;
+        }
+        names.reverse();
+        collection_paths.insert(collection.id, names.join(&COLLECTION_SEPARATOR.to_string()));
+    }
+    collection_paths
+}
+
+fn transform_link(
+    account: &Account,
+    link: LinkwardenLink,
+    collection_paths: &CollectionPaths,
+) -> Option<Bookmark> {
+    let url = link.url.filter(|url| !url.is_empty())?;
+    let date_modified = link.updated_at.or_else(|| link.created_at.clone());
+    let mut bookmark = Bookmark::new(
+        account.id,
+        Some(link.id),
+        url,
+        link.name,
+        link.description,
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        false,
+        false,
+        false,
+        link.tags
+            .iter()
+            .map(|tag| normalize_tag_name(&tag.name))
+            .collect(),
+        link.created_at,
+        date_modified,
+        Some(true),
+    );
+    bookmark.collection = link
+        .collection_id
+        .and_then(|collection_id| collection_paths.get(&collection_id).cloned());
+    Some(bookmark)
+}
+
+fn parse_link(
+    account: &Account,
+    link: Value,
+    collection_paths: &CollectionPaths,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    serde_json::from_value::<LinkwardenLink>(link)
+        .ok()
+        .and_then(|link| transform_link(account, link, collection_paths))
+        .ok_or_else(|| {
+            Box::new(std::io::Error::other(fl!("failed-to-parse-response")))
+                as Box<dyn std::error::Error>
+        })
+}
+
+async fn fetch_collection_paths(
+    account: &Account,
+) -> Result<CollectionPaths, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!("{}{LINKWARDEN_COLLECTIONS_ENDPOINT}", account.instance);
+    let response = http_client.get(rest_api_url).send().await?;
+    match response.status() {
+        StatusCode::OK => match response
+            .json::<LinkwardenResponse<Vec<LinkwardenCollection>>>()
+            .await
+        {
+            Ok(value) => Ok(build_collection_paths(&value.response)),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-find-linkwarden-api-endpoint"
+            )))),
+        },
+        StatusCode::UNAUTHORIZED => Err(Box::new(std::io::Error::other(fl!("invalid-api-token")))),
+        _ => Err(Box::new(std::io::Error::other(fl!(
+            "unexpected-http-return-code",
+            http_rc = response.status().to_string()
+        )))),
+    }
+}
+
+// NOTE: (vkhitrin) Linkwarden paginates links using a cursor (the ID of the last link of the
+//       previous page) with a page size that is set by the instance, pages are fetched until
+//       an empty page is returned. Links can't be filtered by modification date, every sync
+//       is a full sync.
+pub async fn fetch_bookmarks_for_account(
+    account: &Account,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
+) -> Result<DetailedResponse, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let collection_paths = fetch_collection_paths(account).await?;
+    let mut detailed_response = DetailedResponse::new(account.clone(), 0, false, None);
+    let mut endpoint_status = EndpointStatus::new(LINKWARDEN_LINKS_ENDPOINT, None, false);
+    let mut bookmark_ids: HashSet<i64> = HashSet::new();
+    let mut cursor: Option<i64> = None;
+    let mut fetched_pages: usize = 0;
+    loop {
+        let mut page_url = format!("{}{LINKWARDEN_LINKS_ENDPOINT}?sort=0", account.instance);
+        if let Some(cursor) = cursor {
+            page_url.push_str(&format!("&cursor={cursor}"));
+        }
+        let response = http_client.get(&page_url).send().await?;
+        endpoint_status.status = Some(response.status().as_u16());
+        if response.status() != StatusCode::OK {
+            let error = http_error(response).await;
+            detailed_response.error = Some(format!("{LINKWARDEN_LINKS_ENDPOINT}: {error}"));
+            break;
+        }
+        if detailed_response.timestamp == 0 {
+            detailed_response.timestamp = parse_response_timestamp(&response)?;
+        }
+        let links = match response
+            .json::<LinkwardenResponse<Vec<LinkwardenLink>>>()
+            .await
+        {
+            Ok(page) => page.response,
+            Err(e) => {
+                log::error!("Error parsing JSON: {e:?}");
+                detailed_response.error = Some(format!("{LINKWARDEN_LINKS_ENDPOINT}: {e}"));
+                break;
+            }
+        };
+        // NOTE: (vkhitrin) a page without new links also ends the sync, in case the instance
+        //       ignored the cursor.
+        let next_cursor = links.last().map(|link| link.id);
+        let links: Vec<LinkwardenLink> = links
+            .into_iter()
+            .filter(|link| bookmark_ids.insert(link.id))
+            .collect();
+        if links.is_empty() {
+            endpoint_status.successful = true;
+            detailed_response.successful = true;
+            break;
+        }
+        fetched_pages += 1;
+        let bookmarks: Vec<Bookmark> = links
+            .into_iter()
+            .filter_map(|link| transform_link(account, link, &collection_paths))
+            .collect();
+        // NOTE: (vkhitrin) the total number of pages is unknown, another page is assumed
+        //       until an empty page is returned.
+        let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+            account.clone(),
+            bookmarks,
+            fetched_pages,
+            fetched_pages + 1,
+        )));
+        cursor = next_cursor;
+    }
+    if detailed_response.timestamp == 0 {
+        detailed_response.timestamp = current_timestamp();
+    }
+    detailed_response.endpoint_statuses.push(endpoint_status);
+    detailed_response.bookmarks = Some(Vec::new());
+    Ok(detailed_response)
+}
+
+async fn fetch_link(
+    account: &Account,
+    provider_internal_id: i64,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!(
+        "{}{LINKWARDEN_LINKS_ENDPOINT}/{provider_internal_id}",
+        account.instance
+    );
+    let response = http_client.get(rest_api_url).send().await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<LinkwardenResponse<Value>>().await {
+            Ok(value) => Ok(value.response),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+async fn create_link(
+    account: &Account,
+    bookmark: &Bookmark,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!("{}{LINKWARDEN_LINKS_ENDPOINT}", account.instance);
+    let tags: Vec<LinkwardenTag> = bookmark
+        .tag_names
+        .iter()
+        .map(|tag_name| LinkwardenTag {
+            name: tag_name.clone(),
+        })
+        .collect();
+    // NOTE: (vkhitrin) links created without a collection are added to the account's
+    //       "Unorganized" collection.
+    let response = http_client
+        .post(rest_api_url)
+        .json(&json!({
+            "url": bookmark.url,
+            "name": bookmark.title,
+            "description": bookmark.description,
+            "tags": tags,
+        }))
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => {
+            match response.json::<LinkwardenResponse<Value>>().await {
+                Ok(value) => {
+                    let collection_paths = fetch_collection_paths(account).await?;
+                    parse_link(account, value.response, &collection_paths)
+                }
+                Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                    "failed-to-parse-response"
+                )))),
+            }
+        }
+        _ => Err(http_error(response).await),
+    }
+}
+
+// NOTE: (vkhitrin) Linkwarden replaces the whole link when it is edited, the remote link is
+//       used as the request body (preserving its collection and other fields) with the
+//       modified fields applied on top of it.
+async fn update_link(
+    account: &Account,
+    bookmark: &Bookmark,
+    mut remote_link: Value,
+    collection_paths: &CollectionPaths,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!(
+        "{}{LINKWARDEN_LINKS_ENDPOINT}/{}",
+        account.instance,
+        bookmark.provider_internal_id.unwrap()
+    );
+    let remote_tags: Vec<String> = remote_link
+        .get("tags")
+        .and_then(Value::as_array)
+        .map(|tags| {
+            tags.iter()
+                .filter_map(|tag| tag.get("name").and_then(Value::as_str))
+                .map(std::string::ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let tags: Vec<LinkwardenTag> = bookmark
+        .tag_names
+        .iter()
+        .map(|tag_name| LinkwardenTag {
+            name: remote_tags
+                .iter()
+                .find(|remote_tag| normalize_tag_name(remote_tag) == *tag_name)
+                .unwrap_or(tag_name)
+                .clone(),
+        })
+        .collect();
+    if let Some(obj) = remote_link.as_object_mut() {
+        obj.insert("url".to_string(), json!(bookmark.url));
+        obj.insert("name".to_string(), json!(bookmark.title));
+        obj.insert("description".to_string(), json!(bookmark.description));
+        obj.insert("tags".to_string(), json!(tags));
+    }
+    let response = http_client
+        .put(rest_api_url)
+        .json(&remote_link)
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<LinkwardenResponse<Value>>().await {
+            Ok(value) => parse_link(account, value.response, collection_paths),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+pub async fn populate_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+    check_for_existing: bool,
+) -> Option<BookmarkCheckDetailsResponse> {
+    let mut api_response = BookmarkCheckDetailsResponse::default();
+    if !check_for_existing {
+        edit_link(&account, &bookmark, &mut api_response).await;
+        return Some(api_response);
+    }
+    let creation = create_link(&account, &bookmark)
+        .await
+        .map_err(|e| (is_unreachable_error(e.as_ref()), e.to_string()));
+    match creation {
+        Ok(value) => {
+            api_response.is_new = true;
+            api_response.bookmark = Some(value);
+            api_response.successful = true;
+        }
+        Err((unreachable, e)) => {
+            log::error!("Error adding bookmark: {e}");
+            api_response.unreachable = unreachable;
+            api_response.error = Some(e);
+        }
+    }
+    Some(api_response)
+}
+
+async fn edit_link(
+    account: &Account,
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+) {
+    edit_bookmark_without_conflicts(
+        bookmark,
+        api_response,
+        || async {
+            let remote_link = fetch_link(account, bookmark.provider_internal_id.unwrap()).await?;
+            let collection_paths = fetch_collection_paths(account).await?;
+            let remote_bookmark = parse_link(account, remote_link.clone(), &collection_paths)?;
+            Ok((remote_bookmark, (remote_link, collection_paths)))
+        },
+        |(remote_link, collection_paths)| async move {
+            update_link(account, bookmark, remote_link, &collection_paths).await
+        },
+    )
+    .await;
+}
+
+pub async fn remove_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+) -> Option<BookmarkRemoveResponse> {
+    let rest_api_url = format!(
+        "{}{LINKWARDEN_LINKS_ENDPOINT}/{}",
+        account.instance,
+        bookmark.provider_internal_id.unwrap()
+    );
+    remove_remote_bookmark(|| async {
+        let http_client = http_client_for_account(&account)?;
+        Ok(http_client.delete(rest_api_url).send().await?)
+    })
+    .await
+}
+
+// NOTE: (vkhitrin) the account is validated by fetching its collections.
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    let mut account_details = AccountDetails::default();
+    match fetch_collection_paths(&account).await {
+        Ok(_collection_paths) => {
+            account_details.successful = true;
+        }
+        Err(e) => {
+            account_details.successful = false;
+            account_details.error = Some(account_details_error(&e.to_string()));
+            log::error!(
+                "Error fetching account {} details: {}",
+                account.display_name,
+                e
+            );
+        }
+    }
+    Some(account_details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        let mut account = Account::new(
+            "Linkwarden".to_string(),
+            "token".to_string(),
+            "https://linkwarden.example.com".to_string(),
+            "linkwarden",
+        );
+        account.id = Some(4);
+        account
+    }
+
+    fn collection(id: i64, name: &str, parent_id: Option<i64>) -> LinkwardenCollection {
+        LinkwardenCollection {
+            id,
+            name: name.to_string(),
+            parent_id,
+        }
+    }
+
+    #[test]
+    fn build_collection_paths_joins_parents() {
+        let collection_paths = build_collection_paths(&[
+            collection(1, "Work", None),
+            collection(2, "Projects", Some(1)),
+            collection(3, "Shared", Some(99)),
+            collection(4, "Loop", Some(5)),
+            collection(5, "Cycle", Some(4)),
+        ]);
+
+        assert_eq!(collection_paths[&1], "Work");
+        assert_eq!(collection_paths[&2], "Work/Projects");
+        assert_eq!(collection_paths[&3], "Shared");
+        assert_eq!(collection_paths[&4], "Cycle/Loop");
+    }
+
+    #[test]
+    fn parse_link_transforms_link() {
+        let collection_paths = build_collection_paths(&[
+            collection(1, "Work", None),
+            collection(2, "Projects", Some(1)),
+        ]);
+
+        let bookmark = parse_link(
+            &account(),
+            json!({
+                "id": 21,
+                "name": "Example",
+                "url": "https://example.com",
+                "collectionId": 2,
+                "tags": [{"name": "open source"}],
+                "createdAt": "2024-01-01T00:00:00.000Z",
+                "updatedAt": null
+            }),
+            &collection_paths,
+        )
+        .unwrap();
+
+        assert_eq!(bookmark.user_account_id, Some(4));
+        assert_eq!(bookmark.provider_internal_id, Some(21));
+        assert_eq!(bookmark.tag_names, vec!["open-source"]);
+        assert_eq!(bookmark.collection.as_deref(), Some("Work/Projects"));
+        assert_eq!(
+            bookmark.date_modified.as_deref(),
+            Some("2024-01-01T00:00:00.000Z")
+        );
+    }
+
+    #[test]
+    fn parse_link_rejects_uploaded_files() {
+        assert!(parse_link(
+            &account(),
+            json!({"id": 22, "name": "Document.pdf", "url": null}),
+            &CollectionPaths::new(),
+        )
+        .is_err());
+    }
+}
@@ -1,81 +1,753 @@
 mod cosmicding;
+mod http_client;
+mod karakeep;
 mod linkding;
+mod linkwarden;
+mod nextcloud_bookmarks;
+mod shaarli;
+mod wallabag;
 
-use crate::models::{
-    account::{Account, LinkdingAccountApiResponse},
-    bookmarks::{Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, DetailedResponse},
-    provider::Provider,
-};
-use cosmic::iced_core::image::Bytes;
-use std::time::{SystemTime, UNIX_EPOCH};
-
-pub const ALLOWED_PROVIDERS: &[&str] = &["Linkding"];
-
-pub async fn fetch_bookmarks_for_single_account(account: Account) -> DetailedResponse {
-    match account.provider() {
-        Provider::Cosmicding => cosmicding::fetch_bookmarks_for_account(&account).await,
-        Provider::Linkding => match linkding::fetch_bookmarks_for_account(&account).await {
-            Ok(response) => response,
-            Err(e) => {
-                let epoch_timestamp = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .expect("Time went backwards")
-                    .as_secs();
-                #[allow(clippy::cast_possible_wrap)]
-                let error_response =
-                    DetailedResponse::new(account, epoch_timestamp as i64, false, None);
-                log::error!("Error fetching linkding bookmarks: {e}");
-                error_response
-            }
+use crate::{
+    fl,
+    models::{
+        account::{Account, AccountDetails},
+        bookmarks::{
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksSyncEvent,
+            DetailedResponse,
         },
+        pending_operation::{PendingOperation, PendingOperationKind, PendingOperationResult},
+        provider::{ProviderCapabilities, COSMICDING_PROVIDER},
+    },
+};
+use chrono::{DateTime, Utc};
+use cosmic::{iced_core::image::Bytes, widget};
+use futures::{
+    channel::mpsc::{self, UnboundedSender},
+    future::{self, BoxFuture},
+    stream::{self, Stream, StreamExt},
+};
+use reqwest::{header::HeaderValue, StatusCode};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::LazyLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub use http_client::remove_http_client;
+
+pub trait BookmarkProvider: Send + Sync {
+    // NOTE: (vkhitrin) stored as part of accounts, the registry is keyed by it.
+    fn provider_string(&self) -> &'static str;
+
+    fn display_name(&self) -> &'static str;
+
+    // NOTE: (vkhitrin) providers without a bundled logo use a generic icon.
+    fn svg_icon(&self) -> widget::icon::Handle {
+        widget::icon::from_name("web-browser-symbolic").handle()
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    // NOTE: (vkhitrin) providers that authenticate using a username, the API token is used
+    //       as the password.
+    fn requires_username(&self) -> bool {
+        false
+    }
+
+    // NOTE: (vkhitrin) providers that authenticate using OAuth client credentials in
+    //       addition to a username and a password.
+    fn requires_client_credentials(&self) -> bool {
+        false
+    }
+
+    fn version(&self, account_details: Option<&AccountDetails>) -> Option<String> {
+        account_details.and_then(|details| details.version.clone())
+    }
+
+    // NOTE: (vkhitrin) the `Authorization` header sent with every request of the account,
+    //       providers that sign each request (e.g. Shaarli) set it per request instead.
+    fn authorization(&self, _account: &Account) -> Option<String> {
+        None
+    }
+
+    // NOTE: (vkhitrin) pages may be sent through `sender` while fetching, the returned
+    //       response contains the remaining bookmarks (if any).
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        page_size: u16,
+        modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse>;
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>>;
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>>;
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>>;
+
+    fn fetch_account_details(&self, account: Account)
+        -> BoxFuture<'static, Option<AccountDetails>>;
+}
+
+impl AsRef<str> for dyn BookmarkProvider {
+    fn as_ref(&self) -> &str {
+        self.display_name()
+    }
+}
+
+static PROVIDERS: LazyLock<HashMap<&'static str, &'static dyn BookmarkProvider>> =
+    LazyLock::new(|| {
+        let providers: [&'static dyn BookmarkProvider; 7] = [
+            &cosmicding::CosmicdingProvider,
+            &karakeep::KarakeepProvider,
+            &linkding::LinkdingProvider,
+            &linkwarden::LinkwardenProvider,
+            &nextcloud_bookmarks::NextcloudBookmarksProvider,
+            &shaarli::ShaarliProvider,
+            &wallabag::WallabagProvider,
+        ];
+        providers
+            .into_iter()
+            .map(|bookmark_provider| (bookmark_provider.provider_string(), bookmark_provider))
+            .collect()
+    });
+
+// NOTE: (vkhitrin) remote providers that can be selected when adding an account.
+pub static ALLOWED_PROVIDERS: LazyLock<Vec<&'static dyn BookmarkProvider>> = LazyLock::new(|| {
+    let mut providers: Vec<&'static dyn BookmarkProvider> = PROVIDERS
+        .values()
+        .copied()
+        .filter(|bookmark_provider| bookmark_provider.provider_string() != COSMICDING_PROVIDER)
+        .collect();
+    providers.sort_by_key(|bookmark_provider| bookmark_provider.display_name());
+    providers
+});
+
+pub fn get_provider(provider_string: &str) -> Option<&'static dyn BookmarkProvider> {
+    PROVIDERS.get(provider_string).copied()
+}
+
+fn provider_for_account(account: &Account) -> Option<&'static dyn BookmarkProvider> {
+    let bookmark_provider = get_provider(&account.provider_string);
+    if bookmark_provider.is_none() {
+        log::error!(
+            "Unsupported provider '{}' for account {}",
+            account.provider_string,
+            account.display_name
+        );
+    }
+    bookmark_provider
+}
+
+// NOTE: (vkhitrin) connection failures and timeouts indicate that the instance could not be
+//       reached, as opposed to the instance rejecting the request.
+fn is_unreachable_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout())
+}
+
+fn parse_response_timestamp(
+    response: &reqwest::Response,
+) -> Result<i64, Box<dyn std::error::Error>> {
+    // NOTE: (vkhitrin) if no Date header was returned, we will use current time.
+    let parsed_date = response
+        .headers()
+        .get("Date")
+        .cloned()
+        .unwrap_or_else(|| HeaderValue::from_str(&Utc::now().to_rfc2822()).expect(""));
+    let date: DateTime<Utc> =
+        DateTime::parse_from_rfc2822(parsed_date.to_str()?)?.with_timezone(&Utc);
+    let unix_timestamp = SystemTime::from(date).duration_since(UNIX_EPOCH)?.as_secs();
+    #[allow(clippy::cast_possible_wrap)]
+    Ok(unix_timestamp as i64)
+}
+
+pub fn current_timestamp() -> i64 {
+    #[allow(clippy::cast_possible_wrap)]
+    let epoch_timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    epoch_timestamp
+}
+
+async fn http_error(response: reqwest::Response) -> Box<dyn std::error::Error> {
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Box::new(std::io::Error::other(fl!("invalid-api-token")));
+    }
+    let http_rc = status.to_string();
+    let http_err = response.text().await.unwrap_or_default();
+    log::error!("HTTP Error: {http_rc} {http_err}");
+    Box::new(std::io::Error::other(fl!(
+        "http-error",
+        http_rc = http_rc,
+        http_err = http_err
+    )))
+}
+
+// NOTE: (vkhitrin) separates the names of nested collections (e.g. `Work/Projects`).
+const COLLECTION_SEPARATOR: char = '/';
+
+// NOTE: (vkhitrin) tags are stored separated by spaces, spaces within remote tags are
+//       replaced. The original names are restored when the bookmark is edited.
+fn normalize_tag_name(tag_name: &str) -> String {
+    tag_name.trim().replace(' ', "-")
+}
+
+// NOTE: (vkhitrin) flags without an equivalent field (e.g. favourites) are represented using
+//       reserved tags, which start with a single `RESERVED_TAG_PREFIX`. The prefix is doubled
+//       in remote tags starting with it, they can't be mistaken for reserved tags.
+const RESERVED_TAG_PREFIX: char = '!';
+
+fn normalize_unreserved_tag_name(tag_name: &str) -> String {
+    let tag_name = normalize_tag_name(tag_name);
+    if tag_name.starts_with(RESERVED_TAG_PREFIX) {
+        format!("{RESERVED_TAG_PREFIX}{tag_name}")
+    } else {
+        tag_name
+    }
+}
+
+// NOTE: (vkhitrin) the name of a new remote tag, the doubled prefix is removed.
+fn unescape_tag_name(tag_name: &str) -> &str {
+    tag_name
+        .strip_prefix(RESERVED_TAG_PREFIX)
+        .filter(|unescaped| unescaped.starts_with(RESERVED_TAG_PREFIX))
+        .unwrap_or(tag_name)
+}
+
+// NOTE: (vkhitrin) `fetch` returns the remote bookmark along with the remote value required
+//       by the provider to submit the edit. The remote bookmark is returned (as an error) if
+//       it was modified since it was cached.
+async fn find_conflicting_bookmark<R, Fetch, FetchFuture>(
+    bookmark: &Bookmark,
+    fetch: Fetch,
+) -> Result<Result<R, Bookmark>, Box<dyn std::error::Error>>
+where
+    Fetch: FnOnce() -> FetchFuture,
+    FetchFuture: Future<Output = Result<(Bookmark, R), Box<dyn std::error::Error>>>,
+{
+    let (remote_bookmark, remote_value) = fetch().await?;
+    if bookmark
+        .date_modified
+        .as_ref()
+        .is_some_and(|date_modified| remote_bookmark.date_modified.as_ref() != Some(date_modified))
+    {
+        return Ok(Err(remote_bookmark));
+    }
+    Ok(Ok(remote_value))
+}
+
+// NOTE: (vkhitrin) providers don't support conditional requests, the remote bookmark is
+//       fetched and its modification date is compared against the cached bookmark before
+//       `update` submits the edit.
+async fn edit_bookmark_without_conflicts<R, Fetch, FetchFuture, Update, UpdateFuture>(
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+    fetch: Fetch,
+    update: Update,
+) where
+    Fetch: FnOnce() -> FetchFuture,
+    FetchFuture: Future<Output = Result<(Bookmark, R), Box<dyn std::error::Error>>>,
+    Update: FnOnce(R) -> UpdateFuture,
+    UpdateFuture: Future<Output = Result<Bookmark, Box<dyn std::error::Error>>>,
+{
+    let result = async {
+        let conflict = find_conflicting_bookmark(bookmark, fetch).await?;
+        match conflict {
+            Ok(remote_value) => Ok(Ok(update(remote_value).await?)),
+            Err(remote_bookmark) => Ok(Err(remote_bookmark)),
+        }
+    }
+    .await
+    .map_err(|e: Box<dyn std::error::Error>| (is_unreachable_error(e.as_ref()), e.to_string()));
+    match result {
+        Ok(Err(remote_bookmark)) => {
+            log::warn!(
+                "Bookmark {} was modified remotely since it was cached",
+                bookmark.url
+            );
+            api_response.bookmark = Some(bookmark.clone());
+            api_response.conflict = Some(remote_bookmark);
+        }
+        Ok(Ok(value)) => {
+            api_response.bookmark = Some(value);
+            api_response.successful = true;
+        }
+        Err((unreachable, e)) => {
+            api_response.unreachable = unreachable;
+            api_response.error = Some(e);
+        }
+    }
+}
+
+// NOTE: (vkhitrin) `request` sends the removal request of the provider.
+async fn remove_remote_bookmark<Request, RequestFuture>(
+    request: Request,
+) -> Option<BookmarkRemoveResponse>
+where
+    Request: FnOnce() -> RequestFuture,
+    RequestFuture: Future<Output = Result<reqwest::Response, Box<dyn std::error::Error>>>,
+{
+    let mut api_response: BookmarkRemoveResponse = BookmarkRemoveResponse::default();
+    let result = request()
+        .await
+        .map_err(|e| (is_unreachable_error(e.as_ref()), e.to_string()));
+    let response = match result {
+        Ok(response) => response,
+        Err((unreachable, e)) => {
+            api_response.unreachable = unreachable;
+            api_response.error = Some(format!("Request failed: {e}"));
+            log::error!("Error removing bookmark: {e}");
+            return Some(api_response);
+        }
+    };
+    match response.status() {
+        StatusCode::OK | StatusCode::NO_CONTENT => {
+            api_response.successful = true;
+        }
+        _ => {
+            let error = http_error(response).await;
+            log::error!("Error removing bookmark: {error}");
+            api_response.error = Some(error.to_string());
+        }
+    }
+    Some(api_response)
+}
+
+// NOTE: (vkhitrin) bookmarks that were not created remotely yet have no identifier.
+fn not_synced_error() -> Box<dyn std::error::Error> {
+    Box::new(std::io::Error::other(fl!("bookmark-not-synced")))
+}
+
+// NOTE: (vkhitrin) reqwest reports malformed instance URLs as builder errors.
+fn account_details_error(error: &str) -> String {
+    if error.contains("builder error") {
+        fl!("provided-url-is-not-valid")
+    } else {
+        error.to_string()
     }
 }
 
+fn unsupported_provider_error(account: &Account) -> String {
+    fl!(
+        "unsupported-provider",
+        provider = account.provider_string.clone()
+    )
+}
+
+// NOTE: (vkhitrin) the stream yields every fetched page, followed by a single
+//       `BookmarksSyncEvent::Finished` event once the provider is done.
+pub fn fetch_bookmarks_for_single_account(
+    account: Account,
+    page_size: u16,
+) -> impl Stream<Item = BookmarksSyncEvent> {
+    let (sender, receiver) = mpsc::unbounded();
+    let fetch = async move {
+        let epoch_timestamp = current_timestamp();
+        let modified_since = account.delta_sync_cursor(epoch_timestamp).map(String::from);
+        let mut response = match provider_for_account(&account) {
+            Some(bookmark_provider) => {
+                bookmark_provider
+                    .fetch_bookmarks(account, page_size, modified_since, sender.clone())
+                    .await
+            }
+            None => {
+                let mut response = DetailedResponse::new(account, epoch_timestamp, false, None);
+                response.error = Some(unsupported_provider_error(&response.account));
+                response
+            }
+        };
+        response.start_timestamp = epoch_timestamp;
+        let _ = sender.unbounded_send(BookmarksSyncEvent::Finished(response));
+    };
+    stream::select(receiver.map(Some), stream::once(fetch).map(|()| None)).filter_map(future::ready)
+}
+
 pub async fn populate_bookmark(
     account: Account,
     bookmark: Bookmark,
     check_for_existing: bool,
     disable_scraping: bool,
 ) -> Option<BookmarkCheckDetailsResponse> {
-    match account.provider() {
-        Provider::Cosmicding => {
-            cosmicding::populate_bookmark(account, bookmark, check_for_existing, disable_scraping)
-                .await
+    match provider_for_account(&account) {
+        Some(bookmark_provider) => {
+            let response = bookmark_provider
+                .populate_bookmark(
+                    account,
+                    bookmark.clone(),
+                    check_for_existing,
+                    disable_scraping,
+                )
+                .await;
+            with_unreachable_bookmark(response, bookmark)
         }
-        Provider::Linkding => {
-            linkding::populate_bookmark(account, bookmark, check_for_existing, disable_scraping)
-                .await
+        None => Some(BookmarkCheckDetailsResponse {
+            error: Some(unsupported_provider_error(&account)),
+            ..Default::default()
+        }),
+    }
+}
+
+pub async fn edit_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+) -> Option<BookmarkCheckDetailsResponse> {
+    match provider_for_account(&account) {
+        Some(bookmark_provider) => {
+            let response = bookmark_provider
+                .edit_bookmark(account, bookmark.clone())
+                .await;
+            with_unreachable_bookmark(response, bookmark)
         }
+        None => Some(BookmarkCheckDetailsResponse {
+            error: Some(unsupported_provider_error(&account)),
+            ..Default::default()
+        }),
     }
 }
 
+// NOTE: (vkhitrin) responses of unreachable providers carry the requested bookmark, this
+//       allows queueing the operation for later.
+fn with_unreachable_bookmark(
+    mut response: Option<BookmarkCheckDetailsResponse>,
+    bookmark: Bookmark,
+) -> Option<BookmarkCheckDetailsResponse> {
+    if let Some(ref mut details) = response {
+        if details.unreachable && details.bookmark.is_none() {
+            details.bookmark = Some(bookmark);
+        }
+    }
+    response
+}
+
 pub async fn remove_bookmark(
     account: Account,
     bookmark: Bookmark,
 ) -> Option<BookmarkRemoveResponse> {
-    match account.provider() {
-        Provider::Cosmicding => cosmicding::remove_bookmark(account, bookmark).await,
-        Provider::Linkding => linkding::remove_bookmark(account, bookmark).await,
+    match provider_for_account(&account) {
+        Some(bookmark_provider) => bookmark_provider.remove_bookmark(account, bookmark).await,
+        None => Some(BookmarkRemoveResponse {
+            error: Some(unsupported_provider_error(&account)),
+            ..Default::default()
+        }),
     }
 }
 
-pub async fn fetch_account_details(account: Account) -> Option<LinkdingAccountApiResponse> {
-    match account.provider() {
-        Provider::Cosmicding => None, // Local provider has no remote account details
-        Provider::Linkding => linkding::fetch_account_details(account).await,
+// NOTE: (vkhitrin) operations are replayed in order, replaying stops at the first operation
+//       that could not reach the provider, the remaining operations are kept for later.
+pub async fn replay_pending_operations(
+    account: Account,
+    operations: Vec<PendingOperation>,
+) -> Vec<PendingOperationResult> {
+    let mut results: Vec<PendingOperationResult> = Vec::with_capacity(operations.len());
+    for operation in operations {
+        let (bookmark, conflict, error, unreachable) = match operation.kind {
+            PendingOperationKind::Add | PendingOperationKind::Edit => {
+                let response = if operation.kind == PendingOperationKind::Add {
+                    populate_bookmark(account.clone(), operation.bookmark.clone(), true, true).await
+                } else {
+                    edit_bookmark(account.clone(), operation.bookmark.clone()).await
+                };
+                match response {
+                    Some(details) => (
+                        details.bookmark,
+                        details.conflict,
+                        details.error,
+                        details.unreachable,
+                    ),
+                    None => (None, None, Some(fl!("failed-to-parse-response")), false),
+                }
+            }
+            PendingOperationKind::Remove => {
+                match remove_bookmark(account.clone(), operation.bookmark.clone()).await {
+                    Some(details) => (None, None, details.error, details.unreachable),
+                    None => (None, None, Some(fl!("failed-to-parse-response")), false),
+                }
+            }
+        };
+        results.push(PendingOperationResult {
+            operation,
+            bookmark,
+            conflict,
+            error,
+            unreachable,
+        });
+        if unreachable {
+            break;
+        }
     }
+    results
 }
 
-pub async fn fetch_bookmark_favicon(url: String) -> Bytes {
-    linkding::fetch_bookmark_favicon(url).await
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    match provider_for_account(&account) {
+        Some(bookmark_provider) => bookmark_provider.fetch_account_details(account).await,
+        None => Some(AccountDetails {
+            error: Some(unsupported_provider_error(&account)),
+            successful: false,
+            ..Default::default()
+        }),
+    }
+}
+
+pub async fn fetch_bookmark_favicon(account: Option<Account>, url: String) -> Bytes {
+    linkding::fetch_bookmark_favicon(account, url).await
 }
 
 pub fn get_provider_version(
-    provider: Provider,
-    api_response: Option<&LinkdingAccountApiResponse>,
+    account: &Account,
+    account_details: Option<&AccountDetails>,
 ) -> Option<String> {
-    match provider {
-        Provider::Cosmicding => Some(cosmicding::get_provider_version()),
-        Provider::Linkding => api_response.and_then(linkding::get_provider_version),
+    provider_for_account(account)
+        .and_then(|bookmark_provider| bookmark_provider.version(account_details))
+}
+
+pub fn capabilities(account: &Account) -> ProviderCapabilities {
+    get_provider(&account.provider_string)
+        .map(BookmarkProvider::capabilities)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn providers_are_registered_by_provider_string() {
+        for (provider_string, bookmark_provider) in PROVIDERS.iter() {
+            assert_eq!(bookmark_provider.provider_string(), *provider_string);
+        }
+        assert_eq!(PROVIDERS.len(), 7);
+        assert!(get_provider(COSMICDING_PROVIDER).is_some());
+        assert!(get_provider("unknown").is_none());
+    }
+
+    #[test]
+    fn allowed_providers_exclude_local_provider() {
+        assert_eq!(ALLOWED_PROVIDERS.len(), PROVIDERS.len() - 1);
+        assert!(ALLOWED_PROVIDERS
+            .iter()
+            .all(|bookmark_provider| bookmark_provider.provider_string() != COSMICDING_PROVIDER));
+        assert!(ALLOWED_PROVIDERS
+            .windows(2)
+            .all(|providers| providers[0].display_name() <= providers[1].display_name()));
+    }
+
+    #[test]
+    fn capabilities_of_unknown_provider_are_empty() {
+        let account = Account::new(
+            "Unknown".to_string(),
+            String::new(),
+            String::new(),
+            "unknown",
+        );
+        assert_eq!(capabilities(&account), ProviderCapabilities::default());
+    }
+
+    fn linkding_account(account_id: i64, instance: String) -> Account {
+        let mut account = Account::new(
+            "Linkding".to_string(),
+            "token".to_string(),
+            instance,
+            crate::models::provider::LINKDING_PROVIDER,
+        );
+        account.id = Some(account_id);
+        account
+    }
+
+    fn operation(kind: PendingOperationKind, provider_internal_id: i64) -> PendingOperation {
+        let mut bookmark = Bookmark::new(
+            Some(1),
+            Some(provider_internal_id),
+            format!("https://example.com/{provider_internal_id}"),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            false,
+            false,
+            false,
+            Vec::new(),
+            None,
+            None,
+            Some(true),
+        );
+        bookmark.id = Some(provider_internal_id);
+        PendingOperation::new(1, kind, bookmark, 1)
+    }
+
+    // NOTE: (vkhitrin) responds to every request using `respond`, the request lines (method
+    //       and path) are recorded.
+    pub(super) async fn serve<Respond>(respond: Respond) -> (String, Arc<Mutex<Vec<String>>>)
+    where
+        Respond: Fn(&str) -> (StatusCode, String) + Send + 'static,
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let instance = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded_requests = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 8192];
+                let read = stream.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]);
+                let request_line = request.lines().next().unwrap_or_default();
+                let request_line = request_line
+                    .rsplit_once(' ')
+                    .map_or(request_line, |(request_line, _)| request_line);
+                recorded_requests
+                    .lock()
+                    .unwrap()
+                    .push(request_line.to_string());
+                let (status, body) = respond(request_line);
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (instance, requests)
+    }
+
+    #[tokio::test]
+    async fn replay_pending_operations_keeps_order() {
+        let (instance, requests) = serve(|_| (StatusCode::NOT_FOUND, String::new())).await;
+        let account = linkding_account(9001, instance);
+        let operations = vec![
+            operation(PendingOperationKind::Remove, 1),
+            operation(PendingOperationKind::Edit, 2),
+            operation(PendingOperationKind::Remove, 3),
+        ];
+
+        let results = replay_pending_operations(account, operations).await;
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| (
+                    result.operation.bookmark.provider_internal_id,
+                    result.unreachable
+                ))
+                .collect::<Vec<_>>(),
+            vec![(Some(1), false), (Some(2), false), (Some(3), false)]
+        );
+        assert!(results.iter().all(|result| result.error.is_some()));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "DELETE /api/bookmarks/1/",
+                "GET /api/bookmarks/2/",
+                "DELETE /api/bookmarks/3/",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_pending_operations_stops_when_unreachable() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let instance = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let account = linkding_account(9002, instance);
+        let operations = vec![
+            operation(PendingOperationKind::Remove, 1),
+            operation(PendingOperationKind::Remove, 2),
+        ];
+
+        let results = replay_pending_operations(account, operations).await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].unreachable);
+        assert_eq!(results[0].operation.bookmark.provider_internal_id, Some(1));
+    }
+
+    fn remote_bookmark(date_modified: Option<&str>) -> Bookmark {
+        let mut bookmark = operation(PendingOperationKind::Edit, 1).bookmark;
+        bookmark.date_modified = date_modified.map(ToString::to_string);
+        bookmark
+    }
+
+    #[tokio::test]
+    async fn edit_bookmark_without_conflicts_updates_unmodified_bookmark() {
+        let bookmark = remote_bookmark(Some("2024-01-01T00:00:00Z"));
+        let mut api_response = BookmarkCheckDetailsResponse::default();
+
+        edit_bookmark_without_conflicts(
+            &bookmark,
+            &mut api_response,
+            || async { Ok((bookmark.clone(), "etag")) },
+            |remote_value| async move {
+                assert_eq!(remote_value, "etag");
+                Ok(remote_bookmark(Some("2024-02-01T00:00:00Z")))
+            },
+        )
+        .await;
+
+        assert!(api_response.successful);
+        assert!(api_response.conflict.is_none());
+        assert_eq!(
+            api_response.bookmark.unwrap().date_modified.as_deref(),
+            Some("2024-02-01T00:00:00Z")
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_bookmark_without_conflicts_returns_modified_bookmark() {
+        let bookmark = remote_bookmark(Some("2024-01-01T00:00:00Z"));
+        let mut api_response = BookmarkCheckDetailsResponse::default();
+
+        edit_bookmark_without_conflicts(
+            &bookmark,
+            &mut api_response,
+            || async { Ok((remote_bookmark(Some("2024-02-01T00:00:00Z")), ())) },
+            |()| async { panic!("a conflicting bookmark must not be updated") },
+        )
+        .await;
+
+        assert!(!api_response.successful);
+        assert_eq!(api_response.bookmark, Some(bookmark));
+        assert_eq!(
+            api_response.conflict.unwrap().date_modified.as_deref(),
+            Some("2024-02-01T00:00:00Z")
+        );
+    }
+
+    #[tokio::test]
+    async fn find_conflicting_bookmark_ignores_bookmark_without_modification_date() {
+        let bookmark = remote_bookmark(None);
+
+        let conflict = find_conflicting_bookmark(&bookmark, || async {
+            Ok((remote_bookmark(Some("2024-02-01T00:00:00Z")), ()))
+        })
+        .await
+        .unwrap();
+
+        assert!(conflict.is_ok());
     }
 }
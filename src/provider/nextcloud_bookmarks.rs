@@ -0,0 +1,628 @@
+use crate::{
+    fl,
+    models::{
+        account::{Account, AccountDetails},
+        bookmarks::{
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksPage,
+            BookmarksSyncEvent, DetailedResponse,
+        },
+        provider::ProviderCapabilities,
+        sync_run::EndpointStatus,
+    },
+    provider::{
+        account_details_error, current_timestamp, edit_bookmark_without_conflicts,
+        http_client::http_client_for_account, http_error, is_unreachable_error, normalize_tag_name,
+        parse_response_timestamp, remove_remote_bookmark, BookmarkProvider, COLLECTION_SEPARATOR,
+    },
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, SecondsFormat};
+use futures::{channel::mpsc::UnboundedSender, future::BoxFuture};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use urlencoding::encode;
+
+const NEXTCLOUD_BOOKMARKS_API_PATH: &str = "/index.php/apps/bookmarks/public/rest/v2";
+const NEXTCLOUD_BOOKMARKS_ENDPOINT: &str = "/bookmark";
+const NEXTCLOUD_FOLDERS_ENDPOINT: &str = "/folder";
+
+pub struct NextcloudBookmarksProvider;
+
+impl BookmarkProvider for NextcloudBookmarksProvider {
+    fn provider_string(&self) -> &'static str {
+        "nextcloud-bookmarks"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Nextcloud Bookmarks"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            description: true,
+            ..ProviderCapabilities::default()
+        }
+    }
+
+    fn requires_username(&self) -> bool {
+        true
+    }
+
+    // NOTE: (vkhitrin) Nextcloud authenticates API requests using the username and an app
+    //       password (stored as the API token).
+    fn authorization(&self, account: &Account) -> Option<String> {
+        let credentials = format!(
+            "{}:{}",
+            account.username.as_deref().unwrap_or_default(),
+            account.api_token
+        );
+        Some(format!("Basic {}", BASE64.encode(credentials)))
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        page_size: u16,
+        _modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move {
+            match fetch_bookmarks_for_account(&account, page_size, &sender).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::error!("Error fetching Nextcloud bookmarks: {e}");
+                    let mut response =
+                        DetailedResponse::new(account, current_timestamp(), false, None);
+                    response.error = Some(e.to_string());
+                    response
+                }
+            }
+        })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        _disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(account, bookmark, check_for_existing))
+    }
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(async move {
+            let mut api_response = BookmarkCheckDetailsResponse::default();
+            edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+            Some(api_response)
+        })
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    fn fetch_account_details(
+        &self,
+        account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(fetch_account_details(account))
+    }
+}
+
+// NOTE: (vkhitrin) lists are returned in a `data` field, single items in an `item` field.
+#[derive(Debug, Deserialize)]
+struct NextcloudDataResponse<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct NextcloudItemResponse<T> {
+    item: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct NextcloudFolder {
+    id: i64,
+    title: String,
+    #[serde(default)]
+    children: Vec<NextcloudFolder>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NextcloudBookmark {
+    id: i64,
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    folders: Vec<i64>,
+    #[serde(default)]
+    added: Option<i64>,
+    #[serde(default)]
+    lastmodified: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct NextcloudBookmarkRequest<'a> {
+    url: &'a str,
+    title: &'a str,
+    description: &'a str,
+    tags: Vec<String>,
+}
+
+// NOTE: (vkhitrin) folders are nested under the root folder, each folder is represented
+//       by its path (e.g. `Work/Projects`). Bookmarks in the root folder don't have a path.
+type FolderPaths = HashMap<i64, String>;
+
+fn build_folder_paths(
+    folders: &[NextcloudFolder],
+    parent_path: Option<&str>,
+    paths: &mut FolderPaths,
+) {
+    for folder in folders {
+        let path = match parent_path {
+            Some(parent_path) => format!("{parent_path}{COLLECTION_SEPARATOR}{}", folder.title),
+            None => folder.title.clone(),
+        };
+        build_folder_paths(&folder.children, Some(&path), paths);
+        paths.insert(folder.id, path);
+    }
+}
+
+fn format_timestamp(timestamp: Option<i64>) -> Option<String> {
+    timestamp
+        .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+        .map(|date| date.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+fn api_url(account: &Account, endpoint: &str) -> String {
+    format!(
+        "{}{NEXTCLOUD_BOOKMARKS_API_PATH}{endpoint}",
+        account.instance
+    )
+}
+
+// NOTE: (vkhitrin) a bookmark may be part of multiple folders, the first folder is used.
+fn transform_bookmark(
+    account: &Account,
+    bookmark: NextcloudBookmark,
+    folder_paths: &FolderPaths,
+) -> Bookmark {
+    let date_added = format_timestamp(bookmark.added);
+    let date_modified = format_timestamp(bookmark.lastmodified).or_else(|| date_added.clone());
+    let mut transformed_bookmark = Bookmark::new(
+        account.id,
+        Some(bookmark.id),
+        bookmark.url,
+        bookmark.title,
+        bookmark.description,
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        false,
+        false,
+        false,
+        bookmark
+            .tags
+            .iter()
+            .map(|tag| normalize_tag_name(tag))
+            .collect(),
+        date_added,
+        date_modified,
+        Some(true),
+    );
+    transformed_bookmark.collection = bookmark
+        .folders
+        .iter()
+        .find_map(|folder_id| folder_paths.get(folder_id).cloned());
+    transformed_bookmark
+}
+
+async fn parse_response<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, Box<dyn std::error::Error>> {
+    match response.status() {
+        StatusCode::OK => match response.json::<T>().await {
+            Ok(value) => Ok(value),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+async fn fetch_folder_paths(account: &Account) -> Result<FolderPaths, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let response = http_client
+        .get(api_url(account, NEXTCLOUD_FOLDERS_ENDPOINT))
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => match response
+            .json::<NextcloudDataResponse<Vec<NextcloudFolder>>>()
+            .await
+        {
+            Ok(value) => {
+                let mut folder_paths = FolderPaths::new();
+                build_folder_paths(&value.data, None, &mut folder_paths);
+                Ok(folder_paths)
+            }
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-find-nextcloud-bookmarks-api-endpoint"
+            )))),
+        },
+        StatusCode::UNAUTHORIZED => Err(Box::new(std::io::Error::other(fl!("invalid-api-token")))),
+        _ => Err(Box::new(std::io::Error::other(fl!(
+            "unexpected-http-return-code",
+            http_rc = response.status().to_string()
+        )))),
+    }
+}
+
+// NOTE: (vkhitrin) Nextcloud paginates bookmarks using `page`/`limit` query parameters,
+//       pages are fetched until a page with fewer bookmarks than the limit is returned.
+//       Bookmarks can't be filtered by modification date, every sync is a full sync.
+pub async fn fetch_bookmarks_for_account(
+    account: &Account,
+    page_size: u16,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
+) -> Result<DetailedResponse, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let folder_paths = fetch_folder_paths(account).await?;
+    let page_size = page_size.max(1);
+    let endpoint_url = api_url(account, NEXTCLOUD_BOOKMARKS_ENDPOINT);
+    let mut detailed_response = DetailedResponse::new(account.clone(), 0, false, None);
+    let mut endpoint_status = EndpointStatus::new(NEXTCLOUD_BOOKMARKS_ENDPOINT, None, false);
+    let mut bookmark_ids: HashSet<i64> = HashSet::new();
+    let mut page: usize = 0;
+    loop {
+        let page_url = format!("{endpoint_url}?page={page}&limit={page_size}");
+        let response = http_client.get(&page_url).send().await?;
+        endpoint_status.status = Some(response.status().as_u16());
+        if response.status() != StatusCode::OK {
+            let error = http_error(response).await;
+            detailed_response.error = Some(format!("{NEXTCLOUD_BOOKMARKS_ENDPOINT}: {error}"));
+            break;
+        }
+        if detailed_response.timestamp == 0 {
+            detailed_response.timestamp = parse_response_timestamp(&response)?;
+        }
+        let nextcloud_bookmarks = match response
+            .json::<NextcloudDataResponse<Vec<NextcloudBookmark>>>()
+            .await
+        {
+            Ok(value) => value.data,
+            Err(e) => {
+                log::error!("Error parsing JSON: {e:?}");
+                detailed_response.error = Some(format!("{NEXTCLOUD_BOOKMARKS_ENDPOINT}: {e}"));
+                break;
+            }
+        };
+        page += 1;
+        let is_last_page = nextcloud_bookmarks.len() < usize::from(page_size);
+        let bookmarks: Vec<Bookmark> = nextcloud_bookmarks
+            .into_iter()
+            .filter(|bookmark| bookmark_ids.insert(bookmark.id))
+            .map(|bookmark| transform_bookmark(account, bookmark, &folder_paths))
+            .collect();
+        // NOTE: (vkhitrin) the total number of pages is unknown, another page is assumed
+        //       until the last page is returned.
+        let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+            account.clone(),
+            bookmarks,
+            page,
+            if is_last_page { page } else { page + 1 },
+        )));
+        if is_last_page {
+            endpoint_status.successful = true;
+            detailed_response.successful = true;
+            break;
+        }
+    }
+    if detailed_response.timestamp == 0 {
+        detailed_response.timestamp = current_timestamp();
+    }
+    detailed_response.endpoint_statuses.push(endpoint_status);
+    detailed_response.bookmarks = Some(Vec::new());
+    Ok(detailed_response)
+}
+
+async fn fetch_remote_bookmark(
+    account: &Account,
+    provider_internal_id: i64,
+) -> Result<NextcloudBookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let response = http_client
+        .get(api_url(
+            account,
+            &format!("{NEXTCLOUD_BOOKMARKS_ENDPOINT}/{provider_internal_id}"),
+        ))
+        .send()
+        .await?;
+    Ok(
+        parse_response::<NextcloudItemResponse<NextcloudBookmark>>(response)
+            .await?
+            .item,
+    )
+}
+
+async fn find_bookmark_by_url(
+    account: &Account,
+    url: &str,
+) -> Result<Option<NextcloudBookmark>, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let response = http_client
+        .get(format!(
+            "{}?url={}",
+            api_url(account, NEXTCLOUD_BOOKMARKS_ENDPOINT),
+            encode(url)
+        ))
+        .send()
+        .await?;
+    Ok(
+        parse_response::<NextcloudDataResponse<Vec<NextcloudBookmark>>>(response)
+            .await?
+            .data
+            .into_iter()
+            .next(),
+    )
+}
+
+// NOTE: (vkhitrin) bookmarks created without a folder are added to the root folder.
+async fn create_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let response = http_client
+        .post(api_url(account, NEXTCLOUD_BOOKMARKS_ENDPOINT))
+        .json(&NextcloudBookmarkRequest {
+            url: &bookmark.url,
+            title: &bookmark.title,
+            description: &bookmark.description,
+            tags: bookmark.tag_names.clone(),
+        })
+        .send()
+        .await?;
+    let created_bookmark = parse_response::<NextcloudItemResponse<NextcloudBookmark>>(response)
+        .await?
+        .item;
+    let folder_paths = fetch_folder_paths(account).await?;
+    Ok(transform_bookmark(account, created_bookmark, &folder_paths))
+}
+
+// NOTE: (vkhitrin) folders are not part of the request, the bookmark remains in its
+//       folders.
+async fn update_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+    remote_tags: &[String],
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let tags: Vec<String> = bookmark
+        .tag_names
+        .iter()
+        .map(|tag_name| {
+            remote_tags
+                .iter()
+                .find(|remote_tag| normalize_tag_name(remote_tag) == *tag_name)
+                .unwrap_or(tag_name)
+                .clone()
+        })
+        .collect();
+    let response = http_client
+        .put(api_url(
+            account,
+            &format!(
+                "{NEXTCLOUD_BOOKMARKS_ENDPOINT}/{}",
+                bookmark.provider_internal_id.unwrap()
+            ),
+        ))
+        .json(&NextcloudBookmarkRequest {
+            url: &bookmark.url,
+            title: &bookmark.title,
+            description: &bookmark.description,
+            tags,
+        })
+        .send()
+        .await?;
+    let updated_bookmark = parse_response::<NextcloudItemResponse<NextcloudBookmark>>(response)
+        .await?
+        .item;
+    let folder_paths = fetch_folder_paths(account).await?;
+    Ok(transform_bookmark(account, updated_bookmark, &folder_paths))
+}
+
+pub async fn populate_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+    check_for_existing: bool,
+) -> Option<BookmarkCheckDetailsResponse> {
+    let mut api_response = BookmarkCheckDetailsResponse::default();
+    if !check_for_existing {
+        edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+        return Some(api_response);
+    }
+    let result = populate_remote_bookmark(&account, bookmark)
+        .await
+        .map_err(|e| (is_unreachable_error(e.as_ref()), e.to_string()));
+    match result {
+        Ok((value, is_new)) => {
+            api_response.is_new = is_new;
+            api_response.bookmark = Some(value);
+            api_response.successful = true;
+        }
+        Err((unreachable, e)) => {
+            log::error!("Error adding bookmark: {e}");
+            api_response.unreachable = unreachable;
+            api_response.error = Some(e);
+        }
+    }
+    Some(api_response)
+}
+
+// NOTE: (vkhitrin) returns the bookmark and whether it was created.
+async fn populate_remote_bookmark(
+    account: &Account,
+    mut bookmark: Bookmark,
+) -> Result<(Bookmark, bool), Box<dyn std::error::Error>> {
+    let existing_bookmark = find_bookmark_by_url(account, &bookmark.url).await?;
+    match existing_bookmark {
+        Some(existing_bookmark) => {
+            bookmark.provider_internal_id = Some(existing_bookmark.id);
+            if bookmark.title.is_empty() {
+                bookmark.title = existing_bookmark.title;
+            }
+            if bookmark.description.is_empty() {
+                bookmark.description = existing_bookmark.description;
+            }
+            Ok((
+                update_bookmark(account, &bookmark, &existing_bookmark.tags).await?,
+                false,
+            ))
+        }
+        None => Ok((create_bookmark(account, &bookmark).await?, true)),
+    }
+}
+
+async fn edit_remote_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+) {
+    edit_bookmark_without_conflicts(
+        bookmark,
+        api_response,
+        || async {
+            let remote_bookmark =
+                fetch_remote_bookmark(account, bookmark.provider_internal_id.unwrap()).await?;
+            let remote_tags = remote_bookmark.tags.clone();
+            let folder_paths = fetch_folder_paths(account).await?;
+            Ok((
+                transform_bookmark(account, remote_bookmark, &folder_paths),
+                remote_tags,
+            ))
+        },
+        |remote_tags| async move { update_bookmark(account, bookmark, &remote_tags).await },
+    )
+    .await;
+}
+
+pub async fn remove_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+) -> Option<BookmarkRemoveResponse> {
+    let rest_api_url = api_url(
+        &account,
+        &format!(
+            "{NEXTCLOUD_BOOKMARKS_ENDPOINT}/{}",
+            bookmark.provider_internal_id.unwrap()
+        ),
+    );
+    remove_remote_bookmark(|| async {
+        let http_client = http_client_for_account(&account)?;
+        Ok(http_client.delete(rest_api_url).send().await?)
+    })
+    .await
+}
+
+// NOTE: (vkhitrin) the account is validated by fetching its folders.
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    let mut account_details = AccountDetails::default();
+    match fetch_folder_paths(&account).await {
+        Ok(_folder_paths) => {
+            account_details.successful = true;
+        }
+        Err(e) => {
+            account_details.successful = false;
+            account_details.error = Some(account_details_error(&e.to_string()));
+            log::error!(
+                "Error fetching account {} details: {}",
+                account.display_name,
+                e
+            );
+        }
+    }
+    Some(account_details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        let mut account = Account::new(
+            "Nextcloud".to_string(),
+            "password".to_string(),
+            "https://cloud.example.com".to_string(),
+            "nextcloud_bookmarks",
+        );
+        account.id = Some(5);
+        account
+    }
+
+    fn folder_paths() -> FolderPaths {
+        let folders: Vec<NextcloudFolder> = serde_json::from_value(serde_json::json!([
+            {"id": 1, "title": "Work", "children": [{"id": 2, "title": "Projects"}]},
+            {"id": 3, "title": "Personal"}
+        ]))
+        .unwrap();
+        let mut folder_paths = FolderPaths::new();
+        build_folder_paths(&folders, None, &mut folder_paths);
+        folder_paths
+    }
+
+    #[test]
+    fn build_folder_paths_joins_parents() {
+        let folder_paths = folder_paths();
+
+        assert_eq!(folder_paths[&1], "Work");
+        assert_eq!(folder_paths[&2], "Work/Projects");
+        assert_eq!(folder_paths[&3], "Personal");
+    }
+
+    #[test]
+    fn transform_bookmark_parses_api_response() {
+        let response: NextcloudItemResponse<NextcloudBookmark> =
+            serde_json::from_value(serde_json::json!({
+                "status": "success",
+                "item": {
+                    "id": 31,
+                    "url": "https://example.com",
+                    "title": "Example",
+                    "tags": ["open source"],
+                    "folders": [-1, 2],
+                    "added": 1_704_067_200,
+                    "lastmodified": null
+                }
+            }))
+            .unwrap();
+
+        let bookmark = transform_bookmark(&account(), response.item, &folder_paths());
+
+        assert_eq!(bookmark.user_account_id, Some(5));
+        assert_eq!(bookmark.provider_internal_id, Some(31));
+        assert_eq!(bookmark.tag_names, vec!["open-source"]);
+        assert_eq!(bookmark.collection.as_deref(), Some("Work/Projects"));
+        assert_eq!(bookmark.date_added.as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert_eq!(bookmark.date_modified, bookmark.date_added);
+    }
+}
@@ -0,0 +1,543 @@
+use crate::{
+    fl,
+    models::{
+        account::{Account, AccountDetails},
+        bookmarks::{
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksPage,
+            BookmarksSyncEvent, DetailedResponse,
+        },
+        provider::ProviderCapabilities,
+        sync_run::EndpointStatus,
+    },
+    provider::{
+        account_details_error, current_timestamp, edit_bookmark_without_conflicts,
+        http_client::http_client_for_account, http_error, is_unreachable_error,
+        parse_response_timestamp, remove_remote_bookmark, BookmarkProvider,
+    },
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL, Engine};
+use futures::{channel::mpsc::UnboundedSender, future::BoxFuture};
+use hmac::{Hmac, Mac};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use std::collections::HashSet;
+
+const SHAARLI_INFO_ENDPOINT: &str = "/api/v1/info";
+const SHAARLI_LINKS_ENDPOINT: &str = "/api/v1/links";
+
+pub struct ShaarliProvider;
+
+impl BookmarkProvider for ShaarliProvider {
+    fn provider_string(&self) -> &'static str {
+        "shaarli"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Shaarli"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            archiving: false,
+            description: true,
+            notes: false,
+            sharing: true,
+            snapshots: false,
+            unread: false,
+        }
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        page_size: u16,
+        _modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move {
+            match fetch_bookmarks_for_account(&account, page_size, &sender).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::error!("Error fetching Shaarli bookmarks: {e}");
+                    let mut response =
+                        DetailedResponse::new(account, current_timestamp(), false, None);
+                    response.error = Some(e.to_string());
+                    response
+                }
+            }
+        })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        _disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(account, bookmark, check_for_existing))
+    }
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(async move {
+            let mut api_response = BookmarkCheckDetailsResponse::default();
+            edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+            Some(api_response)
+        })
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    fn fetch_account_details(
+        &self,
+        account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(fetch_account_details(account))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ShaarliLink {
+    id: i64,
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    private: bool,
+    #[serde(default)]
+    created: String,
+    // NOTE: (vkhitrin) Shaarli returns an empty string for links that were never updated.
+    #[serde(default)]
+    updated: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ShaarliLinkRequest<'a> {
+    url: &'a str,
+    title: &'a str,
+    description: &'a str,
+    tags: &'a [String],
+    private: bool,
+}
+
+impl<'a> ShaarliLinkRequest<'a> {
+    fn new(bookmark: &'a Bookmark) -> Self {
+        Self {
+            url: &bookmark.url,
+            title: &bookmark.title,
+            description: &bookmark.description,
+            tags: &bookmark.tag_names,
+            private: !bookmark.shared,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ShaarliInfo {
+    global_counter: u64,
+}
+
+// NOTE: (vkhitrin) Shaarli authenticates every request using a JWT signed (HS512) with the
+//       instance's API secret, tokens are only valid for a few minutes since they were
+//       issued, a new token is signed for each request.
+fn sign_jwt(api_secret: &str) -> String {
+    let header = BASE64_URL.encode(r#"{"typ":"JWT","alg":"HS512"}"#);
+    let payload = BASE64_URL.encode(format!(r#"{{"iat":{}}}"#, current_timestamp()));
+    let mut mac = Hmac::<Sha512>::new_from_slice(api_secret.as_bytes())
+        .expect("HMAC can take key of any size");
+    mac.update(format!("{header}.{payload}").as_bytes());
+    let signature = BASE64_URL.encode(mac.finalize().into_bytes());
+    format!("{header}.{payload}.{signature}")
+}
+
+fn authorized(request: RequestBuilder, account: &Account) -> RequestBuilder {
+    request.bearer_auth(sign_jwt(&account.api_token))
+}
+
+fn transform_link(account: &Account, link: ShaarliLink) -> Bookmark {
+    let date_modified = if link.updated.is_empty() {
+        link.created.clone()
+    } else {
+        link.updated
+    };
+    Bookmark::new(
+        account.id,
+        Some(link.id),
+        link.url,
+        link.title,
+        link.description,
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        false,
+        false,
+        !link.private,
+        link.tags,
+        Some(link.created),
+        Some(date_modified),
+        Some(true),
+    )
+}
+
+async fn parse_link(
+    account: &Account,
+    response: reqwest::Response,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    match response.json::<ShaarliLink>().await {
+        Ok(link) => Ok(transform_link(account, link)),
+        Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+            "failed-to-parse-response"
+        )))),
+    }
+}
+
+// NOTE: (vkhitrin) Shaarli doesn't support filtering links by modification date, every
+//       sync is a full sync of the links (both public and private) using `offset`/`limit`.
+pub async fn fetch_bookmarks_for_account(
+    account: &Account,
+    page_size: u16,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
+) -> Result<DetailedResponse, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let info = fetch_info(account).await?;
+    let page_size = page_size.max(1);
+    let total_pages = std::cmp::max(
+        usize::try_from(info.global_counter.div_ceil(u64::from(page_size))).unwrap_or(usize::MAX),
+        1,
+    );
+    let mut detailed_response = DetailedResponse::new(account.clone(), 0, false, None);
+    let mut endpoint_status = EndpointStatus::new(SHAARLI_LINKS_ENDPOINT, None, false);
+    let mut bookmark_ids: HashSet<i64> = HashSet::new();
+    let mut offset: usize = 0;
+    let mut fetched_pages: usize = 0;
+    loop {
+        let page_url = format!(
+            "{}{SHAARLI_LINKS_ENDPOINT}?offset={offset}&limit={page_size}&visibility=all",
+            account.instance
+        );
+        let response = authorized(http_client.get(&page_url), account)
+            .send()
+            .await?;
+        endpoint_status.status = Some(response.status().as_u16());
+        if response.status() != StatusCode::OK {
+            let error = http_error(response).await;
+            detailed_response.error = Some(format!("{SHAARLI_LINKS_ENDPOINT}: {error}"));
+            break;
+        }
+        if detailed_response.timestamp == 0 {
+            detailed_response.timestamp = parse_response_timestamp(&response)?;
+        }
+        let links = match response.json::<Vec<ShaarliLink>>().await {
+            Ok(links) => links,
+            Err(e) => {
+                log::error!("Error parsing JSON: {e:?}");
+                detailed_response.error = Some(format!("{SHAARLI_LINKS_ENDPOINT}: {e}"));
+                break;
+            }
+        };
+        fetched_pages += 1;
+        let is_last_page = links.len() < usize::from(page_size);
+        offset += links.len();
+        let bookmarks: Vec<Bookmark> = links
+            .into_iter()
+            .filter(|link| bookmark_ids.insert(link.id))
+            .map(|link| transform_link(account, link))
+            .collect();
+        let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+            account.clone(),
+            bookmarks,
+            fetched_pages,
+            std::cmp::max(total_pages, fetched_pages),
+        )));
+        if is_last_page {
+            endpoint_status.successful = true;
+            detailed_response.successful = true;
+            break;
+        }
+    }
+    if detailed_response.timestamp == 0 {
+        detailed_response.timestamp = current_timestamp();
+    }
+    detailed_response.endpoint_statuses.push(endpoint_status);
+    detailed_response.bookmarks = Some(Vec::new());
+    Ok(detailed_response)
+}
+
+async fn fetch_info(account: &Account) -> Result<ShaarliInfo, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!("{}{SHAARLI_INFO_ENDPOINT}", account.instance);
+    let response = authorized(http_client.get(rest_api_url), account)
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<ShaarliInfo>().await {
+            Ok(value) => Ok(value),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-find-shaarli-api-endpoint"
+            )))),
+        },
+        StatusCode::UNAUTHORIZED => Err(Box::new(std::io::Error::other(fl!("invalid-api-token")))),
+        _ => Err(Box::new(std::io::Error::other(fl!(
+            "unexpected-http-return-code",
+            http_rc = response.status().to_string()
+        )))),
+    }
+}
+
+enum LinkCreation {
+    Created(Bookmark),
+    Exists(Bookmark),
+}
+
+async fn create_link(
+    account: &Account,
+    bookmark: &Bookmark,
+) -> Result<LinkCreation, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!("{}{SHAARLI_LINKS_ENDPOINT}", account.instance);
+    let response = authorized(http_client.post(rest_api_url), account)
+        .json(&ShaarliLinkRequest::new(bookmark))
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::CREATED => Ok(LinkCreation::Created(parse_link(account, response).await?)),
+        // NOTE: (vkhitrin) Shaarli responds with the existing link when its URL was already
+        //       bookmarked.
+        StatusCode::CONFLICT => Ok(LinkCreation::Exists(parse_link(account, response).await?)),
+        _ => Err(http_error(response).await),
+    }
+}
+
+pub async fn fetch_bookmark(
+    account: &Account,
+    provider_internal_id: i64,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!(
+        "{}{SHAARLI_LINKS_ENDPOINT}/{provider_internal_id}",
+        account.instance
+    );
+    let response = authorized(http_client.get(rest_api_url), account)
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => parse_link(account, response).await,
+        _ => Err(http_error(response).await),
+    }
+}
+
+pub async fn edit_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let rest_api_url = format!(
+        "{}{SHAARLI_LINKS_ENDPOINT}/{}",
+        account.instance,
+        bookmark.provider_internal_id.unwrap()
+    );
+    let response = authorized(http_client.put(rest_api_url), account)
+        .json(&ShaarliLinkRequest::new(bookmark))
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => parse_link(account, response).await,
+        _ => Err(http_error(response).await),
+    }
+}
+
+pub async fn populate_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+    check_for_existing: bool,
+) -> Option<BookmarkCheckDetailsResponse> {
+    let mut api_response = BookmarkCheckDetailsResponse::default();
+    if !check_for_existing {
+        edit_remote_bookmark(&account, &bookmark, &mut api_response).await;
+        return Some(api_response);
+    }
+    let creation = create_link(&account, &bookmark)
+        .await
+        .map_err(|e| (is_unreachable_error(e.as_ref()), e.to_string()));
+    match creation {
+        Ok(LinkCreation::Created(value)) => {
+            api_response.is_new = true;
+            api_response.bookmark = Some(value);
+            api_response.successful = true;
+        }
+        Ok(LinkCreation::Exists(existing_bookmark)) => {
+            let mut bkmrk = bookmark;
+            bkmrk.provider_internal_id = existing_bookmark.provider_internal_id;
+            if bkmrk.title.is_empty() {
+                bkmrk.title = existing_bookmark.title;
+            }
+            if bkmrk.description.is_empty() {
+                bkmrk.description = existing_bookmark.description;
+            }
+            match edit_bookmark(&account, &bkmrk).await {
+                Ok(value) => {
+                    api_response.bookmark = Some(value);
+                    api_response.successful = true;
+                }
+                Err(e) => {
+                    api_response.unreachable = is_unreachable_error(e.as_ref());
+                    api_response.error = Some(e.to_string());
+                }
+            }
+        }
+        Err((unreachable, e)) => {
+            log::error!("Error adding bookmark: {e}");
+            api_response.unreachable = unreachable;
+            api_response.error = Some(e);
+        }
+    }
+    Some(api_response)
+}
+
+async fn edit_remote_bookmark(
+    account: &Account,
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+) {
+    edit_bookmark_without_conflicts(
+        bookmark,
+        api_response,
+        || async {
+            let remote_bookmark =
+                fetch_bookmark(account, bookmark.provider_internal_id.unwrap()).await?;
+            Ok((remote_bookmark, ()))
+        },
+        |()| edit_bookmark(account, bookmark),
+    )
+    .await;
+}
+
+pub async fn remove_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+) -> Option<BookmarkRemoveResponse> {
+    let rest_api_url = format!(
+        "{}{SHAARLI_LINKS_ENDPOINT}/{}",
+        account.instance,
+        bookmark.provider_internal_id.unwrap()
+    );
+    remove_remote_bookmark(|| async {
+        let http_client = http_client_for_account(&account)?;
+        Ok(authorized(http_client.delete(rest_api_url), &account)
+            .send()
+            .await?)
+    })
+    .await
+}
+
+// NOTE: (vkhitrin) Shaarli has no user profile, the account is validated by fetching the
+//       instance's information. Links are either public or private, which is represented
+//       as sharing.
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    let mut account_details = AccountDetails::default();
+    match fetch_info(&account).await {
+        Ok(_info) => {
+            account_details.successful = true;
+            account_details.enable_sharing = true;
+            account_details.enable_public_sharing = true;
+        }
+        Err(e) => {
+            account_details.successful = false;
+            account_details.error = Some(account_details_error(&e.to_string()));
+            log::error!(
+                "Error fetching account {} details: {}",
+                account.display_name,
+                e
+            );
+        }
+    }
+    Some(account_details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        let mut account = Account::new(
+            "Shaarli".to_string(),
+            "secret".to_string(),
+            "https://shaarli.example.com".to_string(),
+            "shaarli",
+        );
+        account.id = Some(3);
+        account
+    }
+
+    fn link(updated: &str) -> ShaarliLink {
+        serde_json::from_value(serde_json::json!({
+            "id": 12,
+            "url": "https://example.com",
+            "title": "Example",
+            "tags": ["rust"],
+            "private": true,
+            "created": "2024-01-01T00:00:00+00:00",
+            "updated": updated
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn transform_link_uses_updated_date() {
+        let bookmark = transform_link(&account(), link("2024-02-01T00:00:00+00:00"));
+
+        assert_eq!(bookmark.user_account_id, Some(3));
+        assert_eq!(bookmark.provider_internal_id, Some(12));
+        assert_eq!(bookmark.description, "");
+        assert_eq!(bookmark.tag_names, vec!["rust"]);
+        assert!(!bookmark.shared);
+        assert_eq!(
+            bookmark.date_modified.as_deref(),
+            Some("2024-02-01T00:00:00+00:00")
+        );
+    }
+
+    #[test]
+    fn transform_link_falls_back_to_created_date() {
+        let bookmark = transform_link(&account(), link(""));
+
+        assert_eq!(
+            bookmark.date_modified.as_deref(),
+            Some("2024-01-01T00:00:00+00:00")
+        );
+    }
+
+    #[test]
+    fn sign_jwt_signs_header_and_payload() {
+        let token = sign_jwt("secret");
+        let (signed, signature) = token.rsplit_once('.').unwrap();
+        let (header, _) = signed.split_once('.').unwrap();
+
+        assert_eq!(
+            BASE64_URL.decode(header).unwrap(),
+            br#"{"typ":"JWT","alg":"HS512"}"#
+        );
+        let mut mac = Hmac::<Sha512>::new_from_slice(b"secret").unwrap();
+        mac.update(signed.as_bytes());
+        mac.verify_slice(&BASE64_URL.decode(signature).unwrap())
+            .unwrap();
+    }
+}
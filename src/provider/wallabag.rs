@@ -0,0 +1,887 @@
+use crate::{
+    fl,
+    models::{
+        account::{Account, AccountDetails},
+        bookmarks::{
+            Bookmark, BookmarkCheckDetailsResponse, BookmarkRemoveResponse, BookmarksPage,
+            BookmarksSyncEvent, DetailedResponse,
+        },
+        provider::ProviderCapabilities,
+        sync_run::EndpointStatus,
+    },
+    provider::{
+        account_details_error, current_timestamp, edit_bookmark_without_conflicts,
+        http_client::http_client_for_account, http_error, is_unreachable_error,
+        normalize_unreserved_tag_name, not_synced_error, parse_response_timestamp,
+        remove_remote_bookmark, unescape_tag_name, BookmarkProvider,
+    },
+};
+use chrono::{DateTime, SecondsFormat};
+use futures::{channel::mpsc::UnboundedSender, future::BoxFuture};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, Mutex},
+};
+use urlencoding::encode;
+
+const WALLABAG_TOKEN_ENDPOINT: &str = "/oauth/v2/token";
+const WALLABAG_ENTRIES_ENDPOINT: &str = "/api/entries";
+const WALLABAG_USER_ENDPOINT: &str = "/api/user.json";
+// NOTE: (vkhitrin) tokens are refreshed slightly before they expire, to avoid using a token
+//       that expires while the request is in flight.
+const TOKEN_EXPIRY_MARGIN_SECONDS: i64 = 60;
+// NOTE: (vkhitrin) Wallabag entries can be starred, there is no equivalent field in
+//       cosmicding, starred entries are tagged using a reserved tag instead.
+const STARRED_TAG: &str = "!starred";
+
+// NOTE: (vkhitrin) access tokens are short-lived, they are cached (in memory) per set of
+//       credentials and refreshed using the refresh token when they expire.
+static ACCESS_TOKENS: LazyLock<Mutex<HashMap<String, AccessToken>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub struct WallabagProvider;
+
+impl BookmarkProvider for WallabagProvider {
+    fn provider_string(&self) -> &'static str {
+        "wallabag"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Wallabag"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            archiving: true,
+            unread: true,
+            ..ProviderCapabilities::default()
+        }
+    }
+
+    fn requires_username(&self) -> bool {
+        true
+    }
+
+    fn requires_client_credentials(&self) -> bool {
+        true
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        account: Account,
+        page_size: u16,
+        _modified_since: Option<String>,
+        sender: UnboundedSender<BookmarksSyncEvent>,
+    ) -> BoxFuture<'static, DetailedResponse> {
+        Box::pin(async move {
+            match fetch_bookmarks_for_account(&account, page_size, &sender).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::error!("Error fetching Wallabag entries: {e}");
+                    let mut response =
+                        DetailedResponse::new(account, current_timestamp(), false, None);
+                    response.error = Some(e.to_string());
+                    response
+                }
+            }
+        })
+    }
+
+    fn populate_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+        check_for_existing: bool,
+        _disable_scraping: bool,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(populate_bookmark(account, bookmark, check_for_existing))
+    }
+
+    fn edit_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkCheckDetailsResponse>> {
+        Box::pin(async move {
+            let mut api_response = BookmarkCheckDetailsResponse::default();
+            edit_remote_entry(&account, &bookmark, &mut api_response).await;
+            Some(api_response)
+        })
+    }
+
+    fn remove_bookmark(
+        &self,
+        account: Account,
+        bookmark: Bookmark,
+    ) -> BoxFuture<'static, Option<BookmarkRemoveResponse>> {
+        Box::pin(remove_bookmark(account, bookmark))
+    }
+
+    fn fetch_account_details(
+        &self,
+        account: Account,
+    ) -> BoxFuture<'static, Option<AccountDetails>> {
+        Box::pin(fetch_account_details(account))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AccessToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct WallabagTokenResponse {
+    access_token: String,
+    expires_in: i64,
+    refresh_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WallabagEntriesResponse {
+    pages: usize,
+    total: usize,
+    #[serde(rename = "_embedded")]
+    embedded: WallabagEmbeddedEntries,
+}
+
+#[derive(Debug, Deserialize)]
+struct WallabagEmbeddedEntries {
+    items: Vec<WallabagEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct WallabagEntry {
+    id: i64,
+    url: String,
+    title: Option<String>,
+    #[serde(deserialize_with = "deserialize_flag")]
+    is_archived: bool,
+    #[serde(deserialize_with = "deserialize_flag")]
+    is_starred: bool,
+    #[serde(default)]
+    tags: Vec<WallabagTag>,
+    #[serde(default)]
+    reading_time: i64,
+    created_at: String,
+    updated_at: String,
+    preview_picture: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct WallabagTag {
+    id: i64,
+    label: String,
+}
+
+// NOTE: (vkhitrin) flags are returned as integers (`0`/`1`), booleans are accepted as well.
+fn deserialize_flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Bool(value) => value,
+        Value::Number(value) => value.as_i64().is_some_and(|value| value != 0),
+        _ => false,
+    })
+}
+
+fn token_key(account: &Account) -> String {
+    let mut hasher = Sha256::new();
+    for value in [
+        account.instance.as_str(),
+        account.username.as_deref().unwrap_or_default(),
+        account.api_token.as_str(),
+        account.client_id.as_deref().unwrap_or_default(),
+        account.client_secret.as_str(),
+    ] {
+        hasher.update(value.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+// NOTE: (vkhitrin) the refresh token grant is used when a refresh token is provided,
+//       otherwise the password grant is used.
+async fn request_access_token(
+    http_client: &Client,
+    account: &Account,
+    refresh_token: Option<&str>,
+) -> Result<AccessToken, Box<dyn std::error::Error>> {
+    let mut form = vec![
+        (
+            "client_id",
+            account.client_id.as_deref().unwrap_or_default(),
+        ),
+        ("client_secret", account.client_secret.as_str()),
+    ];
+    match refresh_token {
+        Some(refresh_token) => {
+            form.push(("grant_type", "refresh_token"));
+            form.push(("refresh_token", refresh_token));
+        }
+        None => {
+            form.push(("grant_type", "password"));
+            form.push(("username", account.username.as_deref().unwrap_or_default()));
+            form.push(("password", account.api_token.as_str()));
+        }
+    }
+    let response = http_client
+        .post(format!("{}{WALLABAG_TOKEN_ENDPOINT}", account.instance))
+        .form(&form)
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<WallabagTokenResponse>().await {
+            Ok(value) => Ok(AccessToken {
+                access_token: value.access_token,
+                refresh_token: value.refresh_token,
+                expires_at: current_timestamp() + value.expires_in - TOKEN_EXPIRY_MARGIN_SECONDS,
+            }),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-find-wallabag-api-endpoint"
+            )))),
+        },
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED => {
+            Err(Box::new(std::io::Error::other(fl!("invalid-api-token"))))
+        }
+        StatusCode::NOT_FOUND => Err(Box::new(std::io::Error::other(fl!(
+            "failed-to-find-wallabag-api-endpoint"
+        )))),
+        _ => Err(http_error(response).await),
+    }
+}
+
+async fn access_token(
+    http_client: &Client,
+    account: &Account,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let key = token_key(account);
+    let cached_token = ACCESS_TOKENS.lock().unwrap().get(&key).cloned();
+    if let Some(cached_token) = &cached_token {
+        if cached_token.expires_at > current_timestamp() {
+            return Ok(cached_token.access_token.clone());
+        }
+    }
+    let refreshed_token = match cached_token.and_then(|token| token.refresh_token) {
+        Some(refresh_token) => request_access_token(http_client, account, Some(&refresh_token))
+            .await
+            .ok(),
+        None => None,
+    };
+    let token = match refreshed_token {
+        Some(token) => token,
+        None => request_access_token(http_client, account, None).await?,
+    };
+    let access_token = token.access_token.clone();
+    ACCESS_TOKENS.lock().unwrap().insert(key, token);
+    Ok(access_token)
+}
+
+// NOTE: (vkhitrin) a request that was rejected (e.g. the token was revoked) is retried
+//       once using a new token.
+async fn send_request(
+    account: &Account,
+    request: impl Fn(&Client) -> RequestBuilder,
+) -> Result<Response, Box<dyn std::error::Error>> {
+    let http_client = http_client_for_account(account)?;
+    let token = access_token(&http_client, account).await?;
+    let response = request(&http_client).bearer_auth(token).send().await?;
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+    ACCESS_TOKENS.lock().unwrap().remove(&token_key(account));
+    let token = access_token(&http_client, account).await?;
+    Ok(request(&http_client).bearer_auth(token).send().await?)
+}
+
+fn entry_url(account: &Account, entry_id: i64) -> String {
+    format!(
+        "{}{WALLABAG_ENTRIES_ENDPOINT}/{entry_id}.json",
+        account.instance
+    )
+}
+
+// NOTE: (vkhitrin) Wallabag dates don't contain a colon in the offset (e.g.
+//       `2025-01-01T10:00:00+0000`), they are converted to RFC 3339.
+fn format_date(date: &str) -> String {
+    DateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S%z")
+        .map(|date| date.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|_| date.to_string())
+}
+
+// NOTE: (vkhitrin) Wallabag has no unread flag, entries that were not archived are
+//       considered unread and archiving an entry marks it as read.
+fn transform_entry(account: &Account, entry: WallabagEntry) -> Bookmark {
+    let mut tag_names: Vec<String> = entry
+        .tags
+        .iter()
+        .map(|tag| normalize_unreserved_tag_name(&tag.label))
+        .collect();
+    if entry.is_starred && !tag_names.iter().any(|tag| tag == STARRED_TAG) {
+        tag_names.push(STARRED_TAG.to_string());
+    }
+    let mut transformed_bookmark = Bookmark::new(
+        account.id,
+        Some(entry.id),
+        entry.url,
+        entry.title.unwrap_or_default(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        entry.preview_picture.unwrap_or_default(),
+        entry.is_archived,
+        !entry.is_archived,
+        false,
+        tag_names,
+        Some(format_date(&entry.created_at)),
+        Some(format_date(&entry.updated_at)),
+        Some(true),
+    );
+    transformed_bookmark.reading_time = Some(entry.reading_time);
+    transformed_bookmark
+}
+
+// NOTE: (vkhitrin) both the archived and unread flags of the bookmark are mapped to the
+//       archived flag of the entry. Marking an existing entry as read (or unread) archives
+//       (or unarchives) it, otherwise the archived flag of the bookmark is used.
+fn is_archive_requested(bookmark: &Bookmark, remote_entry: Option<&WallabagEntry>) -> bool {
+    match remote_entry {
+        Some(remote_entry) if bookmark.unread == remote_entry.is_archived => !bookmark.unread,
+        _ => bookmark.is_archived,
+    }
+}
+
+// NOTE: (vkhitrin) Wallabag doesn't store descriptions or notes, only the title, tags and
+//       the state of the entry are submitted. Tags are separated by commas.
+fn entry_request(
+    bookmark: &Bookmark,
+    remote_entry: Option<&WallabagEntry>,
+    tags: &[String],
+) -> Value {
+    json!({
+        "title": bookmark.title,
+        "tags": tags.join(","),
+        "archive": i32::from(is_archive_requested(bookmark, remote_entry)),
+        "starred": i32::from(bookmark.tag_names.iter().any(|tag| tag == STARRED_TAG)),
+    })
+}
+
+async fn parse_entry(
+    account: &Account,
+    response: Response,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    match response.status() {
+        StatusCode::OK => match response.json::<WallabagEntry>().await {
+            Ok(value) => Ok(transform_entry(account, value)),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+// NOTE: (vkhitrin) the total number of pages is reported by Wallabag. Entries can't be
+//       filtered by modification date in a way that detects removed entries, every sync is
+//       a full sync. Entries that are added or removed while fetching shift the following
+//       pages, the sync is reported as unsuccessful (no entries are removed locally).
+pub async fn fetch_bookmarks_for_account(
+    account: &Account,
+    page_size: u16,
+    sender: &UnboundedSender<BookmarksSyncEvent>,
+) -> Result<DetailedResponse, Box<dyn std::error::Error>> {
+    let page_size = page_size.max(1);
+    let mut detailed_response = DetailedResponse::new(account.clone(), 0, false, None);
+    let mut endpoint_status = EndpointStatus::new(WALLABAG_ENTRIES_ENDPOINT, None, false);
+    let mut page: usize = 0;
+    let mut expected_total: Option<usize> = None;
+    let mut entry_ids: HashSet<i64> = HashSet::new();
+    loop {
+        // NOTE: (vkhitrin) the content of the entries is not needed.
+        let page_url = format!(
+            "{}{WALLABAG_ENTRIES_ENDPOINT}.json?page={}&perPage={page_size}&detail=metadata&sort=created&order=asc",
+            account.instance,
+            page + 1
+        );
+        let response = send_request(account, |http_client| http_client.get(&page_url)).await?;
+        endpoint_status.status = Some(response.status().as_u16());
+        if response.status() != StatusCode::OK {
+            let error = http_error(response).await;
+            detailed_response.error = Some(format!("{WALLABAG_ENTRIES_ENDPOINT}: {error}"));
+            break;
+        }
+        if detailed_response.timestamp == 0 {
+            detailed_response.timestamp = parse_response_timestamp(&response)?;
+        }
+        let wallabag_response = match response.json::<WallabagEntriesResponse>().await {
+            Ok(value) => value,
+            Err(e) => {
+                log::error!("Error parsing JSON: {e:?}");
+                detailed_response.error = Some(format!("{WALLABAG_ENTRIES_ENDPOINT}: {e}"));
+                break;
+            }
+        };
+        if *expected_total.get_or_insert(wallabag_response.total) != wallabag_response.total {
+            log::warn!("Wallabag entries were modified while fetching");
+            detailed_response.error = Some(format!(
+                "{WALLABAG_ENTRIES_ENDPOINT}: {}",
+                fl!("bookmarks-modified-during-sync")
+            ));
+            break;
+        }
+        page += 1;
+        let total_pages = wallabag_response.pages.max(page);
+        entry_ids.extend(
+            wallabag_response
+                .embedded
+                .items
+                .iter()
+                .map(|entry| entry.id),
+        );
+        let bookmarks: Vec<Bookmark> = wallabag_response
+            .embedded
+            .items
+            .into_iter()
+            .map(|entry| transform_entry(account, entry))
+            .collect();
+        let _ = sender.unbounded_send(BookmarksSyncEvent::Page(BookmarksPage::new(
+            account.clone(),
+            bookmarks,
+            page,
+            total_pages,
+        )));
+        if page >= total_pages {
+            if entry_ids.len() < wallabag_response.total {
+                log::warn!("Wallabag entries were skipped while fetching");
+                detailed_response.error = Some(format!(
+                    "{WALLABAG_ENTRIES_ENDPOINT}: {}",
+                    fl!("bookmarks-modified-during-sync")
+                ));
+                break;
+            }
+            endpoint_status.successful = true;
+            detailed_response.successful = true;
+            break;
+        }
+    }
+    if detailed_response.timestamp == 0 {
+        detailed_response.timestamp = current_timestamp();
+    }
+    detailed_response.endpoint_statuses.push(endpoint_status);
+    detailed_response.bookmarks = Some(Vec::new());
+    Ok(detailed_response)
+}
+
+async fn fetch_remote_entry(
+    account: &Account,
+    entry_id: i64,
+) -> Result<WallabagEntry, Box<dyn std::error::Error>> {
+    let rest_api_url = entry_url(account, entry_id);
+    let response = send_request(account, |http_client| http_client.get(&rest_api_url)).await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<WallabagEntry>().await {
+            Ok(value) => Ok(value),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+async fn find_entry_by_url(
+    account: &Account,
+    url: &str,
+) -> Result<Option<i64>, Box<dyn std::error::Error>> {
+    let rest_api_url = format!(
+        "{}{WALLABAG_ENTRIES_ENDPOINT}/exists.json?return_id=1&url={}",
+        account.instance,
+        encode(url)
+    );
+    let response = send_request(account, |http_client| http_client.get(&rest_api_url)).await?;
+    match response.status() {
+        StatusCode::OK => match response.json::<Value>().await {
+            Ok(value) => Ok(value.get("exists").and_then(Value::as_i64)),
+            Err(_e) => Err(Box::new(std::io::Error::other(fl!(
+                "failed-to-parse-response"
+            )))),
+        },
+        _ => Err(http_error(response).await),
+    }
+}
+
+async fn create_entry(
+    account: &Account,
+    bookmark: &Bookmark,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let rest_api_url = format!("{}{WALLABAG_ENTRIES_ENDPOINT}.json", account.instance);
+    let tags: Vec<String> = bookmark
+        .tag_names
+        .iter()
+        .filter(|tag_name| *tag_name != STARRED_TAG)
+        .map(|tag_name| unescape_tag_name(tag_name).to_string())
+        .collect();
+    let mut request = entry_request(bookmark, None, &tags);
+    request["url"] = json!(bookmark.url);
+    if bookmark.title.is_empty() {
+        request["title"] = Value::Null;
+    }
+    let response = send_request(account, |http_client| {
+        http_client.post(&rest_api_url).json(&request)
+    })
+    .await?;
+    parse_entry(account, response).await
+}
+
+// NOTE: (vkhitrin) tags submitted with the entry are added to it, tags that were removed
+//       are detached from the entry separately.
+async fn update_entry(
+    account: &Account,
+    bookmark: &Bookmark,
+    remote_entry: &WallabagEntry,
+) -> Result<Bookmark, Box<dyn std::error::Error>> {
+    let tags: Vec<String> = bookmark
+        .tag_names
+        .iter()
+        .filter(|tag_name| *tag_name != STARRED_TAG)
+        .map(|tag_name| {
+            remote_entry
+                .tags
+                .iter()
+                .find(|remote_tag| normalize_unreserved_tag_name(&remote_tag.label) == *tag_name)
+                .map_or_else(
+                    || unescape_tag_name(tag_name).to_string(),
+                    |remote_tag| remote_tag.label.clone(),
+                )
+        })
+        .collect();
+    let request = entry_request(bookmark, Some(remote_entry), &tags);
+    let rest_api_url = entry_url(account, remote_entry.id);
+    let response = send_request(account, |http_client| {
+        http_client.patch(&rest_api_url).json(&request)
+    })
+    .await?;
+    if response.status() != StatusCode::OK {
+        return Err(http_error(response).await);
+    }
+    for remote_tag in remote_entry.tags.iter().filter(|remote_tag| {
+        !bookmark
+            .tag_names
+            .contains(&normalize_unreserved_tag_name(&remote_tag.label))
+    }) {
+        let tag_url = format!(
+            "{}{WALLABAG_ENTRIES_ENDPOINT}/{}/tags/{}.json",
+            account.instance, remote_entry.id, remote_tag.id
+        );
+        let response = send_request(account, |http_client| http_client.delete(&tag_url)).await?;
+        if response.status() != StatusCode::OK {
+            return Err(http_error(response).await);
+        }
+    }
+    let response = send_request(account, |http_client| http_client.get(&rest_api_url)).await?;
+    parse_entry(account, response).await
+}
+
+pub async fn populate_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+    check_for_existing: bool,
+) -> Option<BookmarkCheckDetailsResponse> {
+    let mut api_response = BookmarkCheckDetailsResponse::default();
+    if !check_for_existing {
+        edit_remote_entry(&account, &bookmark, &mut api_response).await;
+        return Some(api_response);
+    }
+    let result = populate_remote_entry(&account, bookmark)
+        .await
+        .map_err(|e| (is_unreachable_error(e.as_ref()), e.to_string()));
+    match result {
+        Ok((value, is_new)) => {
+            api_response.is_new = is_new;
+            api_response.bookmark = Some(value);
+            api_response.successful = true;
+        }
+        Err((unreachable, e)) => {
+            log::error!("Error adding bookmark: {e}");
+            api_response.unreachable = unreachable;
+            api_response.error = Some(e);
+        }
+    }
+    Some(api_response)
+}
+
+// NOTE: (vkhitrin) returns the bookmark and whether it was created.
+async fn populate_remote_entry(
+    account: &Account,
+    mut bookmark: Bookmark,
+) -> Result<(Bookmark, bool), Box<dyn std::error::Error>> {
+    let existing_entry_id = find_entry_by_url(account, &bookmark.url).await?;
+    match existing_entry_id {
+        Some(entry_id) => {
+            let existing_entry = fetch_remote_entry(account, entry_id).await?;
+            bookmark.provider_internal_id = Some(existing_entry.id);
+            if bookmark.title.is_empty() {
+                bookmark.title = existing_entry.title.clone().unwrap_or_default();
+            }
+            Ok((
+                update_entry(account, &bookmark, &existing_entry).await?,
+                false,
+            ))
+        }
+        None => Ok((create_entry(account, &bookmark).await?, true)),
+    }
+}
+
+async fn edit_remote_entry(
+    account: &Account,
+    bookmark: &Bookmark,
+    api_response: &mut BookmarkCheckDetailsResponse,
+) {
+    edit_bookmark_without_conflicts(
+        bookmark,
+        api_response,
+        || async {
+            let entry_id = bookmark.provider_internal_id.ok_or_else(not_synced_error)?;
+            let remote_entry = fetch_remote_entry(account, entry_id).await?;
+            Ok((transform_entry(account, remote_entry.clone()), remote_entry))
+        },
+        |remote_entry| async move { update_entry(account, bookmark, &remote_entry).await },
+    )
+    .await;
+}
+
+pub async fn remove_bookmark(
+    account: Account,
+    bookmark: Bookmark,
+) -> Option<BookmarkRemoveResponse> {
+    remove_remote_bookmark(|| async {
+        let entry_id = bookmark.provider_internal_id.ok_or_else(not_synced_error)?;
+        let rest_api_url = entry_url(&account, entry_id);
+        send_request(&account, |http_client| http_client.delete(&rest_api_url)).await
+    })
+    .await
+}
+
+pub async fn fetch_account_details(account: Account) -> Option<AccountDetails> {
+    let mut account_details = AccountDetails::default();
+    let rest_api_url = format!("{}{WALLABAG_USER_ENDPOINT}", account.instance);
+    let result = send_request(&account, |http_client| http_client.get(&rest_api_url))
+        .await
+        .map_err(|e| e.to_string());
+    let error = match result {
+        Ok(response) => match response.status() {
+            StatusCode::OK => None,
+            StatusCode::NOT_FOUND => Some(fl!("failed-to-find-wallabag-api-endpoint")),
+            _ => Some(http_error(response).await.to_string()),
+        },
+        Err(e) => Some(account_details_error(&e)),
+    };
+    account_details.successful = error.is_none();
+    if let Some(error) = error {
+        log::error!(
+            "Error fetching account {} details: {}",
+            account.display_name,
+            error
+        );
+        account_details.error = Some(error);
+    }
+    Some(account_details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        let mut account = Account::new(
+            "Wallabag".to_string(),
+            "password".to_string(),
+            "https://read.example.com".to_string(),
+            "wallabag",
+        );
+        account.id = Some(9);
+        account.username = Some("user".to_string());
+        account.client_id = Some("client".to_string());
+        account.client_secret = "secret".to_string();
+        account
+    }
+
+    fn entry(entry_id: i64, is_archived: bool) -> WallabagEntry {
+        serde_json::from_value(json!({
+            "id": entry_id,
+            "url": format!("https://example.com/{entry_id}"),
+            "title": "Example",
+            "is_archived": i32::from(is_archived),
+            "is_starred": true,
+            "tags": [
+                {"id": 1, "label": "open source"},
+                {"id": 2, "label": "!important"}
+            ],
+            "reading_time": 4,
+            "created_at": "2025-01-01T10:00:00+0000",
+            "updated_at": "2025-01-02T12:30:00+0200",
+            "preview_picture": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn format_date_converts_offset_to_rfc3339() {
+        assert_eq!(
+            format_date("2025-01-01T10:00:00+0000"),
+            "2025-01-01T10:00:00Z"
+        );
+        assert_eq!(
+            format_date("2025-01-02T12:30:00+0200"),
+            "2025-01-02T12:30:00+02:00"
+        );
+        assert_eq!(format_date("not a date"), "not a date");
+    }
+
+    #[test]
+    fn transform_entry_parses_api_response() {
+        let bookmark = transform_entry(&account(), entry(12, false));
+
+        assert_eq!(bookmark.user_account_id, Some(9));
+        assert_eq!(bookmark.provider_internal_id, Some(12));
+        assert!(!bookmark.is_archived);
+        assert!(bookmark.unread);
+        assert_eq!(
+            bookmark.tag_names,
+            vec!["open-source", "!!important", STARRED_TAG]
+        );
+        assert_eq!(bookmark.reading_time, Some(4));
+        assert_eq!(bookmark.date_added.as_deref(), Some("2025-01-01T10:00:00Z"));
+        assert_eq!(
+            bookmark.date_modified.as_deref(),
+            Some("2025-01-02T12:30:00+02:00")
+        );
+    }
+
+    #[test]
+    fn transform_entry_marks_archived_entries_as_read() {
+        let bookmark = transform_entry(&account(), entry(12, true));
+
+        assert!(bookmark.is_archived);
+        assert!(!bookmark.unread);
+    }
+
+    #[test]
+    fn entry_request_maps_unread_to_archived() {
+        let archived_entry = entry(12, true);
+        let unarchived_entry = entry(12, false);
+        let mut bookmark = transform_entry(&account(), unarchived_entry.clone());
+
+        bookmark.unread = false;
+        assert_eq!(
+            entry_request(&bookmark, Some(&unarchived_entry), &[])["archive"],
+            1
+        );
+
+        let mut bookmark = transform_entry(&account(), archived_entry.clone());
+        bookmark.unread = true;
+        assert_eq!(
+            entry_request(&bookmark, Some(&archived_entry), &[])["archive"],
+            0
+        );
+
+        let mut bookmark = transform_entry(&account(), archived_entry.clone());
+        bookmark.is_archived = false;
+        assert_eq!(
+            entry_request(&bookmark, Some(&archived_entry), &[])["archive"],
+            0
+        );
+        assert_eq!(entry_request(&bookmark, None, &[])["archive"], 0);
+    }
+
+    fn entries_page(total: usize, pages: usize, entry_ids: &[i64]) -> String {
+        let items: Vec<Value> = entry_ids
+            .iter()
+            .map(|entry_id| entry_json(*entry_id))
+            .collect();
+        json!({
+            "pages": pages,
+            "total": total,
+            "_embedded": {"items": items}
+        })
+        .to_string()
+    }
+
+    fn entry_json(entry_id: i64) -> Value {
+        json!({
+            "id": entry_id,
+            "url": format!("https://example.com/{entry_id}"),
+            "title": null,
+            "is_archived": 0,
+            "is_starred": 0,
+            "created_at": "2025-01-01T10:00:00+0000",
+            "updated_at": "2025-01-01T10:00:00+0000",
+            "preview_picture": null
+        })
+    }
+
+    // NOTE: (vkhitrin) entries are returned in two pages, the second page reports
+    //       `second_page_total` entries in total and returns `second_page_entry_ids`.
+    async fn fetch_entries(
+        account_id: i64,
+        second_page_total: usize,
+        second_page_entry_ids: &'static [i64],
+    ) -> DetailedResponse {
+        let (instance, _) = crate::provider::tests::serve(move |request_line| {
+            let path = request_line.split_once(' ').map_or("", |(_, path)| path);
+            if path.starts_with(WALLABAG_TOKEN_ENDPOINT) {
+                return (
+                    StatusCode::OK,
+                    json!({"access_token": "token", "expires_in": 3600}).to_string(),
+                );
+            }
+            if path.contains("page=1&") {
+                (StatusCode::OK, entries_page(3, 2, &[1, 2]))
+            } else {
+                (
+                    StatusCode::OK,
+                    entries_page(second_page_total, 2, second_page_entry_ids),
+                )
+            }
+        })
+        .await;
+        let mut account = account();
+        account.id = Some(account_id);
+        account.instance = instance;
+        let (sender, _receiver) = futures::channel::mpsc::unbounded();
+        fetch_bookmarks_for_account(&account, 2, &sender)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_bookmarks_for_account_fetches_every_page() {
+        let response = fetch_entries(9201, 3, &[3]).await;
+
+        assert!(response.successful);
+        assert!(response.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn fetch_bookmarks_for_account_fails_when_total_changes() {
+        let response = fetch_entries(9202, 2, &[3]).await;
+
+        assert!(!response.successful);
+        assert!(response.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn fetch_bookmarks_for_account_fails_when_entries_are_skipped() {
+        let response = fetch_entries(9203, 3, &[2]).await;
+
+        assert!(!response.successful);
+        assert!(response.error.is_some());
+    }
+}
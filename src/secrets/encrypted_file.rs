@@ -0,0 +1,251 @@
+use crate::{
+    app::{APP, ORG, QUALIFIER},
+    fl,
+};
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng, Payload},
+    Aes256Gcm, Key, Nonce,
+};
+use anyhow::{anyhow, Result};
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+const SECRETS_FILE: &str = "secrets.json";
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+// NOTE: (vkhitrin) a known value encrypted with the derived key, used to detect an incorrect
+//       passphrase before any secret is read or written.
+const VERIFIER: &[u8] = APP.as_bytes();
+const VERIFIER_REFERENCE: &str = "verifier";
+
+#[derive(Serialize, Deserialize)]
+struct EncryptedValue {
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SecretsFile {
+    salt: String,
+    verifier: EncryptedValue,
+    secrets: HashMap<String, EncryptedValue>,
+}
+
+// NOTE: (vkhitrin) the key is derived from the passphrase using Argon2id, secrets are
+//       encrypted using AES-256-GCM, their reference is used as associated data so an
+//       encrypted value can't be swapped with another.
+pub struct EncryptedFile {
+    path: PathBuf,
+    cipher: Aes256Gcm,
+    contents: SecretsFile,
+}
+
+impl EncryptedFile {
+    fn path() -> PathBuf {
+        let directories = directories::ProjectDirs::from(QUALIFIER, ORG, APP).unwrap();
+        directories.data_dir().join(SECRETS_FILE)
+    }
+
+    pub fn exists() -> bool {
+        Self::path().exists()
+    }
+
+    pub fn open(passphrase: &str) -> Result<Self> {
+        Self::open_at(Self::path(), passphrase)
+    }
+
+    pub(super) fn open_at(path: PathBuf, passphrase: &str) -> Result<Self> {
+        if passphrase.is_empty() {
+            return Err(anyhow!(fl!("passphrase-empty")));
+        }
+        if path.exists() {
+            let contents: SecretsFile = serde_json::from_slice(&fs::read(&path)?)?;
+            let cipher = derive_cipher(passphrase, &BASE64.decode(&contents.salt)?)?;
+            decrypt(&cipher, VERIFIER_REFERENCE, &contents.verifier)
+                .map_err(|_| anyhow!(fl!("incorrect-passphrase")))?;
+            Ok(Self {
+                path,
+                cipher,
+                contents,
+            })
+        } else {
+            let mut salt = [0u8; SALT_LENGTH];
+            OsRng.fill_bytes(&mut salt);
+            let cipher = derive_cipher(passphrase, &salt)?;
+            let verifier = encrypt(&cipher, VERIFIER_REFERENCE, VERIFIER)?;
+            let encrypted_file = Self {
+                path,
+                cipher,
+                contents: SecretsFile {
+                    salt: BASE64.encode(salt),
+                    verifier,
+                    secrets: HashMap::new(),
+                },
+            };
+            encrypted_file.save()?;
+            Ok(encrypted_file)
+        }
+    }
+
+    pub fn load_secret(&self, reference: &str) -> Result<String> {
+        let encrypted_value = self
+            .contents
+            .secrets
+            .get(reference)
+            .ok_or_else(|| anyhow!("{reference}: {}", fl!("secret-not-found")))?;
+        Ok(String::from_utf8(decrypt(
+            &self.cipher,
+            reference,
+            encrypted_value,
+        )?)?)
+    }
+
+    pub fn store_secret(&mut self, reference: &str, secret: &str) -> Result<()> {
+        let encrypted_value = encrypt(&self.cipher, reference, secret.as_bytes())?;
+        self.contents
+            .secrets
+            .insert(reference.to_string(), encrypted_value);
+        self.save()
+    }
+
+    pub fn delete_secret(&mut self, reference: &str) -> Result<()> {
+        if self.contents.secrets.remove(reference).is_some() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    // NOTE: (vkhitrin) the file is written to a temporary file (readable only by the user) and
+    //       renamed, an interrupted write does not corrupt existing secrets.
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary_path = self.path.with_extension("json.tmp");
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(&temporary_path)?;
+        file.write_all(&serde_json::to_vec(&self.contents)?)?;
+        file.sync_all()?;
+        fs::rename(temporary_path, &self.path)?;
+        Ok(())
+    }
+}
+
+fn derive_cipher(passphrase: &str, salt: &[u8]) -> Result<Aes256Gcm> {
+    let mut key = Key::<Aes256Gcm>::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("{e}"))?;
+    Ok(Aes256Gcm::new(&key))
+}
+
+fn encrypt(cipher: &Aes256Gcm, reference: &str, plaintext: &[u8]) -> Result<EncryptedValue> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad: reference.as_bytes(),
+            },
+        )
+        .map_err(|e| anyhow!("{e}"))?;
+    Ok(EncryptedValue {
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(ciphertext),
+    })
+}
+
+fn decrypt(
+    cipher: &Aes256Gcm,
+    reference: &str,
+    encrypted_value: &EncryptedValue,
+) -> Result<Vec<u8>> {
+    let nonce = BASE64.decode(&encrypted_value.nonce)?;
+    if nonce.len() != NONCE_LENGTH {
+        return Err(anyhow!(fl!("secret-corrupted")));
+    }
+    cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &BASE64.decode(&encrypted_value.ciphertext)?,
+                aad: reference.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow!(fl!("secret-corrupted")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "{APP}-{name}-{}-{}",
+            std::process::id(),
+            OsRng.next_u64()
+        ))
+    }
+
+    #[test]
+    fn encrypted_file_stores_and_loads_secrets() {
+        let path = temp_path("secrets").join(SECRETS_FILE);
+        let mut encrypted_file = EncryptedFile::open_at(path.clone(), "passphrase").unwrap();
+        encrypted_file.store_secret("token", "api-token").unwrap();
+        encrypted_file.store_secret("removed", "api-token").unwrap();
+        encrypted_file.delete_secret("removed").unwrap();
+
+        let encrypted_file = EncryptedFile::open_at(path.clone(), "passphrase").unwrap();
+        assert_eq!(encrypted_file.load_secret("token").unwrap(), "api-token");
+        assert!(encrypted_file.load_secret("removed").is_err());
+        assert!(!fs::read_to_string(&path).unwrap().contains("api-token"));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn encrypted_file_rejects_incorrect_passphrase() {
+        let path = temp_path("passphrase").join(SECRETS_FILE);
+        EncryptedFile::open_at(path.clone(), "passphrase")
+            .unwrap()
+            .store_secret("token", "secret")
+            .unwrap();
+
+        assert_eq!(
+            EncryptedFile::open_at(path.clone(), "incorrect")
+                .err()
+                .unwrap()
+                .to_string(),
+            fl!("incorrect-passphrase")
+        );
+        assert!(EncryptedFile::open_at(path.clone(), "").is_err());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn decrypt_rejects_swapped_secrets() {
+        let path = temp_path("swapped").join(SECRETS_FILE);
+        let mut encrypted_file = EncryptedFile::open_at(path.clone(), "passphrase").unwrap();
+        encrypted_file.store_secret("first", "secret").unwrap();
+        let encrypted_value = encrypted_file.contents.secrets.remove("first").unwrap();
+        encrypted_file
+            .contents
+            .secrets
+            .insert("second".to_string(), encrypted_value);
+
+        assert!(encrypted_file.load_secret("second").is_err());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+use crate::fl;
+use aes_gcm::aead::{rand_core::RngCore, OsRng};
+use anyhow::{anyhow, Result};
+use encrypted_file::EncryptedFile;
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+mod encrypted_file;
+mod secret_service;
+
+// NOTE: (vkhitrin) secrets (API tokens) are stored in the Secret Service (e.g. GNOME Keyring,
+//       KWallet), an encrypted file in the data directory is used when it is not available.
+//       The database only contains a reference to each secret.
+#[allow(clippy::large_enum_variant)]
+enum SecretBackend {
+    Uninitialized,
+    SecretService,
+    // NOTE: (vkhitrin) `None` until the passphrase is provided.
+    EncryptedFile(Option<EncryptedFile>),
+}
+
+static SECRET_BACKEND: LazyLock<Mutex<SecretBackend>> =
+    LazyLock::new(|| Mutex::new(SecretBackend::Uninitialized));
+
+// NOTE: (vkhitrin) secrets are cached after they are read or written, accounts are loaded
+//       frequently and every lookup in the Secret Service is a D-Bus round trip.
+static SECRETS_CACHE: LazyLock<Mutex<HashMap<String, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub async fn initialize() {
+    let backend = match secret_service::check_availability().await {
+        Ok(()) => SecretBackend::SecretService,
+        Err(e) => {
+            log::warn!("Secret Service is not available, using an encrypted file: {e}");
+            SecretBackend::EncryptedFile(None)
+        }
+    };
+    *SECRET_BACKEND.lock().unwrap() = backend;
+}
+
+pub fn is_locked() -> bool {
+    matches!(
+        *SECRET_BACKEND.lock().unwrap(),
+        SecretBackend::EncryptedFile(None)
+    )
+}
+
+// NOTE: (vkhitrin) the first passphrase that is provided is used to create the encrypted file.
+pub fn requires_new_passphrase() -> bool {
+    !EncryptedFile::exists()
+}
+
+pub fn unlock(passphrase: &str) -> Result<()> {
+    let mut backend = SECRET_BACKEND.lock().unwrap();
+    if let SecretBackend::EncryptedFile(encrypted_file) = &mut *backend {
+        *encrypted_file = Some(EncryptedFile::open(passphrase)?);
+    }
+    Ok(())
+}
+
+pub fn new_reference() -> String {
+    format!("{:016x}{:016x}", OsRng.next_u64(), OsRng.next_u64())
+}
+
+pub async fn load_secret(reference: &str) -> Result<String> {
+    if let Some(secret) = SECRETS_CACHE.lock().unwrap().get(reference) {
+        return Ok(secret.clone());
+    }
+    let secret = if uses_secret_service()? {
+        secret_service::load_secret(reference).await?
+    } else {
+        with_encrypted_file(|encrypted_file| encrypted_file.load_secret(reference))?
+    };
+    SECRETS_CACHE
+        .lock()
+        .unwrap()
+        .insert(reference.to_string(), secret.clone());
+    Ok(secret)
+}
+
+pub async fn store_secret(reference: &str, label: &str, secret: &str) -> Result<()> {
+    if SECRETS_CACHE
+        .lock()
+        .unwrap()
+        .get(reference)
+        .is_some_and(|cached_secret| cached_secret == secret)
+    {
+        return Ok(());
+    }
+    if uses_secret_service()? {
+        secret_service::store_secret(reference, label, secret).await?;
+    } else {
+        with_encrypted_file(|encrypted_file| encrypted_file.store_secret(reference, secret))?;
+    }
+    SECRETS_CACHE
+        .lock()
+        .unwrap()
+        .insert(reference.to_string(), secret.to_string());
+    Ok(())
+}
+
+pub async fn delete_secret(reference: &str) -> Result<()> {
+    SECRETS_CACHE.lock().unwrap().remove(reference);
+    if uses_secret_service()? {
+        secret_service::delete_secret(reference).await
+    } else {
+        with_encrypted_file(|encrypted_file| encrypted_file.delete_secret(reference))
+    }
+}
+
+fn uses_secret_service() -> Result<bool> {
+    match *SECRET_BACKEND.lock().unwrap() {
+        SecretBackend::Uninitialized => Err(anyhow!(fl!("secrets-unavailable"))),
+        SecretBackend::SecretService => Ok(true),
+        SecretBackend::EncryptedFile(_) => Ok(false),
+    }
+}
+
+fn with_encrypted_file<T>(f: impl FnOnce(&mut EncryptedFile) -> Result<T>) -> Result<T> {
+    match &mut *SECRET_BACKEND.lock().unwrap() {
+        SecretBackend::EncryptedFile(Some(encrypted_file)) => f(encrypted_file),
+        SecretBackend::EncryptedFile(None) => Err(anyhow!(fl!("secrets-locked"))),
+        _ => Err(anyhow!(fl!("secrets-unavailable"))),
+    }
+}
+
+// NOTE: (vkhitrin) tests store secrets in an encrypted file in the temporary directory.
+#[cfg(test)]
+pub fn initialize_for_tests() {
+    static INITIALIZE: std::sync::Once = std::sync::Once::new();
+    INITIALIZE.call_once(|| {
+        let path = std::env::temp_dir()
+            .join(format!(
+                "{}-secrets-{}",
+                crate::app::APP,
+                std::process::id()
+            ))
+            .join("secrets.json");
+        let _ = std::fs::remove_file(&path);
+        let encrypted_file = EncryptedFile::open_at(path, "passphrase").unwrap();
+        *SECRET_BACKEND.lock().unwrap() = SecretBackend::EncryptedFile(Some(encrypted_file));
+    });
+}
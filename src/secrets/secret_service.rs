@@ -0,0 +1,61 @@
+use crate::{
+    app::{APP, APPID},
+    fl,
+};
+use ::secret_service::{EncryptionType, SecretService};
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+const SECRET_CONTENT_TYPE: &str = "text/plain";
+
+fn secret_attributes(reference: &str) -> HashMap<&str, &str> {
+    HashMap::from([("application", APPID), ("reference", reference)])
+}
+
+pub async fn check_availability() -> Result<()> {
+    let service = SecretService::connect(EncryptionType::Dh).await?;
+    service.get_default_collection().await?;
+    Ok(())
+}
+
+pub async fn load_secret(reference: &str) -> Result<String> {
+    let service = SecretService::connect(EncryptionType::Dh).await?;
+    let collection = service.get_default_collection().await?;
+    collection.ensure_unlocked().await?;
+    let items = collection
+        .search_items(secret_attributes(reference))
+        .await?;
+    let item = items
+        .first()
+        .ok_or_else(|| anyhow!("{reference}: {}", fl!("secret-not-found")))?;
+    Ok(String::from_utf8(item.get_secret().await?)?)
+}
+
+pub async fn store_secret(reference: &str, label: &str, secret: &str) -> Result<()> {
+    let service = SecretService::connect(EncryptionType::Dh).await?;
+    let collection = service.get_default_collection().await?;
+    collection.ensure_unlocked().await?;
+    collection
+        .create_item(
+            &format!("{APP}: {label}"),
+            secret_attributes(reference),
+            secret.as_bytes(),
+            true,
+            SECRET_CONTENT_TYPE,
+        )
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_secret(reference: &str) -> Result<()> {
+    let service = SecretService::connect(EncryptionType::Dh).await?;
+    let collection = service.get_default_collection().await?;
+    collection.ensure_unlocked().await?;
+    for item in collection
+        .search_items(secret_attributes(reference))
+        .await?
+    {
+        item.delete().await?;
+    }
+    Ok(())
+}
@@ -193,6 +193,9 @@ fn parse_bookmark_entry(
         date_modified: None,
         is_owner: None,
         favicon_cached: None,
+        collection: None,
+        provider_remote_id: None,
+        reading_time: None,
     };
 
     Ok(Some(bookmark))
@@ -1,2 +1,3 @@
 pub mod bookmark_parser;
 pub mod json;
+pub mod search;
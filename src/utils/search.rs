@@ -0,0 +1,397 @@
+use crate::fl;
+use chrono::NaiveDate;
+
+// NOTE: (vkhitrin) matched terms in snippets are wrapped with control characters, they are not
+//       expected to be part of bookmarks.
+pub const SNIPPET_MATCH_START: char = '\u{2}';
+pub const SNIPPET_MATCH_END: char = '\u{3}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkState {
+    Archived,
+    Shared,
+    Unread,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateComparison {
+    After,
+    AfterOrOn,
+    Before,
+    BeforeOrOn,
+    On,
+}
+
+impl DateComparison {
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::After => ">",
+            Self::AfterOrOn => ">=",
+            Self::Before => "<",
+            Self::BeforeOrOn => "<=",
+            Self::On => "=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchFilter {
+    Account(String),
+    Added(DateComparison, NaiveDate),
+    Collection(String),
+    Is(BookmarkState),
+    Site(String),
+    Tag(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchCondition {
+    pub filter: SearchFilter,
+    pub negated: bool,
+}
+
+// NOTE: (vkhitrin) free text is matched using the FTS5 index, terms are stored as FTS5
+//       expressions (prefix terms or phrases).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub conditions: Vec<SearchCondition>,
+    pub excluded_terms: Vec<String>,
+    pub terms: Vec<String>,
+}
+
+impl SearchQuery {
+    pub fn fts_query(&self) -> Option<String> {
+        if self.terms.is_empty() {
+            None
+        } else {
+            Some(self.terms.join(" "))
+        }
+    }
+
+    pub fn excluded_fts_query(&self) -> Option<String> {
+        if self.excluded_terms.is_empty() {
+            None
+        } else {
+            Some(self.excluded_terms.join(" OR "))
+        }
+    }
+}
+
+// NOTE: (vkhitrin) parses search queries such as:
+//       `tag:rust -tag:old site:github.com is:unread account:"Work" added:>2025-01-01 "phrase"`
+//       `collection:Work/Projects` matches bookmarks in the collection and its subcollections.
+//       Filters (and free text) can be negated using a `-` prefix, text that isn't a known
+//       filter is matched as a prefix, quoted text is matched as a phrase.
+pub fn parse_search_query(search_string: &str) -> Result<SearchQuery, String> {
+    let mut search_query = SearchQuery::default();
+    for token in tokenize(search_string)? {
+        let (negated, token) = match token.strip_prefix('-') {
+            Some(stripped) if !stripped.is_empty() => (true, stripped),
+            _ => (false, token.as_str()),
+        };
+        let term = if token.starts_with('"') {
+            let phrase = unquote(token);
+            phrase
+                .chars()
+                .any(char::is_alphanumeric)
+                .then(|| format!("\"{}\"", phrase.trim().replace('"', "\"\"")))
+        } else if let Some(filter) = parse_filter(token)? {
+            search_query
+                .conditions
+                .push(SearchCondition { filter, negated });
+            None
+        } else {
+            let word = unquote(token);
+            word.chars()
+                .any(char::is_alphanumeric)
+                .then(|| format!("\"{}\"*", word.replace('"', "\"\"")))
+        };
+        if let Some(term) = term {
+            if negated {
+                search_query.excluded_terms.push(term);
+            } else {
+                search_query.terms.push(term);
+            }
+        }
+    }
+    Ok(search_query)
+}
+
+fn tokenize(search_string: &str) -> Result<Vec<String>, String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = search_string.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            current.push(c);
+            if let Some(escaped) = chars.next() {
+                current.push(escaped);
+            }
+        } else if c == '"' {
+            in_quotes = !in_quotes;
+            current.push(c);
+        } else if c.is_whitespace() && !in_quotes {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if in_quotes {
+        return Err(fl!("search-unclosed-quote"));
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+// NOTE: (vkhitrin) quotes and backslashes can be escaped using a backslash.
+fn unquote(value: &str) -> String {
+    let mut unquoted = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            '"' => {}
+            _ => unquoted.push(c),
+        }
+    }
+    unquoted
+}
+
+pub fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// NOTE: (vkhitrin) tokens with an unknown prefix (for example URLs) are not filters.
+fn parse_filter(token: &str) -> Result<Option<SearchFilter>, String> {
+    let Some((key, value)) = token.split_once(':') else {
+        return Ok(None);
+    };
+    let key = key.to_lowercase();
+    if !matches!(
+        key.as_str(),
+        "account" | "added" | "collection" | "is" | "site" | "tag"
+    ) {
+        return Ok(None);
+    }
+    let value = unquote(value).trim().to_string();
+    if value.is_empty() {
+        return Err(fl!("search-missing-value", filter = key));
+    }
+    let filter = match key.as_str() {
+        "account" => SearchFilter::Account(value),
+        "added" => {
+            let (comparison, date) = if let Some(date) = value.strip_prefix(">=") {
+                (DateComparison::AfterOrOn, date)
+            } else if let Some(date) = value.strip_prefix("<=") {
+                (DateComparison::BeforeOrOn, date)
+            } else if let Some(date) = value.strip_prefix('>') {
+                (DateComparison::After, date)
+            } else if let Some(date) = value.strip_prefix('<') {
+                (DateComparison::Before, date)
+            } else {
+                (
+                    DateComparison::On,
+                    value.strip_prefix('=').unwrap_or(&value),
+                )
+            };
+            match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                Ok(date) => SearchFilter::Added(comparison, date),
+                Err(_) => return Err(fl!("search-invalid-date", value = value)),
+            }
+        }
+        "collection" => SearchFilter::Collection(value.trim_matches('/').to_string()),
+        "is" => match value.to_lowercase().as_str() {
+            "archived" => SearchFilter::Is(BookmarkState::Archived),
+            "shared" => SearchFilter::Is(BookmarkState::Shared),
+            "unread" => SearchFilter::Is(BookmarkState::Unread),
+            _ => return Err(fl!("search-invalid-state", value = value)),
+        },
+        "site" => SearchFilter::Site(value.to_lowercase()),
+        _ => SearchFilter::Tag(value),
+    };
+    Ok(Some(filter))
+}
+
+// NOTE: (vkhitrin) splits a snippet into segments, each segment indicates whether it
+//       matched the search query.
+pub fn parse_snippet(snippet: &str) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    let mut current = String::new();
+    let mut is_match = false;
+    for c in snippet.chars() {
+        if c == SNIPPET_MATCH_START || c == SNIPPET_MATCH_END {
+            if !current.is_empty() {
+                segments.push((std::mem::take(&mut current), is_match));
+            }
+            is_match = c == SNIPPET_MATCH_START;
+        } else if c == '\n' {
+            current.push(' ');
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        segments.push((current, is_match));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(filter: SearchFilter, negated: bool) -> SearchCondition {
+        SearchCondition { filter, negated }
+    }
+
+    #[test]
+    fn tokenize_keeps_quoted_text_together() {
+        assert_eq!(
+            tokenize(r#"rust account:"My Work" "a phrase""#).unwrap(),
+            vec!["rust", r#"account:"My Work""#, r#""a phrase""#]
+        );
+    }
+
+    #[test]
+    fn tokenize_rejects_unclosed_quote() {
+        assert!(tokenize(r#"rust "unclosed"#).is_err());
+    }
+
+    #[test]
+    fn quote_escapes_values() {
+        let value = r#"c"s\tag"#;
+        assert_eq!(quote(value), r#""c\"s\\tag""#);
+        assert_eq!(
+            parse_filter(&format!("tag:{}", quote(value))).unwrap(),
+            Some(SearchFilter::Tag(value.to_string()))
+        );
+        assert_eq!(
+            parse_search_query(&format!("tag:{} rust", quote(value)))
+                .unwrap()
+                .conditions,
+            vec![condition(SearchFilter::Tag(value.to_string()), false)]
+        );
+    }
+
+    #[test]
+    fn parse_filter_ignores_unknown_prefixes() {
+        assert_eq!(parse_filter("https://github.com").unwrap(), None);
+        assert_eq!(parse_filter("rust").unwrap(), None);
+    }
+
+    #[test]
+    fn parse_filter_rejects_missing_values() {
+        assert!(parse_filter("tag:").is_err());
+        assert!(parse_filter(r#"tag:"""#).is_err());
+    }
+
+    #[test]
+    fn parse_filter_parses_dates() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        assert_eq!(
+            parse_filter("added:>=2025-01-01").unwrap(),
+            Some(SearchFilter::Added(DateComparison::AfterOrOn, date))
+        );
+        assert_eq!(
+            parse_filter("added:<2025-01-01").unwrap(),
+            Some(SearchFilter::Added(DateComparison::Before, date))
+        );
+        assert_eq!(
+            parse_filter("added:2025-01-01").unwrap(),
+            Some(SearchFilter::Added(DateComparison::On, date))
+        );
+    }
+
+    #[test]
+    fn parse_filter_rejects_bad_dates() {
+        assert!(parse_filter("added:>2025-13-01").is_err());
+        assert!(parse_filter("added:yesterday").is_err());
+    }
+
+    #[test]
+    fn parse_filter_rejects_unknown_states() {
+        assert!(parse_filter("is:starred").is_err());
+    }
+
+    #[test]
+    fn parse_filter_normalizes_collections_and_sites() {
+        assert_eq!(
+            parse_filter(r#"collection:"/Work/Projects/""#).unwrap(),
+            Some(SearchFilter::Collection("Work/Projects".to_string()))
+        );
+        assert_eq!(
+            parse_filter("site:GitHub.com").unwrap(),
+            Some(SearchFilter::Site("github.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_search_query_parses_filters_and_terms() {
+        let search_query =
+            parse_search_query(r#"tag:rust -tag:old is:unread "a phrase" -draft lang"#).unwrap();
+        assert_eq!(
+            search_query.conditions,
+            vec![
+                condition(SearchFilter::Tag("rust".to_string()), false),
+                condition(SearchFilter::Tag("old".to_string()), true),
+                condition(SearchFilter::Is(BookmarkState::Unread), false),
+            ]
+        );
+        assert_eq!(search_query.terms, vec![r#""a phrase""#, r#""lang"*"#]);
+        assert_eq!(search_query.excluded_terms, vec![r#""draft"*"#]);
+    }
+
+    #[test]
+    fn parse_search_query_negates_quoted_filters() {
+        let search_query = parse_search_query(r#"-collection:"Work/Old" site:github.com"#).unwrap();
+        assert_eq!(
+            search_query.conditions,
+            vec![
+                condition(SearchFilter::Collection("Work/Old".to_string()), true),
+                condition(SearchFilter::Site("github.com".to_string()), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_search_query_ignores_punctuation_terms() {
+        let search_query = parse_search_query(r#"- "" * rust"#).unwrap();
+        assert!(search_query.conditions.is_empty());
+        assert_eq!(search_query.terms, vec![r#""rust"*"#]);
+        assert!(search_query.excluded_terms.is_empty());
+    }
+
+    #[test]
+    fn fts_query_joins_terms() {
+        let search_query = parse_search_query("rust -old -draft lang").unwrap();
+        assert_eq!(
+            search_query.fts_query(),
+            Some(r#""rust"* "lang"*"#.to_string())
+        );
+        assert_eq!(
+            search_query.excluded_fts_query(),
+            Some(r#""old"* OR "draft"*"#.to_string())
+        );
+        assert_eq!(SearchQuery::default().fts_query(), None);
+        assert_eq!(SearchQuery::default().excluded_fts_query(), None);
+    }
+
+    #[test]
+    fn parse_snippet_splits_matches() {
+        let snippet =
+            format!("learn {SNIPPET_MATCH_START}rust{SNIPPET_MATCH_END} today\nand tomorrow");
+        assert_eq!(
+            parse_snippet(&snippet),
+            vec![
+                ("learn ".to_string(), false),
+                ("rust".to_string(), true),
+                (" today and tomorrow".to_string(), false),
+            ]
+        );
+    }
+}